@@ -0,0 +1,46 @@
+//! How long kalk takes to evaluate inputs like the ones in the help, which
+//! happens after every pause in typing in kalk mode
+
+use std::hint::black_box;
+
+use criterion::{Criterion, criterion_group, criterion_main};
+use kalk::parser::Context;
+use quicalc::math;
+
+/// Definitions made before evaluating, like in a session where they were
+/// entered earlier
+const DEFINITIONS: &[&str] = &["x = 5", "rate = 17.5", "f(x) = x^2 + 1"];
+
+/// Inputs to evaluate, by name
+const INPUTS: &[(&str, &str)] = &[
+	("arithmetic", "12.5 * (3 + 4) / 2"),
+	("functions", "sqrt(2) + sin(pi/4) + ln(10)"),
+	("variables", "x * rate / 100"),
+	("factorial", "20!"),
+	("sum", "sum(n=1, 100, 1/n^2)"),
+	("product", "prod(n=1, 10, n)"),
+	("integral", "integrate(0, pi, sin(x) dx)"),
+	("derivative", "f'(2)"),
+	("percentage", "17.5% of 1200"),
+];
+
+fn evaluate(c: &mut Criterion) {
+	let mut ctx = Context::default();
+
+	for definition in DEFINITIONS {
+		math::evaluate(&mut ctx, definition);
+	}
+
+	let mut group = c.benchmark_group("kalk");
+
+	for &(name, input) in INPUTS {
+		group.bench_function(name, |b| {
+			b.iter(|| math::evaluate(&mut ctx, black_box(input)))
+		});
+	}
+
+	group.finish();
+}
+
+criterion_group!(benches, evaluate);
+criterion_main!(benches);
@@ -0,0 +1,86 @@
+//! How long embedded Python takes to evaluate inputs while typing, from
+//! queueing them with the worker to receiving the result, which includes
+//! copying the session's variables so that the evaluation can't change them
+
+use std::hint::black_box;
+
+use criterion::{Criterion, criterion_group, criterion_main};
+use iced::futures::StreamExt;
+use quicalc::{
+	config::PythonConfig,
+	python::{self, Evaluation, Event, Worker},
+};
+
+/// Modules imported before evaluating, like the configured imports
+const IMPORTS: &[&str] = &["math", "fractions"];
+
+/// Code run before evaluating, like definitions from earlier in a session
+const SESSION: &[&str] = &[
+	"items = list(range(1000))",
+	"prices = {str(n): n * 1.5 for n in range(100)}",
+];
+
+/// Inputs to evaluate, by name
+const INPUTS: &[(&str, &str)] = &[
+	("arithmetic", "12.5 * (3 + 4) / 2"),
+	(
+		"functions",
+		"math.sqrt(2) + math.sin(math.pi / 4) + math.log(10)",
+	),
+	("big integers", "2 ** 4096 % 1_000_007"),
+	("comprehension", "sum(x * x for x in items if x % 3)"),
+	(
+		"fractions",
+		"sum(fractions.Fraction(1, n) for n in range(1, 50))",
+	),
+];
+
+fn evaluate(c: &mut Criterion) {
+	let config = PythonConfig {
+		imports: IMPORTS.iter().map(ToString::to_string).collect(),
+		..PythonConfig::default()
+	};
+	python::init(&config);
+
+	let runtime = tokio::runtime::Builder::new_current_thread()
+		.build()
+		.unwrap();
+	let mut events = Box::pin(python::evaluations());
+	let mut worker = Worker::new(config);
+
+	worker.restore(SESSION.iter().map(ToString::to_string).collect());
+	worker.start();
+
+	let mut evaluate = |input: &str| {
+		let id = worker.eval(input);
+
+		runtime.block_on(async {
+			while let Some(event) = events.next().await {
+				if let Event::Evaluated(evaluated, evaluation) = event
+					&& evaluated == id
+				{
+					return evaluation;
+				}
+			}
+
+			unreachable!("the python worker stopped sending results")
+		})
+	};
+
+	// the first evaluation also starts the interpreter and runs the session
+	assert!(matches!(evaluate("len(items)"), Evaluation::Expression {
+		result: Some(_),
+		..
+	}));
+
+	let mut group = c.benchmark_group("python");
+
+	for &(name, input) in INPUTS {
+		group.bench_function(name, |b| b.iter(|| evaluate(black_box(input))));
+	}
+
+	group.finish();
+}
+
+criterion_group!(benches, evaluate);
+criterion_main!(benches);
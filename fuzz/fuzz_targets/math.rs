@@ -0,0 +1,9 @@
+#![no_main]
+
+use kalk::parser::Context;
+use libfuzzer_sys::fuzz_target;
+use quicalc::math;
+
+fuzz_target!(|input: &str| {
+	let _ = math::evaluate(&mut Context::default(), input);
+});
@@ -0,0 +1,8 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use quicalc::pad;
+
+fuzz_target!(|input: &str| {
+	let _ = pad::eval_lines(input);
+});
@@ -0,0 +1,24 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use quicalc::{
+	calendar,
+	config::{CalendarConfig, TaxConfig},
+	cron::Schedule,
+	finance,
+	percent::Phrase,
+	sizes, tax, timers,
+};
+
+fuzz_target!(|input: &str| {
+	let _ = Phrase::parse(input);
+	let _ = sizes::eval(input);
+	let _ = finance::eval(input);
+	let _ = tax::eval(input, &TaxConfig::default());
+	let _ = calendar::eval(input, &CalendarConfig::default());
+	let _ = timers::parse(input);
+
+	if let Ok(schedule) = Schedule::parse(input) {
+		let _ = schedule.explain();
+	}
+});
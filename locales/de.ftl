@@ -0,0 +1,253 @@
+mode-calculator = Rechner
+mode-pad = Notizblock
+mode-python = Python
+mode-sym = Symbolische Mathematik
+mode-sys = System
+mode-kill = Prozesse
+mode-kill-prompt = Prozesse suchen
+mode-win = Fenster
+mode-win-prompt = Zu einem Fenster wechseln
+mode-go = Lesezeichen
+mode-go-prompt = Ein Lesezeichen öffnen
+mode-timer = Timer
+mode-timer-prompt = Einen Timer starten, wie 25m Tee
+mode-clock = Uhr
+mode-clock-prompt = Uhrzeit in einer Stadt
+mode-cron = Cron
+mode-cron-prompt = Einen Cron-Ausdruck erklären
+mode-geo = Geo
+mode-geo-prompt = Zwei Orte eingeben, z. B. 52.52,13.40 to London
+mode-sun = Sonne
+mode-sun-prompt = Einen Ort und ein Datum eingeben, z. B. Ljubljana 2026-06-21
+mode-loan = Kredit
+mode-loan-prompt = Einen Kredit wie 250000 at 3.6% over 25y eingeben
+mode-wx = Wetter
+mode-wx-prompt = Einen Ort eingeben, um sein Wetter zu sehen
+mode-price = Kurs
+mode-price-prompt = Ein Symbol wie btc oder AAPL eingeben
+mode-unsupported = Der Modus „{ $mode }“ ist in diesem Build nicht verfügbar.
+python-timeout = Zeitüberschreitung bei der Auswertung
+python-reset = Python-Sitzung zurückgesetzt
+total-cleared = Laufende Summe zurückgesetzt
+session-saved = Sitzung „{ $name }“ gespeichert
+session-save-failed = Sitzung „{ $name }“ konnte nicht gespeichert werden
+session-loaded = Sitzung „{ $name }“ geladen
+session-not-found = Es gibt keine Sitzung namens „{ $name }“
+session-load-failed = Sitzung „{ $name }“ konnte nicht geladen werden
+output-more = { $lines } weitere anzeigen
+output-less = Weniger anzeigen
+output-traceback = Traceback anzeigen
+file-reading = Datei wird gelesen…
+file-lines = { $lines } Zeilen
+file-hash-copied = SHA-256-Hash kopiert
+result-copied = Kopiert
+file-error = Die Datei konnte nicht gelesen werden
+capture-failed = Ausdruck konnte nicht erfasst werden
+capture-empty = Es wurde kein Text erkannt.
+selection-failed = Auswahl konnte nicht berechnet werden
+selection-empty = Es ist kein Text ausgewählt.
+sys-lock = Bildschirm sperren
+sys-sleep = Computer in den Ruhezustand versetzen
+sys-restart = Computer neu starten
+sys-shutdown = Computer herunterfahren
+sys-empty-trash = Papierkorb leeren
+sys-confirm = { $action }? Zum Bestätigen erneut Enter drücken
+sys-unknown = Unbekannter Systembefehl
+sys-failed = Systembefehl konnte nicht ausgeführt werden
+kill-process = { $name } · PID { $pid } · CPU { $cpu } % · { $memory }
+kill-hint = ↑/↓ wählt einen Prozess, Enter beendet ihn
+kill-confirm = { $name } beenden? Zum Bestätigen erneut Enter drücken
+kill-no-match = Keine passenden Prozesse
+kill-done = { $name } beendet
+kill-failed = { $name } konnte nicht beendet werden: { $error }
+win-window = { $title } · { $app }
+win-hint = ↑/↓ wählt ein Fenster, Enter wechselt dorthin
+win-no-match = Keine passenden Fenster
+win-list-failed = Die offenen Fenster konnten nicht aufgelistet werden
+win-focus-failed = Wechseln zum Fenster fehlgeschlagen
+go-bookmark = { $name } · { $url }
+go-hint = ↑/↓ wählt ein Lesezeichen, Enter öffnet es
+go-no-match = Keine passenden Lesezeichen
+go-open-failed = { $name } konnte nicht geöffnet werden
+query-hint = Enter öffnet { $url }
+query-failed = Die Websuche konnte nicht geöffnet werden
+action-hint = Enter führt { $command } aus
+action-started = { $name } wurde gestartet
+action-failed = { $name } ist fehlgeschlagen
+timer-running = { $label } · noch { $remaining }
+timer-unlabeled = Timer
+timer-hint = Eine Dauer wie 25m, 1h30m oder 4:30 eingeben, gefolgt von einer Bezeichnung
+timer-start = { $label } · Enter startet { $duration }
+timer-started = { $label } · { $duration } gestartet
+timer-invalid = Keine Dauer wie 25m, 1h30m oder 4:30
+timer-done = Timer abgelaufen
+sw-started = Stoppuhr gestartet
+sw-stopped = Stoppuhr bei { $elapsed } angehalten
+clock-local = Ortszeit
+clock-time = { $city } · { $day } { $time } { $zone }
+clock-hint = Die aktuelle Uhrzeit in den passenden Städten
+clock-no-match = Keine passenden Städte
+calendar-invalid-date = { $date } ist kein Datum wie 2025-03-14
+calendar-invalid-year = { $year } ist kein Jahr von 1583 bis 9999
+calendar-week = Woche { $week } von { $year }
+calendar-easter = Ostersonntag ist am { $date }
+calendar-workdays = Arbeitstage: { $count }
+tip-invalid = Eine Rechnung wie tip 84.60 18% /3 eingeben
+tip-result = Trinkgeld { $tip } · gesamt { $total }
+tip-each = { $share } pro Person
+aspect-invalid = Eine Auflösung wie ar 2560x1440 27" oder ein Verhältnis wie ar 16:9 at 1200 wide eingeben
+aspect-ratio = { $ratio } ({ $decimal }:1)
+aspect-density = { $ppi } PPI bei { $diagonal }″
+aspect-scaled = { $width }×{ $height }
+percent-change = { $change } ({ $percent } %)
+percent-share = { $percent } %
+loan-invalid = Einen Kredit wie 250000 at 3.6% over 25y eingeben
+loan-result = Monatliche Rate { $payment } · Zinsen insgesamt { $interest }
+compound-invalid = Eine Anlage wie compound 10000 at 5% for 8y monthly eingeben
+compound-result = Endwert { $value } · Zinsen { $interest }
+tax-invalid = Einen Betrag wie gross 120, net 100 reduced oder net 100 9.5% eingeben
+tax-no-rate = Einen Steuersatz im Abschnitt [tax] der Konfiguration festlegen
+tax-unknown-rate = Kein Steuersatz namens { $rate } in der Konfiguration
+tax-breakdown = Netto { $net } + Steuer { $tax } ({ $rate } %) = brutto { $gross }
+size-invalid = { $amount } ist keine Zahl
+size-converted = { $size } { $unit }
+size-ambiguous = { $decimal } { $unit } in Potenzen von 1000 · { $binary } { $unit } in Potenzen von 1024
+size-transfer = { $duration } für die Übertragung
+size-transfer-ambiguous = { $decimal } für die Übertragung in Potenzen von 1000 · { $binary } in Potenzen von 1024
+cron-every-minute = Jede Minute
+cron-every-minutes = Alle { $step } Minuten
+cron-minutes = In Minute { $minutes }
+cron-at = Um { $times }
+cron-every-hours = alle { $step } Stunden
+cron-hours = in den Stunden { $hours }
+cron-days = am Tag { $days } des Monats
+cron-months = im { $months }
+cron-weekdays = am { $weekdays }
+cron-never = Läuft in den nächsten fünf Jahren nicht
+cron-field-count = Ein Cron-Ausdruck hat fünf Felder: Minute, Stunde, Tag des Monats, Monat und Wochentag
+cron-invalid-field = Ungültiges Feld: { $field }
+geo-hint = Zwei Orte getrennt durch „to“ eingeben, z. B. 52.52,13.40 to London
+geo-unknown-place = Unbekannter Ort: { $place }
+geo-distance = { $km } km · { $mi } mi · { $arrow } { $bearing }°
+sun-times = Sonnenaufgang { $sunrise }, Sonnenuntergang { $sunset } { $zone } · { $hours } h { $minutes } min Tageslicht
+sun-polar-day = Die Sonne geht an diesem Tag nicht unter
+sun-polar-night = Die Sonne geht an diesem Tag nicht auf
+wx-current = { $place } { $icon } { $temperature } °C, { $wind } km/h
+wx-hint = Enter drücken, um das Wetter abzurufen
+wx-loading = Wetter wird abgerufen…
+wx-no-location = Einen Ort eingeben oder im Abschnitt [weather] der Konfiguration festlegen
+wx-unknown-location = Unbekannter Ort: { $location }
+wx-failed = Das Wetter konnte nicht abgerufen werden: { $error }
+price-hint = Ein Symbol wie btc oder AAPL eingeben, optional gefolgt von einer Rechnung wie * 0.05
+price-loading = Kurs von { $symbol } wird abgerufen…
+price-unknown = Der Kurs von { $symbol } wurde nicht gefunden
+
+tray-show = Anzeigen
+tray-mode = Modus
+tray-capture = Ausdruck erfassen
+tray-autostart = Bei Anmeldung starten
+tray-config = Konfiguration öffnen
+tray-about = Über
+tray-update = Update
+tray-update-install = Update { $version } installieren
+tray-update-download = Update { $version } herunterladen
+tray-exit = Beenden
+
+update-available = Quicalc-Update verfügbar
+update-available-body = Version { $version } ist verfügbar, nutze das Tray-Menü, um sie zu erhalten.
+update-failed = Quicalc-Update fehlgeschlagen
+
+hotkey-changed = Quicalc-Tastenkürzel geändert
+hotkey-changed-body = { $default } wird bereits von einem anderen Programm verwendet, drücke stattdessen { $hotkey }, um Quicalc zu öffnen.
+hotkey-unavailable = Quicalc-Tastenkürzel nicht verfügbar
+hotkey-unavailable-body = Alle konfigurierten Tastenkürzel werden von anderen Programmen verwendet, nutze das Tray-Symbol, um Quicalc zu öffnen.
+config-invalid = Ungültige Quicalc-Konfiguration
+config-restart = Starte Quicalc neu, um alle Änderungen zu übernehmen
+config-restart-body = Einige der geänderten Einstellungen werden erst nach einem Neustart von Quicalc wirksam.
+portal-shortcut = Quicalc anzeigen
+
+onboarding-hotkey = Drücke überall { $hotkey }, um Quicalc zu öffnen, und Esc, um es auszublenden.
+onboarding-modes = Gib „{ $python }“ für Python oder „{ $kalk }“ für den Rechner ein.
+onboarding-math = Fang einfach an, etwas zu rechnen.
+
+help-commands = Befehle (mit Enter bestätigen)
+help-calculator = Rechner
+help-keys = Tasten
+help-command-python = zum Python-Modus wechseln
+help-command-sym = zum Modus für symbolische Mathematik wechseln (erfordert SymPy)
+help-command-reset = die Python-Sitzung zurücksetzen (im Python-Modus)
+help-command-kalk = zum Rechner wechseln
+help-command-pad = zu einem Notizblock wechseln, in dem jede Zeile berechnet wird und „total“ die Zeilen darüber addiert
+help-command-sys = zu Systembefehlen wechseln: { $commands }
+help-command-kill = laufende Prozesse suchen und einen beenden
+help-command-win = offene Fenster suchen und zu einem wechseln
+help-command-go = Lesezeichen suchen und eines öffnen, oder mit go NAME das beste öffnen
+help-command-timer = einen Countdown starten, der benachrichtigt, wenn er abgelaufen ist
+help-command-sw = die Stoppuhr starten oder anhalten
+help-command-clock = die Uhrzeit in anderen Städten und Zeitzonen anzeigen
+help-command-cron = einen Cron-Ausdruck erklären und die nächsten Ausführungen anzeigen
+help-command-geo = Entfernung und Richtung zwischen zwei Koordinaten oder Städten anzeigen
+help-command-sun = Sonnenaufgang, Sonnenuntergang und Tageslänge an einem Ort anzeigen
+help-command-loan = monatliche Rate und Gesamtzinsen eines Kredits anzeigen
+help-command-wx = das Wetter und eine kurze Vorhersage anzeigen
+help-command-price = den Kurs einer Kryptowährung oder Aktie anzeigen, auch im Rechner
+help-command-clear = die Variablen des aktuellen Modus vergessen und das Ergebnis leeren
+help-command-session-save = die Definitionen, früheren Eingaben und die Summe unter einem Namen speichern
+help-command-session-load = zu einer gespeicherten Sitzung wechseln
+help-command-help = diese Übersicht anzeigen
+help-command-about = Version und Build-Informationen anzeigen
+help-key-open = Quicalc öffnen
+help-key-hide = Quicalc ausblenden
+shortcuts-global = Überall
+shortcuts-window = In Quicalc
+shortcut-capture = einen Ausdruck vom Bildschirm erfassen
+shortcut-submit = berechnen oder einen Befehl ausführen
+shortcut-new-line = eine neue Zeile beginnen (im Python-Modus oder in einem Notizblock, wo Enter das tut)
+shortcut-pad-submit = einen Befehl in einem Notizblock ausführen
+shortcut-complete = Namen im Python-Modus sowie Websuchen und Aktionen vervollständigen
+shortcut-undo = die letzte Änderung rückgängig machen
+shortcut-redo = die letzte rückgängig gemachte Änderung wiederherstellen
+shortcut-line-start = zum Zeilenanfang springen
+shortcut-line-end = zum Zeilenende springen
+shortcut-kill-line = die Zeile vor dem Cursor löschen
+shortcut-kill-word = das Wort vor dem Cursor löschen
+shortcut-vi-normal = in den Normalmodus von vi wechseln
+shortcut-vi-insert = wieder tippen, vor oder nach dem Cursor oder am Anfang oder Ende der Zeile
+shortcut-vi-move = den Cursor im Normalmodus bewegen
+shortcut-vi-edit = Text im Normalmodus löschen oder ändern
+shortcut-output = die ganze Ausgabe ein- oder ausblenden
+shortcut-copy = das Ergebnis kopieren
+shortcut-pin = das Ergebnis in einem eigenen Fenster anheften
+shortcut-on-top = das Fenster über anderen halten oder nicht mehr
+shortcut-shortcuts = die Tastenkürzel anzeigen
+help-fn-variable = Variable definieren
+help-fn-function = Funktion definieren
+help-fn-root = Quadrat- und Kubikwurzel
+help-fn-trig = Trigonometrie (Bogenmaß)
+help-fn-inverse-trig = inverse Trigonometrie
+help-fn-log = Logarithmen und Exponentialfunktion
+help-fn-rounding = Runden und Betrag
+help-fn-comparison = Vergleiche und Teiler
+help-fn-factorial = Fakultät
+help-fn-sum = Summe
+help-fn-product = Produkt
+help-fn-integral = bestimmtes Integral
+help-fn-derivative = Ableitung einer Funktion
+help-fn-constants = Konstanten
+help-fn-week = ISO-Kalenderwoche eines Datums
+help-fn-easter = Datum des Ostersonntags
+help-fn-workdays = Arbeitstage zwischen zwei Daten, beide eingeschlossen
+help-fn-tip = Trinkgeld, Gesamtbetrag und Anteil pro Person
+help-fn-aspect = Seitenverhältnis und Pixeldichte einer Auflösung, oder eine Seite zu einem Verhältnis
+help-fn-change = die Änderung von einem Wert zum anderen, auch in Prozent
+help-fn-share = ein Wert als Prozentsatz eines anderen
+help-fn-compound = der Endwert einer Anlage mit Zinseszins
+help-fn-tax = einen Bruttobetrag in Netto und Steuer aufteilen oder Steuer zu einem Nettobetrag addieren
+help-fn-sizes = Datengrößen umrechnen, mit beiden Bedeutungen von Einheiten wie GB
+help-fn-transfer = wie lange die Übertragung einer Datengröße bei einer Rate in Bits oder Bytes dauert
+
+about-build = Build: { $tag } ({ $profile }, { $os } { $arch })
+about-build-local = lokal
+about-features = Features: { $features }
+about-features-none = keine
+about-license = Lizenziert unter { $license }, Lizenzen von Drittanbietern siehe attribution.html
@@ -0,0 +1,253 @@
+mode-calculator = Calculator
+mode-pad = Pad
+mode-python = Python
+mode-sym = Symbolic math
+mode-sys = System
+mode-kill = Processes
+mode-kill-prompt = Search processes
+mode-win = Windows
+mode-win-prompt = Switch to a window
+mode-go = Go
+mode-go-prompt = Open a bookmark
+mode-timer = Timer
+mode-timer-prompt = Start a timer, like 25m tea
+mode-clock = Clock
+mode-clock-prompt = Time in a city
+mode-cron = Cron
+mode-cron-prompt = Explain a cron expression
+mode-geo = Geo
+mode-geo-prompt = Enter two places, like 52.52,13.40 to London
+mode-sun = Sun
+mode-sun-prompt = Enter a place and a date, like Ljubljana 2026-06-21
+mode-loan = Loan
+mode-loan-prompt = Enter a loan like 250000 at 3.6% over 25y
+mode-wx = Weather
+mode-wx-prompt = Enter a place to see its weather
+mode-price = Price
+mode-price-prompt = Enter a symbol like btc or AAPL
+mode-unsupported = { $mode } mode is not available in this build.
+python-timeout = Evaluation timed out
+python-reset = Python session reset
+total-cleared = Running total cleared
+session-saved = Session "{ $name }" saved
+session-save-failed = Couldn't save session "{ $name }"
+session-loaded = Session "{ $name }" loaded
+session-not-found = There is no session called "{ $name }"
+session-load-failed = Couldn't load session "{ $name }"
+output-more = Show { $lines } more
+output-less = Show less
+output-traceback = Show traceback
+file-reading = Reading file…
+file-lines = { $lines } lines
+file-hash-copied = SHA-256 hash copied
+result-copied = Copied
+file-error = Could not read the file
+capture-failed = Could not capture an expression
+capture-empty = No text was recognized.
+selection-failed = Could not evaluate the selection
+selection-empty = No text is selected.
+sys-lock = Lock the screen
+sys-sleep = Put the computer to sleep
+sys-restart = Restart the computer
+sys-shutdown = Shut down the computer
+sys-empty-trash = Empty the trash
+sys-confirm = { $action }? Press Enter again to confirm
+sys-unknown = Unknown system command
+sys-failed = Could not run the system command
+kill-process = { $name } · PID { $pid } · CPU { $cpu }% · { $memory }
+kill-hint = ↑/↓ select a process, Enter terminates it
+kill-confirm = Terminate { $name }? Press Enter again to confirm
+kill-no-match = No matching processes
+kill-done = Terminated { $name }
+kill-failed = Could not terminate { $name }: { $error }
+win-window = { $title } · { $app }
+win-hint = ↑/↓ select a window, Enter switches to it
+win-no-match = No matching windows
+win-list-failed = Could not list the open windows
+win-focus-failed = Could not switch to the window
+go-bookmark = { $name } · { $url }
+go-hint = ↑/↓ select a bookmark, Enter opens it
+go-no-match = No matching bookmarks
+go-open-failed = Could not open { $name }
+query-hint = Enter opens { $url }
+query-failed = Could not open the web query
+action-hint = Enter runs { $command }
+action-started = { $name } started
+action-failed = { $name } failed
+timer-running = { $label } · { $remaining } left
+timer-unlabeled = Timer
+timer-hint = Enter a duration like 25m, 1h30m, or 4:30, followed by a label
+timer-start = { $label } · Enter starts { $duration }
+timer-started = { $label } · started { $duration }
+timer-invalid = Not a duration, like 25m, 1h30m, or 4:30
+timer-done = Timer done
+sw-started = Stopwatch started
+sw-stopped = Stopwatch stopped at { $elapsed }
+clock-local = Local time
+clock-time = { $city } · { $day } { $time } { $zone }
+clock-hint = The current time in the matching cities
+clock-no-match = No matching cities
+calendar-invalid-date = { $date } is not a date like 2025-03-14
+calendar-invalid-year = { $year } is not a year from 1583 to 9999
+calendar-week = Week { $week } of { $year }
+calendar-easter = Easter Sunday is on { $date }
+calendar-workdays = Workdays: { $count }
+tip-invalid = Enter a bill like tip 84.60 18% /3
+tip-result = Tip { $tip } · total { $total }
+tip-each = { $share } each
+aspect-invalid = Enter a resolution like ar 2560x1440 27", or a ratio like ar 16:9 at 1200 wide
+aspect-ratio = { $ratio } ({ $decimal }:1)
+aspect-density = { $ppi } PPI at { $diagonal }″
+aspect-scaled = { $width }×{ $height }
+percent-change = { $change } ({ $percent }%)
+percent-share = { $percent }%
+loan-invalid = Enter a loan like 250000 at 3.6% over 25y
+loan-result = Monthly payment { $payment } · total interest { $interest }
+compound-invalid = Enter an investment like compound 10000 at 5% for 8y monthly
+compound-result = Future value { $value } · interest { $interest }
+tax-invalid = Enter an amount like gross 120, net 100 reduced, or net 100 9.5%
+tax-no-rate = Set a tax rate in the [tax] section of the config
+tax-unknown-rate = No tax rate named { $rate } in the config
+tax-breakdown = Net { $net } + tax { $tax } ({ $rate }%) = gross { $gross }
+size-invalid = { $amount } is not a number
+size-converted = { $size } { $unit }
+size-ambiguous = { $decimal } { $unit } in powers of 1000 · { $binary } { $unit } in powers of 1024
+size-transfer = { $duration } to transfer
+size-transfer-ambiguous = { $decimal } to transfer in powers of 1000 · { $binary } in powers of 1024
+cron-every-minute = Every minute
+cron-every-minutes = Every { $step } minutes
+cron-minutes = At minute { $minutes }
+cron-at = At { $times }
+cron-every-hours = every { $step } hours
+cron-hours = in hours { $hours }
+cron-days = on day { $days } of the month
+cron-months = in { $months }
+cron-weekdays = on { $weekdays }
+cron-never = Doesn't run in the next five years
+cron-field-count = A cron expression has five fields: minute, hour, day of the month, month, and day of the week
+cron-invalid-field = Invalid field: { $field }
+geo-hint = Enter two places separated by "to", like 52.52,13.40 to London
+geo-unknown-place = Unknown place: { $place }
+geo-distance = { $km } km · { $mi } mi · { $arrow } { $bearing }°
+sun-times = Sunrise { $sunrise }, sunset { $sunset } { $zone } · { $hours } h { $minutes } min of daylight
+sun-polar-day = The sun doesn't set on this day
+sun-polar-night = The sun doesn't rise on this day
+wx-current = { $place } { $icon } { $temperature }°C, { $wind } km/h
+wx-hint = Enter looks up the weather there
+wx-loading = Fetching the weather…
+wx-no-location = Enter a place, or set one in the [weather] section of the config
+wx-unknown-location = Unknown place: { $location }
+wx-failed = Could not fetch the weather: { $error }
+price-hint = Enter a symbol like btc or AAPL, optionally followed by math like * 0.05
+price-loading = Fetching the price of { $symbol }…
+price-unknown = Could not find the price of { $symbol }
+
+tray-show = Show
+tray-mode = Mode
+tray-capture = Capture expression
+tray-autostart = Start at login
+tray-config = Open config
+tray-about = About
+tray-update = Update
+tray-update-install = Install update { $version }
+tray-update-download = Download update { $version }
+tray-exit = Exit
+
+update-available = Quicalc update available
+update-available-body = Version { $version } is available, use the tray menu to get it.
+update-failed = Quicalc update failed
+
+hotkey-changed = Quicalc hotkey changed
+hotkey-changed-body = { $default } is already in use by another program, press { $hotkey } to open Quicalc instead.
+hotkey-unavailable = Quicalc hotkey unavailable
+hotkey-unavailable-body = All configured hotkeys are in use by other programs, use the tray icon to open Quicalc.
+config-invalid = Invalid Quicalc configuration
+config-restart = Restart Quicalc to apply all changes
+config-restart-body = Some of the changed settings only take effect after Quicalc is restarted.
+portal-shortcut = Show Quicalc
+
+onboarding-hotkey = Press { $hotkey } anywhere to open Quicalc, Esc to hide it.
+onboarding-modes = Enter "{ $python }" for Python or "{ $kalk }" for the calculator.
+onboarding-math = Just start typing some math.
+
+help-commands = Commands (press Enter)
+help-calculator = Calculator
+help-keys = Keys
+help-command-python = switch to Python mode
+help-command-sym = switch to symbolic math mode (requires SymPy)
+help-command-reset = reset the Python session (in Python mode)
+help-command-kalk = switch to the calculator
+help-command-pad = switch to a pad, where each line is calculated and "total" adds up the lines above
+help-command-sys = switch to system commands: { $commands }
+help-command-kill = search running processes and terminate one
+help-command-win = search open windows and switch to one
+help-command-go = search bookmarks and open one, or open the best match with go NAME
+help-command-timer = start a countdown that notifies when it's done
+help-command-sw = start the stopwatch, or stop it
+help-command-clock = show the time in other cities and time zones
+help-command-cron = explain a cron expression and show when it runs next
+help-command-geo = show the distance and bearing between two coordinates or cities
+help-command-sun = show the sunrise, sunset, and day length at a place
+help-command-loan = show the monthly payment and total interest of a loan
+help-command-wx = show the weather and a short forecast
+help-command-price = show the price of a cryptocurrency or stock, also in the calculator
+help-command-clear = forget the current mode's variables and clear the result
+help-command-session-save = save the definitions, earlier inputs, and total under a name
+help-command-session-load = switch to a saved session
+help-command-help = show this reference
+help-command-about = show version and build info
+help-key-open = open Quicalc
+help-key-hide = hide Quicalc
+shortcuts-global = Anywhere
+shortcuts-window = In Quicalc
+shortcut-capture = capture an expression from the screen
+shortcut-submit = evaluate, or run a command
+shortcut-new-line = start a new line (in Python mode or a pad, where Enter does that)
+shortcut-pad-submit = run a command in a pad
+shortcut-complete = complete names in Python mode, and web queries and actions
+shortcut-undo = undo the last edit
+shortcut-redo = redo the last undone edit
+shortcut-line-start = move to the start of the line
+shortcut-line-end = move to the end of the line
+shortcut-kill-line = delete the line before the cursor
+shortcut-kill-word = delete the word before the cursor
+shortcut-vi-normal = switch to vi's normal mode
+shortcut-vi-insert = type again, before or after the cursor or at the start or end of the line
+shortcut-vi-move = move the cursor in normal mode
+shortcut-vi-edit = delete or change text in normal mode
+shortcut-output = show or hide the full output
+shortcut-copy = copy the result
+shortcut-pin = pin the result in its own window
+shortcut-on-top = keep the window above others, or stop doing that
+shortcut-shortcuts = show the keyboard shortcuts
+help-fn-variable = define a variable
+help-fn-function = define a function
+help-fn-root = square and cube root
+help-fn-trig = trigonometry (radians)
+help-fn-inverse-trig = inverse trigonometry
+help-fn-log = logarithms and exponential
+help-fn-rounding = rounding and absolute value
+help-fn-comparison = comparisons and divisors
+help-fn-factorial = factorial
+help-fn-sum = summation
+help-fn-product = product
+help-fn-integral = definite integral
+help-fn-derivative = derivative of a function
+help-fn-constants = constants
+help-fn-week = ISO week number of a date
+help-fn-easter = date of Easter Sunday
+help-fn-workdays = workdays between two dates, including both
+help-fn-tip = the tip, total, and share of each person
+help-fn-aspect = the aspect ratio and pixel density of a resolution, or a side for a ratio
+help-fn-change = the change from one value to another, also in percent
+help-fn-share = one value as a percentage of another
+help-fn-compound = the future value of an investment with compound interest
+help-fn-tax = split a gross amount into net and tax, or add tax to a net one
+help-fn-sizes = convert data sizes, showing both meanings of units like GB
+help-fn-transfer = how long transferring a data size takes at a rate, in bits or bytes
+
+about-build = Build: { $tag } ({ $profile }, { $os } { $arch })
+about-build-local = local
+about-features = Features: { $features }
+about-features-none = none
+about-license = Licensed under { $license }, see attribution.html for third-party licenses
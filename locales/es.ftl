@@ -0,0 +1,253 @@
+mode-calculator = Calculadora
+mode-pad = Bloc
+mode-python = Python
+mode-sym = Matemática simbólica
+mode-sys = Sistema
+mode-kill = Procesos
+mode-kill-prompt = Buscar procesos
+mode-win = Ventanas
+mode-win-prompt = Cambiar a una ventana
+mode-go = Marcadores
+mode-go-prompt = Abrir un marcador
+mode-timer = Temporizador
+mode-timer-prompt = Iniciar un temporizador, como 25m té
+mode-clock = Reloj
+mode-clock-prompt = Hora en una ciudad
+mode-cron = Cron
+mode-cron-prompt = Explicar una expresión cron
+mode-geo = Geo
+mode-geo-prompt = Introducir dos lugares, como 52.52,13.40 to London
+mode-sun = Sol
+mode-sun-prompt = Introducir un lugar y una fecha, como Ljubljana 2026-06-21
+mode-loan = Préstamo
+mode-loan-prompt = Introducir un préstamo como 250000 at 3.6% over 25y
+mode-wx = Tiempo
+mode-wx-prompt = Introducir un lugar para ver su tiempo
+mode-price = Precio
+mode-price-prompt = Introducir un símbolo como btc o AAPL
+mode-unsupported = El modo { $mode } no está disponible en esta versión.
+python-timeout = Se agotó el tiempo de evaluación
+python-reset = Sesión de Python reiniciada
+total-cleared = Total acumulado borrado
+session-saved = Sesión «{ $name }» guardada
+session-save-failed = No se pudo guardar la sesión «{ $name }»
+session-loaded = Sesión «{ $name }» cargada
+session-not-found = No hay ninguna sesión llamada «{ $name }»
+session-load-failed = No se pudo cargar la sesión «{ $name }»
+output-more = Mostrar { $lines } más
+output-less = Mostrar menos
+output-traceback = Mostrar traceback
+file-reading = Leyendo el archivo…
+file-lines = { $lines } líneas
+file-hash-copied = Hash SHA-256 copiado
+result-copied = Copiado
+file-error = No se pudo leer el archivo
+capture-failed = No se pudo capturar una expresión
+capture-empty = No se reconoció ningún texto.
+selection-failed = No se pudo evaluar la selección
+selection-empty = No hay ningún texto seleccionado.
+sys-lock = Bloquear la pantalla
+sys-sleep = Suspender el ordenador
+sys-restart = Reiniciar el ordenador
+sys-shutdown = Apagar el ordenador
+sys-empty-trash = Vaciar la papelera
+sys-confirm = ¿{ $action }? Pulsa Enter de nuevo para confirmar
+sys-unknown = Comando del sistema desconocido
+sys-failed = No se pudo ejecutar el comando del sistema
+kill-process = { $name } · PID { $pid } · CPU { $cpu } % · { $memory }
+kill-hint = ↑/↓ selecciona un proceso, Enter lo termina
+kill-confirm = ¿Terminar { $name }? Pulsa Enter de nuevo para confirmar
+kill-no-match = No hay procesos que coincidan
+kill-done = { $name } terminado
+kill-failed = No se pudo terminar { $name }: { $error }
+win-window = { $title } · { $app }
+win-hint = ↑/↓ selecciona una ventana, Enter cambia a ella
+win-no-match = No hay ventanas que coincidan
+win-list-failed = No se pudieron listar las ventanas abiertas
+win-focus-failed = No se pudo cambiar a la ventana
+go-bookmark = { $name } · { $url }
+go-hint = ↑/↓ selecciona un marcador, Enter lo abre
+go-no-match = No hay marcadores que coincidan
+go-open-failed = No se pudo abrir { $name }
+query-hint = Enter abre { $url }
+query-failed = No se pudo abrir la búsqueda web
+action-hint = Enter ejecuta { $command }
+action-started = { $name } se inició
+action-failed = { $name } falló
+timer-running = { $label } · quedan { $remaining }
+timer-unlabeled = Temporizador
+timer-hint = Introduce una duración como 25m, 1h30m o 4:30, seguida de una etiqueta
+timer-start = { $label } · Enter inicia { $duration }
+timer-started = { $label } · { $duration } iniciado
+timer-invalid = No es una duración como 25m, 1h30m o 4:30
+timer-done = Temporizador terminado
+sw-started = Cronómetro iniciado
+sw-stopped = Cronómetro detenido en { $elapsed }
+clock-local = Hora local
+clock-time = { $city } · { $day } { $time } { $zone }
+clock-hint = La hora actual en las ciudades que coinciden
+clock-no-match = No hay ciudades que coincidan
+calendar-invalid-date = { $date } no es una fecha como 2025-03-14
+calendar-invalid-year = { $year } no es un año entre 1583 y 9999
+calendar-week = Semana { $week } de { $year }
+calendar-easter = El Domingo de Pascua es el { $date }
+calendar-workdays = Días laborables: { $count }
+tip-invalid = Introduce una cuenta como tip 84.60 18% /3
+tip-result = Propina { $tip } · total { $total }
+tip-each = { $share } por persona
+aspect-invalid = Introduce una resolución como ar 2560x1440 27" o una proporción como ar 16:9 at 1200 wide
+aspect-ratio = { $ratio } ({ $decimal }:1)
+aspect-density = { $ppi } PPI a { $diagonal }″
+aspect-scaled = { $width }×{ $height }
+percent-change = { $change } ({ $percent } %)
+percent-share = { $percent } %
+loan-invalid = Introduce un préstamo como 250000 at 3.6% over 25y
+loan-result = Cuota mensual { $payment } · intereses totales { $interest }
+compound-invalid = Introduce una inversión como compound 10000 at 5% for 8y monthly
+compound-result = Valor futuro { $value } · intereses { $interest }
+tax-invalid = Introduce un importe como gross 120, net 100 reduced o net 100 9.5%
+tax-no-rate = Configura un tipo impositivo en la sección [tax] de la configuración
+tax-unknown-rate = No hay ningún tipo impositivo llamado { $rate } en la configuración
+tax-breakdown = Neto { $net } + impuesto { $tax } ({ $rate } %) = bruto { $gross }
+size-invalid = { $amount } no es un número
+size-converted = { $size } { $unit }
+size-ambiguous = { $decimal } { $unit } en potencias de 1000 · { $binary } { $unit } en potencias de 1024
+size-transfer = { $duration } de transferencia
+size-transfer-ambiguous = { $decimal } de transferencia en potencias de 1000 · { $binary } en potencias de 1024
+cron-every-minute = Cada minuto
+cron-every-minutes = Cada { $step } minutos
+cron-minutes = En el minuto { $minutes }
+cron-at = A las { $times }
+cron-every-hours = cada { $step } horas
+cron-hours = en las horas { $hours }
+cron-days = el día { $days } del mes
+cron-months = en { $months }
+cron-weekdays = los { $weekdays }
+cron-never = No se ejecuta en los próximos cinco años
+cron-field-count = Una expresión cron tiene cinco campos: minuto, hora, día del mes, mes y día de la semana
+cron-invalid-field = Campo no válido: { $field }
+geo-hint = Introduce dos lugares separados por «to», como 52.52,13.40 to London
+geo-unknown-place = Lugar desconocido: { $place }
+geo-distance = { $km } km · { $mi } mi · { $arrow } { $bearing }°
+sun-times = Amanecer { $sunrise }, atardecer { $sunset } { $zone } · { $hours } h { $minutes } min de luz
+sun-polar-day = El sol no se pone este día
+sun-polar-night = El sol no sale este día
+wx-current = { $place } { $icon } { $temperature } °C, { $wind } km/h
+wx-hint = Pulsa Enter para consultar el tiempo
+wx-loading = Consultando el tiempo…
+wx-no-location = Introduce un lugar o configúralo en la sección [weather] de la configuración
+wx-unknown-location = Lugar desconocido: { $location }
+wx-failed = No se pudo consultar el tiempo: { $error }
+price-hint = Introduce un símbolo como btc o AAPL, opcionalmente seguido de un cálculo como * 0.05
+price-loading = Consultando el precio de { $symbol }…
+price-unknown = No se encontró el precio de { $symbol }
+
+tray-show = Mostrar
+tray-mode = Modo
+tray-capture = Capturar expresión
+tray-autostart = Iniciar al iniciar sesión
+tray-config = Abrir configuración
+tray-about = Acerca de
+tray-update = Actualización
+tray-update-install = Instalar actualización { $version }
+tray-update-download = Descargar actualización { $version }
+tray-exit = Salir
+
+update-available = Actualización de Quicalc disponible
+update-available-body = La versión { $version } está disponible, usa el menú de la bandeja para obtenerla.
+update-failed = Error al actualizar Quicalc
+
+hotkey-changed = Atajo de Quicalc cambiado
+hotkey-changed-body = { $default } ya lo usa otro programa, pulsa { $hotkey } para abrir Quicalc.
+hotkey-unavailable = Atajo de Quicalc no disponible
+hotkey-unavailable-body = Todos los atajos configurados los usan otros programas, usa el icono de la bandeja para abrir Quicalc.
+config-invalid = Configuración de Quicalc no válida
+config-restart = Reinicia Quicalc para aplicar todos los cambios
+config-restart-body = Algunos de los ajustes cambiados solo se aplican después de reiniciar Quicalc.
+portal-shortcut = Mostrar Quicalc
+
+onboarding-hotkey = Pulsa { $hotkey } en cualquier lugar para abrir Quicalc y Esc para ocultarlo.
+onboarding-modes = Escribe «{ $python }» para Python o «{ $kalk }» para la calculadora.
+onboarding-math = Empieza a escribir una operación.
+
+help-commands = Comandos (pulsa Enter)
+help-calculator = Calculadora
+help-keys = Teclas
+help-command-python = cambiar al modo Python
+help-command-sym = cambiar al modo de matemática simbólica (requiere SymPy)
+help-command-reset = reiniciar la sesión de Python (en modo Python)
+help-command-kalk = cambiar a la calculadora
+help-command-pad = cambiar a un bloc, donde se calcula cada línea y «total» suma las líneas anteriores
+help-command-sys = cambiar a comandos del sistema: { $commands }
+help-command-kill = buscar procesos en ejecución y terminar uno
+help-command-win = buscar ventanas abiertas y cambiar a una
+help-command-go = buscar marcadores y abrir uno, o abrir el mejor con go NOMBRE
+help-command-timer = iniciar una cuenta atrás que avisa al terminar
+help-command-sw = iniciar el cronómetro, o detenerlo
+help-command-clock = mostrar la hora en otras ciudades y zonas horarias
+help-command-cron = explicar una expresión cron y mostrar cuándo se ejecuta
+help-command-geo = mostrar la distancia y el rumbo entre dos coordenadas o ciudades
+help-command-sun = mostrar el amanecer, el atardecer y la duración del día en un lugar
+help-command-loan = mostrar la cuota mensual y los intereses totales de un préstamo
+help-command-wx = mostrar el tiempo y un breve pronóstico
+help-command-price = mostrar el precio de una criptomoneda o acción, también en la calculadora
+help-command-clear = olvidar las variables del modo actual y borrar el resultado
+help-command-session-save = guardar las definiciones, las entradas anteriores y el total con un nombre
+help-command-session-load = cambiar a una sesión guardada
+help-command-help = mostrar esta referencia
+help-command-about = mostrar la versión y la compilación
+help-key-open = abrir Quicalc
+help-key-hide = ocultar Quicalc
+shortcuts-global = En cualquier lugar
+shortcuts-window = En Quicalc
+shortcut-capture = capturar una expresión de la pantalla
+shortcut-submit = calcular o ejecutar un comando
+shortcut-new-line = empezar una línea nueva (en modo Python o en un bloc, donde lo hace Enter)
+shortcut-pad-submit = ejecutar un comando en un bloc
+shortcut-complete = completar nombres en modo Python, y búsquedas web y acciones
+shortcut-undo = deshacer la última edición
+shortcut-redo = rehacer la última edición deshecha
+shortcut-line-start = ir al inicio de la línea
+shortcut-line-end = ir al final de la línea
+shortcut-kill-line = borrar la línea antes del cursor
+shortcut-kill-word = borrar la palabra antes del cursor
+shortcut-vi-normal = cambiar al modo normal de vi
+shortcut-vi-insert = volver a escribir, antes o después del cursor o al inicio o final de la línea
+shortcut-vi-move = mover el cursor en el modo normal
+shortcut-vi-edit = borrar o cambiar texto en el modo normal
+shortcut-output = mostrar u ocultar la salida completa
+shortcut-copy = copiar el resultado
+shortcut-pin = fijar el resultado en su propia ventana
+shortcut-on-top = mantener la ventana sobre las demás, o dejar de hacerlo
+shortcut-shortcuts = mostrar los atajos de teclado
+help-fn-variable = definir una variable
+help-fn-function = definir una función
+help-fn-root = raíz cuadrada y cúbica
+help-fn-trig = trigonometría (radianes)
+help-fn-inverse-trig = trigonometría inversa
+help-fn-log = logaritmos y exponencial
+help-fn-rounding = redondeo y valor absoluto
+help-fn-comparison = comparaciones y divisores
+help-fn-factorial = factorial
+help-fn-sum = sumatorio
+help-fn-product = productorio
+help-fn-integral = integral definida
+help-fn-derivative = derivada de una función
+help-fn-constants = constantes
+help-fn-week = número de semana ISO de una fecha
+help-fn-easter = fecha del Domingo de Pascua
+help-fn-workdays = días laborables entre dos fechas, ambas incluidas
+help-fn-tip = la propina, el total y la parte de cada persona
+help-fn-aspect = la relación de aspecto y la densidad de píxeles de una resolución, o un lado para una proporción
+help-fn-change = el cambio de un valor a otro, también en porcentaje
+help-fn-share = un valor como porcentaje de otro
+help-fn-compound = el valor futuro de una inversión con interés compuesto
+help-fn-tax = dividir un importe bruto en neto e impuesto, o añadir el impuesto a uno neto
+help-fn-sizes = convertir tamaños de datos, con ambos significados de unidades como GB
+help-fn-transfer = cuánto tarda en transferirse un tamaño de datos a una velocidad, en bits o bytes
+
+about-build = Compilación: { $tag } ({ $profile }, { $os } { $arch })
+about-build-local = local
+about-features = Características: { $features }
+about-features-none = ninguna
+about-license = Con licencia { $license }, consulta attribution.html para las licencias de terceros
@@ -0,0 +1,253 @@
+mode-calculator = Calculatrice
+mode-pad = Bloc-notes
+mode-python = Python
+mode-sym = Calcul symbolique
+mode-sys = Système
+mode-kill = Processus
+mode-kill-prompt = Rechercher des processus
+mode-win = Fenêtres
+mode-win-prompt = Passer à une fenêtre
+mode-go = Favoris
+mode-go-prompt = Ouvrir un favori
+mode-timer = Minuteur
+mode-timer-prompt = Lancer un minuteur, comme 25m thé
+mode-clock = Horloge
+mode-clock-prompt = Heure dans une ville
+mode-cron = Cron
+mode-cron-prompt = Expliquer une expression cron
+mode-geo = Géo
+mode-geo-prompt = Saisir deux lieux, comme 52.52,13.40 to London
+mode-sun = Soleil
+mode-sun-prompt = Saisir un lieu et une date, comme Ljubljana 2026-06-21
+mode-loan = Prêt
+mode-loan-prompt = Saisir un prêt comme 250000 at 3.6% over 25y
+mode-wx = Météo
+mode-wx-prompt = Saisir un lieu pour voir sa météo
+mode-price = Cours
+mode-price-prompt = Saisir un symbole comme btc ou AAPL
+mode-unsupported = Le mode { $mode } n’est pas disponible dans cette version.
+python-timeout = Délai d’évaluation dépassé
+python-reset = Session Python réinitialisée
+total-cleared = Total cumulé effacé
+session-saved = Session « { $name } » enregistrée
+session-save-failed = Impossible d’enregistrer la session « { $name } »
+session-loaded = Session « { $name } » chargée
+session-not-found = Il n’y a pas de session appelée « { $name } »
+session-load-failed = Impossible de charger la session « { $name } »
+output-more = Afficher { $lines } de plus
+output-less = Afficher moins
+output-traceback = Afficher la trace d’appels
+file-reading = Lecture du fichier…
+file-lines = { $lines } lignes
+file-hash-copied = Empreinte SHA-256 copiée
+result-copied = Copié
+file-error = Impossible de lire le fichier
+capture-failed = Impossible de capturer une expression
+capture-empty = Aucun texte n’a été reconnu.
+selection-failed = Impossible d’évaluer la sélection
+selection-empty = Aucun texte n’est sélectionné.
+sys-lock = Verrouiller l’écran
+sys-sleep = Mettre l’ordinateur en veille
+sys-restart = Redémarrer l’ordinateur
+sys-shutdown = Éteindre l’ordinateur
+sys-empty-trash = Vider la corbeille
+sys-confirm = { $action } ? Appuyez à nouveau sur Entrée pour confirmer
+sys-unknown = Commande système inconnue
+sys-failed = Impossible d’exécuter la commande système
+kill-process = { $name } · PID { $pid } · CPU { $cpu } % · { $memory }
+kill-hint = ↑/↓ sélectionne un processus, Entrée le termine
+kill-confirm = Terminer { $name } ? Appuyez à nouveau sur Entrée pour confirmer
+kill-no-match = Aucun processus correspondant
+kill-done = { $name } terminé
+kill-failed = Impossible de terminer { $name } : { $error }
+win-window = { $title } · { $app }
+win-hint = ↑/↓ sélectionne une fenêtre, Entrée y bascule
+win-no-match = Aucune fenêtre correspondante
+win-list-failed = Impossible de lister les fenêtres ouvertes
+win-focus-failed = Impossible de passer à la fenêtre
+go-bookmark = { $name } · { $url }
+go-hint = ↑/↓ sélectionne un favori, Entrée l’ouvre
+go-no-match = Aucun favori correspondant
+go-open-failed = Impossible d’ouvrir { $name }
+query-hint = Entrée ouvre { $url }
+query-failed = Impossible d’ouvrir la recherche web
+action-hint = Entrée exécute { $command }
+action-started = { $name } a démarré
+action-failed = { $name } a échoué
+timer-running = { $label } · encore { $remaining }
+timer-unlabeled = Minuteur
+timer-hint = Saisissez une durée comme 25m, 1h30m ou 4:30, suivie d’un libellé
+timer-start = { $label } · Entrée lance { $duration }
+timer-started = { $label } · { $duration } lancé
+timer-invalid = Ce n’est pas une durée comme 25m, 1h30m ou 4:30
+timer-done = Minuteur terminé
+sw-started = Chronomètre lancé
+sw-stopped = Chronomètre arrêté à { $elapsed }
+clock-local = Heure locale
+clock-time = { $city } · { $day } { $time } { $zone }
+clock-hint = L’heure actuelle dans les villes correspondantes
+clock-no-match = Aucune ville correspondante
+calendar-invalid-date = { $date } n’est pas une date comme 2025-03-14
+calendar-invalid-year = { $year } n’est pas une année de 1583 à 9999
+calendar-week = Semaine { $week } de { $year }
+calendar-easter = Le dimanche de Pâques est le { $date }
+calendar-workdays = Jours ouvrés : { $count }
+tip-invalid = Saisissez une addition comme tip 84.60 18% /3
+tip-result = Pourboire { $tip } · total { $total }
+tip-each = { $share } par personne
+aspect-invalid = Saisissez une résolution comme ar 2560x1440 27" ou un rapport comme ar 16:9 at 1200 wide
+aspect-ratio = { $ratio } ({ $decimal }:1)
+aspect-density = { $ppi } PPI à { $diagonal }″
+aspect-scaled = { $width }×{ $height }
+percent-change = { $change } ({ $percent } %)
+percent-share = { $percent } %
+loan-invalid = Saisissez un prêt comme 250000 at 3.6% over 25y
+loan-result = Mensualité { $payment } · intérêts totaux { $interest }
+compound-invalid = Saisissez un placement comme compound 10000 at 5% for 8y monthly
+compound-result = Valeur future { $value } · intérêts { $interest }
+tax-invalid = Saisissez un montant comme gross 120, net 100 reduced ou net 100 9.5%
+tax-no-rate = Définissez un taux de taxe dans la section [tax] de la configuration
+tax-unknown-rate = Aucun taux de taxe nommé { $rate } dans la configuration
+tax-breakdown = HT { $net } + taxe { $tax } ({ $rate } %) = TTC { $gross }
+size-invalid = { $amount } n’est pas un nombre
+size-converted = { $size } { $unit }
+size-ambiguous = { $decimal } { $unit } en puissances de 1000 · { $binary } { $unit } en puissances de 1024
+size-transfer = { $duration } de transfert
+size-transfer-ambiguous = { $decimal } de transfert en puissances de 1000 · { $binary } en puissances de 1024
+cron-every-minute = Chaque minute
+cron-every-minutes = Toutes les { $step } minutes
+cron-minutes = À la minute { $minutes }
+cron-at = À { $times }
+cron-every-hours = toutes les { $step } heures
+cron-hours = aux heures { $hours }
+cron-days = le jour { $days } du mois
+cron-months = en { $months }
+cron-weekdays = le { $weekdays }
+cron-never = Ne s’exécute pas dans les cinq prochaines années
+cron-field-count = Une expression cron a cinq champs : minute, heure, jour du mois, mois et jour de la semaine
+cron-invalid-field = Champ invalide : { $field }
+geo-hint = Saisissez deux lieux séparés par « to », comme 52.52,13.40 to London
+geo-unknown-place = Lieu inconnu : { $place }
+geo-distance = { $km } km · { $mi } mi · { $arrow } { $bearing }°
+sun-times = Lever { $sunrise }, coucher { $sunset } { $zone } · { $hours } h { $minutes } min de jour
+sun-polar-day = Le soleil ne se couche pas ce jour-là
+sun-polar-night = Le soleil ne se lève pas ce jour-là
+wx-current = { $place } { $icon } { $temperature } °C, { $wind } km/h
+wx-hint = Appuyez sur Entrée pour consulter la météo
+wx-loading = Récupération de la météo…
+wx-no-location = Saisissez un lieu, ou définissez-en un dans la section [weather] de la configuration
+wx-unknown-location = Lieu inconnu : { $location }
+wx-failed = Impossible de récupérer la météo : { $error }
+price-hint = Saisissez un symbole comme btc ou AAPL, éventuellement suivi d’un calcul comme * 0.05
+price-loading = Récupération du cours de { $symbol }…
+price-unknown = Impossible de trouver le cours de { $symbol }
+
+tray-show = Afficher
+tray-mode = Mode
+tray-capture = Capturer une expression
+tray-autostart = Lancer à l’ouverture de session
+tray-config = Ouvrir la configuration
+tray-about = À propos
+tray-update = Mise à jour
+tray-update-install = Installer la mise à jour { $version }
+tray-update-download = Télécharger la mise à jour { $version }
+tray-exit = Quitter
+
+update-available = Mise à jour de Quicalc disponible
+update-available-body = La version { $version } est disponible, utilisez le menu de la zone de notification pour l’obtenir.
+update-failed = Échec de la mise à jour de Quicalc
+
+hotkey-changed = Raccourci de Quicalc modifié
+hotkey-changed-body = { $default } est déjà utilisé par un autre programme, appuyez sur { $hotkey } pour ouvrir Quicalc.
+hotkey-unavailable = Raccourci de Quicalc indisponible
+hotkey-unavailable-body = Tous les raccourcis configurés sont utilisés par d’autres programmes, utilisez l’icône de la zone de notification pour ouvrir Quicalc.
+config-invalid = Configuration de Quicalc invalide
+config-restart = Redémarrez Quicalc pour appliquer toutes les modifications
+config-restart-body = Certains des paramètres modifiés ne prennent effet qu’après le redémarrage de Quicalc.
+portal-shortcut = Afficher Quicalc
+
+onboarding-hotkey = Appuyez sur { $hotkey } n’importe où pour ouvrir Quicalc, et sur Échap pour le masquer.
+onboarding-modes = Saisissez « { $python } » pour Python ou « { $kalk } » pour la calculatrice.
+onboarding-math = Commencez simplement à taper un calcul.
+
+help-commands = Commandes (appuyez sur Entrée)
+help-calculator = Calculatrice
+help-keys = Touches
+help-command-python = passer en mode Python
+help-command-sym = passer en mode calcul symbolique (nécessite SymPy)
+help-command-reset = réinitialiser la session Python (en mode Python)
+help-command-kalk = passer à la calculatrice
+help-command-pad = passer à un bloc-notes, où chaque ligne est calculée et « total » additionne les lignes précédentes
+help-command-sys = passer aux commandes système : { $commands }
+help-command-kill = rechercher les processus en cours et en terminer un
+help-command-win = rechercher les fenêtres ouvertes et passer à l’une d’elles
+help-command-go = rechercher les favoris et en ouvrir un, ou ouvrir le meilleur avec go NOM
+help-command-timer = lancer un compte à rebours qui notifie quand il est terminé
+help-command-sw = lancer le chronomètre, ou l’arrêter
+help-command-clock = afficher l’heure dans d’autres villes et fuseaux horaires
+help-command-cron = expliquer une expression cron et afficher ses prochaines exécutions
+help-command-geo = afficher la distance et le cap entre deux coordonnées ou villes
+help-command-sun = afficher le lever et le coucher du soleil et la durée du jour en un lieu
+help-command-loan = afficher la mensualité et les intérêts totaux d’un prêt
+help-command-wx = afficher la météo et de brèves prévisions
+help-command-price = afficher le cours d’une cryptomonnaie ou d’une action, aussi dans la calculatrice
+help-command-clear = oublier les variables du mode actuel et effacer le résultat
+help-command-session-save = enregistrer les définitions, les saisies précédentes et le total sous un nom
+help-command-session-load = passer à une session enregistrée
+help-command-help = afficher cette aide
+help-command-about = afficher la version et les informations de compilation
+help-key-open = ouvrir Quicalc
+help-key-hide = masquer Quicalc
+shortcuts-global = Partout
+shortcuts-window = Dans Quicalc
+shortcut-capture = capturer une expression à l’écran
+shortcut-submit = calculer ou exécuter une commande
+shortcut-new-line = commencer une nouvelle ligne (en mode Python ou dans un bloc-notes, où Entrée le fait)
+shortcut-pad-submit = exécuter une commande dans un bloc-notes
+shortcut-complete = compléter les noms en mode Python, ainsi que les recherches web et les actions
+shortcut-undo = annuler la dernière modification
+shortcut-redo = rétablir la dernière modification annulée
+shortcut-line-start = aller au début de la ligne
+shortcut-line-end = aller à la fin de la ligne
+shortcut-kill-line = supprimer la ligne avant le curseur
+shortcut-kill-word = supprimer le mot avant le curseur
+shortcut-vi-normal = passer au mode normal de vi
+shortcut-vi-insert = taper à nouveau, avant ou après le curseur ou au début ou à la fin de la ligne
+shortcut-vi-move = déplacer le curseur en mode normal
+shortcut-vi-edit = supprimer ou modifier du texte en mode normal
+shortcut-output = afficher ou masquer la sortie complète
+shortcut-copy = copier le résultat
+shortcut-pin = épingler le résultat dans sa propre fenêtre
+shortcut-on-top = garder la fenêtre au-dessus des autres, ou arrêter
+shortcut-shortcuts = afficher les raccourcis clavier
+help-fn-variable = définir une variable
+help-fn-function = définir une fonction
+help-fn-root = racine carrée et cubique
+help-fn-trig = trigonométrie (radians)
+help-fn-inverse-trig = trigonométrie inverse
+help-fn-log = logarithmes et exponentielle
+help-fn-rounding = arrondi et valeur absolue
+help-fn-comparison = comparaisons et diviseurs
+help-fn-factorial = factorielle
+help-fn-sum = somme
+help-fn-product = produit
+help-fn-integral = intégrale définie
+help-fn-derivative = dérivée d’une fonction
+help-fn-constants = constantes
+help-fn-week = numéro de semaine ISO d’une date
+help-fn-easter = date du dimanche de Pâques
+help-fn-workdays = jours ouvrés entre deux dates, toutes deux incluses
+help-fn-tip = le pourboire, le total et la part de chacun
+help-fn-aspect = le rapport d’aspect et la densité de pixels d’une résolution, ou un côté pour un rapport
+help-fn-change = la variation d’une valeur à une autre, aussi en pourcentage
+help-fn-share = une valeur en pourcentage d’une autre
+help-fn-compound = la valeur future d’un placement à intérêts composés
+help-fn-tax = décomposer un montant TTC en HT et taxe, ou ajouter la taxe à un montant HT
+help-fn-sizes = convertir des tailles de données, avec les deux sens d’unités comme GB
+help-fn-transfer = combien de temps prend le transfert d’une taille de données à un débit, en bits ou en octets
+
+about-build = Compilation : { $tag } ({ $profile }, { $os } { $arch })
+about-build-local = locale
+about-features = Fonctionnalités : { $features }
+about-features-none = aucune
+about-license = Sous licence { $license }, voir attribution.html pour les licences tierces
@@ -0,0 +1,253 @@
+mode-calculator = Kalkulator
+mode-pad = Beležka
+mode-python = Python
+mode-sym = Simbolna matematika
+mode-sys = Sistem
+mode-kill = Procesi
+mode-kill-prompt = Iskanje procesov
+mode-win = Okna
+mode-win-prompt = Preklop na okno
+mode-go = Zaznamki
+mode-go-prompt = Odpri zaznamek
+mode-timer = Časovnik
+mode-timer-prompt = Zaženi časovnik, na primer 25m čaj
+mode-clock = Ura
+mode-clock-prompt = Čas v mestu
+mode-cron = Cron
+mode-cron-prompt = Razloži izraz cron
+mode-geo = Geo
+mode-geo-prompt = Vnesi dva kraja, npr. 52.52,13.40 to London
+mode-sun = Sonce
+mode-sun-prompt = Vnesi kraj in datum, npr. Ljubljana 2026-06-21
+mode-loan = Posojilo
+mode-loan-prompt = Vnesi posojilo, npr. 250000 at 3.6% over 25y
+mode-wx = Vreme
+mode-wx-prompt = Vnesi kraj za prikaz vremena
+mode-price = Cena
+mode-price-prompt = Vnesi simbol, kot je btc ali AAPL
+mode-unsupported = Način { $mode } v tej različici ni na voljo.
+python-timeout = Čas za izračun je potekel
+python-reset = Seja Python je ponastavljena
+total-cleared = Tekoča vsota je ponastavljena
+session-saved = Seja »{ $name }« shranjena
+session-save-failed = Seje »{ $name }« ni bilo mogoče shraniti
+session-loaded = Seja »{ $name }« naložena
+session-not-found = Seja z imenom »{ $name }« ne obstaja
+session-load-failed = Seje »{ $name }« ni bilo mogoče naložiti
+output-more = Prikaži še { $lines }
+output-less = Prikaži manj
+output-traceback = Prikaži sled klicev
+file-reading = Branje datoteke…
+file-lines = Vrstice: { $lines }
+file-hash-copied = Zgoščena vrednost SHA-256 je kopirana
+result-copied = Kopirano
+file-error = Datoteke ni bilo mogoče prebrati
+capture-failed = Izraza ni bilo mogoče zajeti
+capture-empty = Besedilo ni bilo prepoznano.
+selection-failed = Izbora ni bilo mogoče izračunati
+selection-empty = Izbrano ni nobeno besedilo.
+sys-lock = Zakleni zaslon
+sys-sleep = Preklopi računalnik v stanje spanja
+sys-restart = Znova zaženi računalnik
+sys-shutdown = Izklopi računalnik
+sys-empty-trash = Izprazni koš
+sys-confirm = { $action }? Za potrditev znova pritisnite Enter
+sys-unknown = Neznan sistemski ukaz
+sys-failed = Sistemskega ukaza ni bilo mogoče izvesti
+kill-process = { $name } · PID { $pid } · CPE { $cpu } % · { $memory }
+kill-hint = ↑/↓ izbere proces, Enter ga ustavi
+kill-confirm = Ustavim { $name }? Za potrditev znova pritisnite Enter
+kill-no-match = Ni ujemajočih se procesov
+kill-done = { $name } je ustavljen
+kill-failed = { $name } ni bilo mogoče ustaviti: { $error }
+win-window = { $title } · { $app }
+win-hint = ↑/↓ izbere okno, Enter preklopi nanj
+win-no-match = Ni ujemajočih se oken
+win-list-failed = Odprtih oken ni bilo mogoče našteti
+win-focus-failed = Preklop na okno ni uspel
+go-bookmark = { $name } · { $url }
+go-hint = ↑/↓ izbere zaznamek, Enter ga odpre
+go-no-match = Ni ujemajočih se zaznamkov
+go-open-failed = { $name } ni bilo mogoče odpreti
+query-hint = Enter odpre { $url }
+query-failed = Spletnega iskanja ni bilo mogoče odpreti
+action-hint = Enter zažene { $command }
+action-started = { $name } se je zagnal
+action-failed = { $name } ni uspel
+timer-running = { $label } · še { $remaining }
+timer-unlabeled = Časovnik
+timer-hint = Vnesi trajanje, kot je 25m, 1h30m ali 4:30, in oznako
+timer-start = { $label } · Enter zažene { $duration }
+timer-started = { $label } · { $duration } zagnano
+timer-invalid = To ni trajanje, kot je 25m, 1h30m ali 4:30
+timer-done = Časovnik se je iztekel
+sw-started = Štoparica zagnana
+sw-stopped = Štoparica ustavljena pri { $elapsed }
+clock-local = Krajevni čas
+clock-time = { $city } · { $day } { $time } { $zone }
+clock-hint = Trenutni čas v ujemajočih se mestih
+clock-no-match = Ni ujemajočih se mest
+calendar-invalid-date = { $date } ni datum, kot je 2025-03-14
+calendar-invalid-year = { $year } ni leto od 1583 do 9999
+calendar-week = Teden { $week } leta { $year }
+calendar-easter = Velikonočna nedelja je { $date }
+calendar-workdays = Delovni dnevi: { $count }
+tip-invalid = Vnesi račun, npr. tip 84.60 18% /3
+tip-result = Napitnina { $tip } · skupaj { $total }
+tip-each = { $share } na osebo
+aspect-invalid = Vnesi ločljivost, npr. ar 2560x1440 27", ali razmerje, npr. ar 16:9 at 1200 wide
+aspect-ratio = { $ratio } ({ $decimal }:1)
+aspect-density = { $ppi } PPI pri { $diagonal }″
+aspect-scaled = { $width }×{ $height }
+percent-change = { $change } ({ $percent } %)
+percent-share = { $percent } %
+loan-invalid = Vnesi posojilo, npr. 250000 at 3.6% over 25y
+loan-result = Mesečni obrok { $payment } · skupne obresti { $interest }
+compound-invalid = Vnesi naložbo, npr. compound 10000 at 5% for 8y monthly
+compound-result = Prihodnja vrednost { $value } · obresti { $interest }
+tax-invalid = Vnesi znesek, npr. gross 120, net 100 reduced ali net 100 9.5%
+tax-no-rate = Nastavi davčno stopnjo v razdelku [tax] nastavitev
+tax-unknown-rate = V nastavitvah ni davčne stopnje z imenom { $rate }
+tax-breakdown = Neto { $net } + davek { $tax } ({ $rate } %) = bruto { $gross }
+size-invalid = { $amount } ni število
+size-converted = { $size } { $unit }
+size-ambiguous = { $decimal } { $unit } v potencah 1000 · { $binary } { $unit } v potencah 1024
+size-transfer = { $duration } za prenos
+size-transfer-ambiguous = { $decimal } za prenos v potencah 1000 · { $binary } v potencah 1024
+cron-every-minute = Vsako minuto
+cron-every-minutes = Vsakih { $step } minut
+cron-minutes = Ob minuti { $minutes }
+cron-at = Ob { $times }
+cron-every-hours = vsakih { $step } ur
+cron-hours = ob urah { $hours }
+cron-days = na dan { $days } v mesecu
+cron-months = v mesecih { $months }
+cron-weekdays = ob dnevih { $weekdays }
+cron-never = V naslednjih petih letih se ne izvede
+cron-field-count = Izraz cron ima pet polj: minuta, ura, dan v mesecu, mesec in dan v tednu
+cron-invalid-field = Neveljavno polje: { $field }
+geo-hint = Vnesi dva kraja, ločena z »to«, npr. 52.52,13.40 to London
+geo-unknown-place = Neznan kraj: { $place }
+geo-distance = { $km } km · { $mi } mi · { $arrow } { $bearing }°
+sun-times = Vzhod { $sunrise }, zahod { $sunset } { $zone } · { $hours } h { $minutes } min dnevne svetlobe
+sun-polar-day = Sonce ta dan ne zaide
+sun-polar-night = Sonce ta dan ne vzide
+wx-current = { $place } { $icon } { $temperature } °C, { $wind } km/h
+wx-hint = Pritisni Enter za prikaz vremena
+wx-loading = Pridobivanje vremena…
+wx-no-location = Vnesi kraj ali ga nastavi v razdelku [weather] nastavitev
+wx-unknown-location = Neznan kraj: { $location }
+wx-failed = Vremena ni bilo mogoče pridobiti: { $error }
+price-hint = Vnesi simbol, kot je btc ali AAPL, po želji z izračunom, kot je * 0.05
+price-loading = Pridobivanje cene { $symbol }…
+price-unknown = Cene { $symbol } ni bilo mogoče najti
+
+tray-show = Prikaži
+tray-mode = Način
+tray-capture = Zajemi izraz
+tray-autostart = Zaženi ob prijavi
+tray-config = Odpri nastavitve
+tray-about = O programu
+tray-update = Posodobitev
+tray-update-install = Namesti posodobitev { $version }
+tray-update-download = Prenesi posodobitev { $version }
+tray-exit = Izhod
+
+update-available = Na voljo je posodobitev Quicalc
+update-available-body = Na voljo je različica { $version }, prenesete jo lahko prek menija v sistemski vrstici.
+update-failed = Posodobitev Quicalc ni uspela
+
+hotkey-changed = Bližnjica za Quicalc je spremenjena
+hotkey-changed-body = { $default } že uporablja drug program, za odpiranje Quicalc pritisnite { $hotkey }.
+hotkey-unavailable = Bližnjica za Quicalc ni na voljo
+hotkey-unavailable-body = Vse nastavljene bližnjice že uporabljajo drugi programi, za odpiranje Quicalc uporabite ikono v sistemski vrstici.
+config-invalid = Neveljavne nastavitve za Quicalc
+config-restart = Za uveljavitev vseh sprememb znova zaženite Quicalc
+config-restart-body = Nekatere spremenjene nastavitve začnejo veljati šele po ponovnem zagonu Quicalc.
+portal-shortcut = Prikaži Quicalc
+
+onboarding-hotkey = Kjerkoli pritisnite { $hotkey } za odpiranje Quicalc in Esc za skrivanje.
+onboarding-modes = Vnesite »{ $python }« za Python ali »{ $kalk }« za kalkulator.
+onboarding-math = Kar začnite vnašati račun.
+
+help-commands = Ukazi (pritisnite Enter)
+help-calculator = Kalkulator
+help-keys = Tipke
+help-command-python = preklop v način Python
+help-command-sym = preklop v način simbolne matematike (zahteva SymPy)
+help-command-reset = ponastavitev seje Python (v načinu Python)
+help-command-kalk = preklop na kalkulator
+help-command-pad = preklopi na beležko, kjer se izračuna vsaka vrstica, »total« pa sešteje vrstice nad njim
+help-command-sys = preklopi na sistemske ukaze: { $commands }
+help-command-kill = iskanje med procesi, ki se izvajajo, in ustavitev enega
+help-command-win = iskanje med odprtimi okni in preklop na eno
+help-command-go = iskanje med zaznamki in odpiranje enega ali odpiranje najboljšega z go IME
+help-command-timer = zaženi odštevanje, ki obvesti, ko se izteče
+help-command-sw = zaženi ali ustavi štoparico
+help-command-clock = prikaz časa v drugih mestih in časovnih pasovih
+help-command-cron = razloži izraz cron in prikaži, kdaj se bo izvedel
+help-command-geo = prikaži razdaljo in smer med dvema koordinatama ali mestoma
+help-command-sun = prikaži sončni vzhod, zahod in dolžino dneva v kraju
+help-command-loan = prikaži mesečni obrok in skupne obresti posojila
+help-command-wx = prikaži vreme in kratko napoved
+help-command-price = prikaži ceno kriptovalute ali delnice, tudi v kalkulatorju
+help-command-clear = pozabi spremenljivke trenutnega načina in počisti rezultat
+help-command-session-save = shrani definicije, prejšnje vnose in vsoto pod imenom
+help-command-session-load = preklopi na shranjeno sejo
+help-command-help = prikaz tega pregleda
+help-command-about = prikaz različice in podatkov o gradnji
+help-key-open = odpiranje Quicalc
+help-key-hide = skrivanje Quicalc
+shortcuts-global = Kjerkoli
+shortcuts-window = V programu Quicalc
+shortcut-capture = zajemi izraz z zaslona
+shortcut-submit = izračunaj ali izvedi ukaz
+shortcut-new-line = začni novo vrstico (v načinu Python ali v beležki, kjer to stori Enter)
+shortcut-pad-submit = izvedi ukaz v beležki
+shortcut-complete = dopolni imena v načinu Python ter spletna iskanja in dejanja
+shortcut-undo = razveljavi zadnjo spremembo
+shortcut-redo = uveljavi zadnjo razveljavljeno spremembo
+shortcut-line-start = pojdi na začetek vrstice
+shortcut-line-end = pojdi na konec vrstice
+shortcut-kill-line = izbriši vrstico pred kazalcem
+shortcut-kill-word = izbriši besedo pred kazalcem
+shortcut-vi-normal = preklopi v običajni način vi
+shortcut-vi-insert = spet tipkaj, pred ali za kazalcem ali na začetku ali koncu vrstice
+shortcut-vi-move = premikaj kazalec v običajnem načinu
+shortcut-vi-edit = briši ali spreminjaj besedilo v običajnem načinu
+shortcut-output = prikaži ali skrij celoten izpis
+shortcut-copy = kopiraj rezultat
+shortcut-pin = pripni rezultat v lastno okno
+shortcut-on-top = obdrži okno nad drugimi ali tega ne počni več
+shortcut-shortcuts = prikaži bližnjice na tipkovnici
+help-fn-variable = definicija spremenljivke
+help-fn-function = definicija funkcije
+help-fn-root = kvadratni in kubični koren
+help-fn-trig = trigonometrija (radiani)
+help-fn-inverse-trig = inverzna trigonometrija
+help-fn-log = logaritmi in eksponentna funkcija
+help-fn-rounding = zaokroževanje in absolutna vrednost
+help-fn-comparison = primerjave in delitelji
+help-fn-factorial = fakulteta
+help-fn-sum = vsota
+help-fn-product = produkt
+help-fn-integral = določeni integral
+help-fn-derivative = odvod funkcije
+help-fn-constants = konstante
+help-fn-week = številka tedna ISO za datum
+help-fn-easter = datum velikonočne nedelje
+help-fn-workdays = delovni dnevi med datumoma, vključno z obema
+help-fn-tip = napitnina, skupni znesek in delež vsake osebe
+help-fn-aspect = razmerje stranic in gostota slikovnih pik ločljivosti ali stranica za razmerje
+help-fn-change = sprememba od ene vrednosti do druge, tudi v odstotkih
+help-fn-share = vrednost kot odstotek druge
+help-fn-compound = prihodnja vrednost naložbe z obrestnimi obrestmi
+help-fn-tax = razdeli bruto znesek na neto in davek ali dodaj davek neto znesku
+help-fn-sizes = pretvori velikosti podatkov, z obema pomenoma enot, kot je GB
+help-fn-transfer = koliko časa traja prenos velikosti podatkov pri hitrosti v bitih ali bajtih
+
+about-build = Gradnja: { $tag } ({ $profile }, { $os } { $arch })
+about-build-local = lokalna
+about-features = Funkcije: { $features }
+about-features-none = brez
+about-license = Licencirano pod { $license }, licence tretjih oseb so v attribution.html
@@ -0,0 +1,58 @@
+use std::env::consts::{ARCH, OS};
+
+use iced::{
+	Element,
+	widget::{column, text},
+};
+
+use crate::{Message, locale::tr};
+
+pub const BUILD_TAG: Option<&str> = option_env!("QUICALC_BUILD_TAG");
+
+pub const COMMANDS: [&str; 2] = ["about", "version"];
+
+fn features() -> String {
+	let features = [
+		cfg!(feature = "python").then_some("python"),
+		cfg!(feature = "python-embedded").then_some("python-embedded"),
+		cfg!(feature = "plots").then_some("plots"),
+		cfg!(feature = "sym").then_some("sym"),
+		cfg!(feature = "updater").then_some("updater"),
+		cfg!(feature = "weather").then_some("weather"),
+		cfg!(feature = "prices").then_some("prices"),
+		cfg!(feature = "ocr").then_some("ocr"),
+		cfg!(feature = "debug").then_some("debug"),
+	]
+	.into_iter()
+	.flatten()
+	.collect::<Vec<_>>();
+
+	if features.is_empty() {
+		tr!("about-features-none")
+	} else {
+		features.join(", ")
+	}
+}
+
+pub fn view<'a>() -> Element<'a, Message> {
+	column![
+		text(format!("Quicalc {}", env!("CARGO_PKG_VERSION"))).size(24),
+		text(tr!(
+			"about-build",
+			tag = BUILD_TAG.map_or_else(|| tr!("about-build-local"), str::to_string),
+			profile = if cfg!(debug_assertions) {
+				"debug"
+			} else {
+				"release"
+			},
+			os = OS,
+			arch = ARCH,
+		))
+		.size(14),
+		text(tr!("about-features", features = features())).size(14),
+		text(tr!("about-license", license = env!("CARGO_PKG_LICENSE"))).size(14),
+	]
+	.spacing(2)
+	.padding(4)
+	.into()
+}
@@ -0,0 +1,187 @@
+#[cfg(windows)]
+use std::iter;
+use std::{
+	collections::BTreeMap,
+	process::{Command, Stdio},
+	thread,
+};
+
+use tracing::{debug, warn};
+
+/// A shell command from the config, run by entering its name followed by its
+/// arguments, like `vpn up`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Action {
+	pub name: String,
+	pub command: String,
+	pub args: Vec<String>,
+}
+
+impl Action {
+	/// The action entered as `input`, with the rest of the input as its
+	/// arguments
+	pub fn parse(actions: &BTreeMap<String, String>, input: &str) -> Option<Self> {
+		let mut words = input.split_whitespace();
+		let name = words.next()?;
+		let command = actions.get(name)?;
+
+		Some(Self {
+			name: name.to_string(),
+			command: command.clone(),
+			args: words.map(str::to_string).collect(),
+		})
+	}
+
+	/// The command line run by this action
+	pub fn command_line(&self) -> String {
+		[self.command.as_str()]
+			.into_iter()
+			.chain(self.args.iter().map(String::as_str))
+			.collect::<Vec<_>>()
+			.join(" ")
+	}
+
+	/// Start this action in the background, without waiting for it to exit,
+	/// since it may keep running for as long as it likes
+	pub fn run(&self) -> Result<(), String> {
+		let mut child = shell(&self.command, &self.args)
+			.stdin(Stdio::null())
+			.stdout(Stdio::null())
+			.stderr(Stdio::null())
+			.spawn()
+			.map_err(|e| e.to_string())?;
+
+		let name = self.name.clone();
+
+		// the exit status is only waited for so that the process is cleaned up
+		thread::spawn(move || match child.wait() {
+			Ok(status) if status.success() => debug!(%name, "action exited"),
+			Ok(status) => warn!(%name, %status, "action exited unsuccessfully"),
+			Err(err) => warn!(?err, %name, "error waiting for action"),
+		});
+
+		Ok(())
+	}
+}
+
+/// The names of the actions starting with `word`, followed by a space for
+/// entering the arguments
+pub fn complete(actions: &BTreeMap<String, String>, word: &str) -> Vec<String> {
+	actions
+		.keys()
+		.filter(|name| name.starts_with(word))
+		.map(|name| format!("{name} "))
+		.collect()
+}
+
+/// Run `command` with `sh`, passing the arguments separately so they aren't
+/// interpreted by the shell
+#[cfg(not(windows))]
+fn shell(command: &str, args: &[String]) -> Command {
+	let mut shell = Command::new("sh");
+	shell
+		.arg("-c")
+		.arg(format!("{command} \"$@\""))
+		.arg("sh")
+		.args(args);
+	shell
+}
+
+/// Run `command` with `cmd`, escaping the arguments so that they're passed to
+/// the command as they are instead of being interpreted by `cmd`
+#[cfg(windows)]
+fn shell(command: &str, args: &[String]) -> Command {
+	use std::os::windows::process::CommandExt;
+
+	let line = iter::once(command.to_string())
+		.chain(args.iter().map(|arg| cmd_escape(&quote(arg))))
+		.collect::<Vec<_>>()
+		.join(" ");
+
+	let mut shell = Command::new("cmd.exe");
+	// with `/S`, only the outer quotes are removed, whatever the command is
+	shell.args(["/S", "/C"]).raw_arg(format!("\"{line}\""));
+	// CREATE_NO_WINDOW, to not flash a console window when starting
+	shell.creation_flags(0x0800_0000);
+	shell
+}
+
+/// Quote `arg` so that programs split their command line back into it, the
+/// way `CommandLineToArgvW` and the C runtime do
+#[cfg(windows)]
+fn quote(arg: &str) -> String {
+	if !arg.is_empty() && !arg.contains([' ', '\t', '\n', '\x0b', '"']) {
+		return arg.to_string();
+	}
+
+	let mut quoted = String::from('"');
+	let mut backslashes = 0;
+
+	for c in arg.chars() {
+		match c {
+			'\\' => backslashes += 1,
+			'"' => {
+				// the backslashes before a quote escape each other, and one more
+				// escapes the quote itself
+				quoted.extend(iter::repeat_n('\\', backslashes * 2 + 1));
+				quoted.push('"');
+				backslashes = 0;
+			}
+			c => {
+				quoted.extend(iter::repeat_n('\\', backslashes));
+				quoted.push(c);
+				backslashes = 0;
+			}
+		}
+	}
+
+	// the closing quote mustn't be escaped by backslashes at the end
+	quoted.extend(iter::repeat_n('\\', backslashes * 2));
+	quoted.push('"');
+	quoted
+}
+
+/// Escape the characters that `cmd` would interpret, including quotes, so
+/// that it doesn't treat anything as quoted and removes every escape
+#[cfg(windows)]
+fn cmd_escape(arg: &str) -> String {
+	let mut escaped = String::with_capacity(arg.len());
+
+	for c in arg.chars() {
+		if matches!(c, '(' | ')' | '%' | '!' | '^' | '"' | '<' | '>' | '&' | '|') {
+			escaped.push('^');
+		}
+
+		escaped.push(c);
+	}
+
+	escaped
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	#[cfg(windows)]
+	fn arguments_are_escaped_for_cmd() {
+		assert_eq!(cmd_escape(&quote("up")), "up");
+		assert_eq!(cmd_escape(&quote("a&b|c")), "a^&b^|c");
+		assert_eq!(cmd_escape(&quote("%PATH%")), "^%PATH^%");
+		assert_eq!(cmd_escape(&quote(r#"say "hi""#)), r#"^"say \^"hi\^"^""#);
+		assert_eq!(cmd_escape(&quote(r"C:\dir\ x\")), r#"^"C:\dir\ x\\^""#);
+	}
+
+	#[test]
+	#[cfg(not(windows))]
+	fn arguments_are_passed_as_they_are() {
+		let action = Action {
+			name: "check".to_string(),
+			command: "test \"$1\" = 'a;b' && test \"$2\" = '$(x)' && true".to_string(),
+			args: vec!["a;b".to_string(), "$(x)".to_string()],
+		};
+
+		let status = shell(&action.command, &action.args).status().unwrap();
+		assert!(status.success());
+	}
+}
@@ -0,0 +1,99 @@
+use crate::locale::tr;
+
+pub const COMMAND: &str = "ar";
+
+/// The aspect ratio of a resolution like `2560x1440`, with its pixel density
+/// at diagonals like `27"`, or the other side of a ratio like `16:9 at 1200
+/// wide`, or `None` if the input is empty
+pub fn eval(input: &str) -> Option<Result<String, String>> {
+	if input.trim().is_empty() {
+		return None;
+	}
+
+	Some(calculate(input).ok_or_else(|| tr!("aspect-invalid")))
+}
+
+fn calculate(input: &str) -> Option<String> {
+	let words = input.split_whitespace().collect::<Vec<_>>();
+	let (&size, rest) = words.split_first()?;
+	let (width, height) = size.split_once(['x', '×', ':'])?;
+	let (width, height) = (number(width)?, number(height)?);
+
+	match rest {
+		["at", side, "wide" | "w"] => {
+			let side = number(side)?;
+			Some(scaled(side, side * height / width))
+		}
+		["at", side, "high" | "tall" | "h"] => {
+			let side = number(side)?;
+			Some(scaled(side * width / height, side))
+		}
+		diagonals => {
+			let diagonal = (width * width + height * height).sqrt();
+
+			[Some(ratio(width, height))]
+				.into_iter()
+				.chain(diagonals.iter().map(|&inches| {
+					let inches = number(
+						inches
+							.strip_suffix(['"', '″'])
+							.or_else(|| inches.strip_suffix("in"))
+							.unwrap_or(inches),
+					)?;
+
+					Some(tr!(
+						"aspect-density",
+						ppi = format!("{:.0}", diagonal / inches),
+						diagonal = trim(inches)
+					))
+				}))
+				.collect::<Option<Vec<_>>>()
+				.map(|parts| parts.join(" · "))
+		}
+	}
+}
+
+/// A ratio in its lowest terms, like `16:9` for `2560x1440`, and as a
+/// decimal, like `1.78:1`
+fn ratio(width: f64, height: f64) -> String {
+	let ratio = if width.fract() == 0.0 && height.fract() == 0.0 {
+		let divisor = gcd(width as u64, height as u64).max(1) as f64;
+		format!("{}:{}", width / divisor, height / divisor)
+	} else {
+		format!("{}:{}", trim(width), trim(height))
+	};
+
+	tr!(
+		"aspect-ratio",
+		ratio = ratio,
+		decimal = trim(width / height)
+	)
+}
+
+/// A resolution with whole pixels, like `1200×675`
+fn scaled(width: f64, height: f64) -> String {
+	tr!(
+		"aspect-scaled",
+		width = format!("{width:.0}"),
+		height = format!("{height:.0}")
+	)
+}
+
+const fn gcd(a: u64, b: u64) -> u64 {
+	if b == 0 { a } else { gcd(b, a % b) }
+}
+
+/// A positive number, like a side of a resolution or a diagonal
+fn number(word: &str) -> Option<f64> {
+	word.parse::<f64>()
+		.ok()
+		.filter(|number| number.is_finite() && *number > 0.0)
+}
+
+/// A number with at most 2 decimals, and none if they're zeros
+fn trim(number: f64) -> String {
+	format!("{number:.2}")
+		.trim_end_matches('0')
+		.trim_end_matches('.')
+		.to_string()
+}
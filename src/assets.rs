@@ -0,0 +1,62 @@
+use std::{sync::LazyLock, thread};
+
+use image::{DynamicImage, ImageFormat, imageops::FilterType};
+use tracing::{error, info, warn};
+
+use crate::{QuicalcMode, paths, startup};
+
+pub static ICON: LazyLock<DynamicImage> =
+	LazyLock::new(|| decode(include_bytes!("../assets/icon.png"), "program icon"));
+pub static ICON_MONO: LazyLock<DynamicImage> = LazyLock::new(|| {
+	decode(
+		include_bytes!("../assets/icon-mono.png"),
+		"monochrome program icon",
+	)
+});
+
+fn decode(bytes: &[u8], name: &str) -> DynamicImage {
+	image::load_from_memory_with_format(bytes, ImageFormat::Png)
+		.inspect_err(|err| error!(?err, name, "error loading image"))
+		.unwrap_or_default()
+}
+
+/// Load a mode indicator, preferring a PNG image with the same name in the
+/// `indicators` directory next to the config file over the built-in one
+///
+/// Custom images are scaled down to fit the size of the built-in ones.
+pub fn indicator(name: &str, builtin: &[u8]) -> DynamicImage {
+	let builtin = decode(builtin, name);
+
+	paths::config()
+		.map(|dir| dir.join("indicators").join(format!("{name}.png")))
+		.filter(|path| path.exists())
+		.and_then(|path| {
+			image::open(&path)
+				.inspect(|_| info!(?path, "using custom indicator"))
+				.inspect_err(|err| warn!(?err, ?path, "error loading custom indicator"))
+				.ok()
+		})
+		.map(|custom| custom.resize(builtin.width(), builtin.height(), FilterType::Lanczos3))
+		.unwrap_or(builtin)
+}
+
+/// Decode images on a separate thread, so that they're usually ready by the
+/// time the window or tray icon needs them
+///
+/// Images that are needed before then are decoded when they're first used.
+pub fn preload() {
+	thread::Builder::new()
+		.name("assets".to_string())
+		.spawn(|| {
+			LazyLock::force(&ICON);
+			LazyLock::force(&ICON_MONO);
+
+			for mode in QuicalcMode::ALL {
+				mode.indicator();
+			}
+
+			startup::phase("assets decoded");
+		})
+		.inspect_err(|err| error!(?err, "error spawning asset loading thread"))
+		.ok();
+}
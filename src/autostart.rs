@@ -0,0 +1,132 @@
+use std::{env, path::PathBuf};
+
+use cfg_if::cfg_if;
+use tracing::{error, info, warn};
+
+const NAME: &str = "Quicalc";
+
+fn executable() -> Result<PathBuf, String> {
+	env::current_exe().map_err(|e| e.to_string())
+}
+
+cfg_if! {
+	if #[cfg(windows)] {
+		use winreg::{
+			RegKey,
+			enums::{HKEY_CURRENT_USER, KEY_READ, KEY_SET_VALUE},
+		};
+
+		const RUN_KEY: &str = r"Software\Microsoft\Windows\CurrentVersion\Run";
+
+		fn run_key() -> Result<RegKey, String> {
+			RegKey::predef(HKEY_CURRENT_USER)
+				.open_subkey_with_flags(RUN_KEY, KEY_READ | KEY_SET_VALUE)
+				.map_err(|e| e.to_string())
+		}
+
+		fn command() -> Result<String, String> {
+			Ok(format!("\"{}\"", executable()?.display()))
+		}
+
+		pub fn is_enabled() -> bool {
+			run_key().is_ok_and(|key| key.get_value::<String, _>(NAME).is_ok())
+		}
+
+		fn is_current() -> bool {
+			run_key().ok().and_then(|key| key.get_value::<String, _>(NAME).ok()) == command().ok()
+		}
+
+		pub fn enable() -> Result<(), String> {
+			run_key()?.set_value(NAME, &command()?).map_err(|e| e.to_string())
+		}
+
+		pub fn disable() -> Result<(), String> {
+			run_key()?.delete_value(NAME).map_err(|e| e.to_string())
+		}
+	} else {
+		use std::{fs, io::ErrorKind, path::Path};
+
+		cfg_if! {
+			if #[cfg(target_os = "macos")] {
+				fn entry_path() -> Option<PathBuf> {
+					dirs::home_dir().map(|dir| {
+						dir.join("Library")
+							.join("LaunchAgents")
+							.join("dev.janm.quicalc.plist")
+					})
+				}
+
+				fn entry(executable: &Path) -> String {
+					format!(
+						r#"<?xml version="1.0" encoding="UTF-8"?>
+<!DOCTYPE plist PUBLIC "-//Apple//DTD PLIST 1.0//EN" "http://www.apple.com/DTDs/PropertyList-1.0.dtd">
+<plist version="1.0">
+<dict>
+	<key>Label</key>
+	<string>dev.janm.quicalc</string>
+	<key>ProgramArguments</key>
+	<array>
+		<string>{}</string>
+	</array>
+	<key>RunAtLoad</key>
+	<true/>
+</dict>
+</plist>
+"#,
+						executable.display()
+					)
+				}
+			} else {
+				fn entry_path() -> Option<PathBuf> {
+					dirs::config_dir().map(|dir| dir.join("autostart").join("quicalc.desktop"))
+				}
+
+				fn entry(executable: &Path) -> String {
+					format!(
+						"[Desktop Entry]\nType=Application\nName={NAME}\nExec=\"{}\"\nX-GNOME-Autostart-enabled=true\n",
+						executable.display()
+					)
+				}
+			}
+		}
+
+		pub fn is_enabled() -> bool {
+			entry_path().is_some_and(|path| path.exists())
+		}
+
+		fn is_current() -> bool {
+			let current = entry_path().and_then(|path| fs::read_to_string(path).ok());
+			current.is_some() && current == executable().ok().map(|executable| entry(&executable))
+		}
+
+		pub fn enable() -> Result<(), String> {
+			let path = entry_path().ok_or("could not determine autostart location")?;
+
+			if let Some(parent) = path.parent() {
+				fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+			}
+
+			fs::write(path, entry(&executable()?)).map_err(|e| e.to_string())
+		}
+
+		pub fn disable() -> Result<(), String> {
+			let path = entry_path().ok_or("could not determine autostart location")?;
+
+			match fs::remove_file(path) {
+				Err(err) if err.kind() != ErrorKind::NotFound => Err(err.to_string()),
+				_ => Ok(()),
+			}
+		}
+	}
+}
+
+pub fn verify() {
+	if is_enabled() && !is_current() {
+		warn!("start at login entry points to a different executable, updating it");
+
+		enable()
+			.inspect(|_| info!("updated start at login entry"))
+			.inspect_err(|err| error!(?err, "error updating start at login entry"))
+			.ok();
+	}
+}
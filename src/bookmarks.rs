@@ -0,0 +1,132 @@
+use std::{fs, path::PathBuf};
+
+use serde_json::Value;
+use tracing::{debug, error};
+
+use crate::{
+	config::{Browser, GoConfig},
+	fuzzy::Searchable,
+	locale::tr,
+};
+
+/// Opens the best matching bookmark from any mode, like `go jira`
+pub const COMMAND: &str = "go";
+
+/// A named URL opened from the go mode
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Bookmark {
+	pub name: String,
+	pub url: String,
+}
+
+impl Searchable for Bookmark {
+	type Id = String;
+
+	fn id(&self) -> String {
+		self.url.clone()
+	}
+
+	fn text(&self) -> String {
+		self.name.clone()
+	}
+
+	fn summary(&self) -> String {
+		tr!(
+			"go-bookmark",
+			name = self.name.as_str(),
+			url = self.url.as_str()
+		)
+	}
+}
+
+/// The bookmarks from the config, followed by the imported ones
+pub fn load(config: &GoConfig) -> Vec<Bookmark> {
+	let mut bookmarks = config
+		.bookmarks
+		.iter()
+		.map(|(name, url)| Bookmark {
+			name: name.clone(),
+			url: url.clone(),
+		})
+		.collect::<Vec<_>>();
+
+	if let Some(browser) = config.import {
+		import(browser)
+			.inspect(|imported| debug!(?browser, count = imported.len(), "imported bookmarks"))
+			.inspect_err(|err| error!(?err, ?browser, "error importing bookmarks"))
+			.map(|imported| bookmarks.extend(imported))
+			.ok();
+	}
+
+	bookmarks
+}
+
+pub fn open(bookmark: &Bookmark) -> Result<(), String> {
+	open::that(&bookmark.url).map_err(|e| e.to_string())
+}
+
+/// The bookmarks saved in the browser's default profile
+fn import(browser: Browser) -> Result<Vec<Bookmark>, String> {
+	let path = bookmarks_path(browser).ok_or("could not determine bookmarks file location")?;
+	let contents = fs::read_to_string(&path).map_err(|e| e.to_string())?;
+	let bookmarks = serde_json::from_str::<Value>(&contents).map_err(|e| e.to_string())?;
+
+	let mut imported = Vec::new();
+
+	for root in bookmarks["roots"]
+		.as_object()
+		.into_iter()
+		.flat_map(|roots| roots.values())
+	{
+		collect(root, &mut imported);
+	}
+
+	Ok(imported)
+}
+
+/// Add the bookmarks in a bookmark or folder to `bookmarks`
+fn collect(node: &Value, bookmarks: &mut Vec<Bookmark>) {
+	if let (Some(name), Some(url)) = (node["name"].as_str(), node["url"].as_str()) {
+		bookmarks.push(Bookmark {
+			name: name.to_string(),
+			url: url.to_string(),
+		});
+	}
+
+	for child in node["children"].as_array().into_iter().flatten() {
+		collect(child, bookmarks);
+	}
+}
+
+fn bookmarks_path(browser: Browser) -> Option<PathBuf> {
+	let profiles = if cfg!(windows) {
+		let dir = match browser {
+			Browser::Chrome => "Google/Chrome",
+			Browser::Chromium => "Chromium",
+			Browser::Edge => "Microsoft/Edge",
+			Browser::Brave => "BraveSoftware/Brave-Browser",
+		};
+
+		dirs::data_local_dir()?.join(dir).join("User Data")
+	} else if cfg!(target_os = "macos") {
+		let dir = match browser {
+			Browser::Chrome => "Google/Chrome",
+			Browser::Chromium => "Chromium",
+			Browser::Edge => "Microsoft Edge",
+			Browser::Brave => "BraveSoftware/Brave-Browser",
+		};
+
+		dirs::config_dir()?.join(dir)
+	} else {
+		let dir = match browser {
+			Browser::Chrome => "google-chrome",
+			Browser::Chromium => "chromium",
+			Browser::Edge => "microsoft-edge",
+			Browser::Brave => "BraveSoftware/Brave-Browser",
+		};
+
+		dirs::config_dir()?.join(dir)
+	};
+
+	Some(profiles.join("Default").join("Bookmarks"))
+}
@@ -0,0 +1,42 @@
+use std::{
+	collections::{HashMap, VecDeque},
+	hash::Hash,
+};
+
+const CAPACITY: usize = 256;
+
+/// A map of recent results, which forgets the oldest ones first
+#[derive(Debug)]
+pub struct Cache<K, V> {
+	entries: HashMap<K, V>,
+	order: VecDeque<K>,
+}
+
+impl<K, V> Default for Cache<K, V> {
+	fn default() -> Self {
+		Self {
+			entries: HashMap::new(),
+			order: VecDeque::new(),
+		}
+	}
+}
+
+impl<K: Clone + Eq + Hash, V> Cache<K, V> {
+	pub fn get(&self, key: &K) -> Option<&V> {
+		self.entries.get(key)
+	}
+
+	pub fn insert(&mut self, key: K, value: V) {
+		if self.entries.insert(key.clone(), value).is_some() {
+			return;
+		}
+
+		self.order.push_back(key);
+
+		if self.order.len() > CAPACITY
+			&& let Some(oldest) = self.order.pop_front()
+		{
+			self.entries.remove(&oldest);
+		}
+	}
+}
@@ -0,0 +1,205 @@
+use std::collections::{BTreeSet, HashSet};
+
+use jiff::{
+	ToSpan, Zoned,
+	civil::{Date, Weekday as JiffWeekday},
+};
+use tracing::warn;
+
+use crate::{
+	config::{CalendarConfig, Weekday},
+	locale::tr,
+};
+
+/// Evaluate a calendar helper, like `week 2025-03-14`, `easter 2026`, or
+/// `workdays between 2025-01-02 and 2025-02-01`, or return `None` if the
+/// input isn't one
+pub fn eval(input: &str, config: &CalendarConfig) -> Option<Result<String, String>> {
+	let words = input.split_whitespace().collect::<Vec<_>>();
+
+	match words.as_slice() {
+		["week", date] => Some(week(date)),
+		["easter", year] => Some(easter(year)),
+		["workdays", "between", start, "and", end] | ["workdays", start, end] => {
+			Some(workdays(start, end, config))
+		}
+		_ => None,
+	}
+}
+
+/// A date like `2025-03-14`, or `today`
+pub fn parse_date(text: &str) -> Result<Date, String> {
+	if text == "today" {
+		return Ok(Zoned::now().date());
+	}
+
+	text.parse()
+		.map_err(|_| tr!("calendar-invalid-date", date = text))
+}
+
+/// The ISO week a date is in
+fn week(date: &str) -> Result<String, String> {
+	let week = parse_date(date)?.iso_week_date();
+
+	Ok(tr!(
+		"calendar-week",
+		week = week.week(),
+		year = week.year().to_string()
+	))
+}
+
+/// The date of (western) Easter Sunday, with the anonymous Gregorian
+/// algorithm
+fn easter(year: &str) -> Result<String, String> {
+	let year = year
+		.parse::<i16>()
+		.ok()
+		.filter(|year| (1583..=9999).contains(year))
+		.ok_or_else(|| tr!("calendar-invalid-year", year = year))?;
+
+	let a = year % 19;
+	let (b, c) = (year / 100, year % 100);
+	let (d, e) = (b / 4, b % 4);
+	let f = (b + 8) / 25;
+	let g = (b - f + 1) / 3;
+	let h = (19 * a + b - d - g + 15) % 30;
+	let (i, k) = (c / 4, c % 4);
+	let l = (32 + 2 * e + 2 * i - h - k) % 7;
+	let m = (a + 11 * h + 22 * l) / 451;
+	let month = (h + l - 7 * m + 114) / 31;
+	let day = (h + l - 7 * m + 114) % 31 + 1;
+
+	let date = Date::new(year, month as i8, day as i8).map_err(|e| e.to_string())?;
+
+	Ok(tr!("calendar-easter", date = date.to_string()))
+}
+
+/// The number of days between two dates (including both) that aren't on the
+/// weekend or holidays
+fn workdays(start: &str, end: &str, config: &CalendarConfig) -> Result<String, String> {
+	let (start, end) = (parse_date(start)?, parse_date(end)?);
+	let (start, end) = (start.min(end), start.max(end));
+
+	let weekend = config
+		.weekend
+		.iter()
+		.map(|&day| weekday(day))
+		.collect::<HashSet<_>>();
+	let is_weekend = |date: Date| weekend.contains(&date.weekday());
+
+	let days = start.until(end).map_err(|e| e.to_string())?.get_days() + 1;
+
+	// every full week has the same number of workdays
+	let mut count = days / 7 * (7 - weekend.len() as i32);
+
+	// and the days left over are counted from the end
+	count += end
+		.series(-1.day())
+		.take((days % 7) as usize)
+		.filter(|&date| !is_weekend(date))
+		.count() as i32;
+
+	let holidays = config
+		.holidays
+		.iter()
+		.filter_map(|holiday| {
+			holiday
+				.parse::<Date>()
+				.inspect_err(|err| warn!(?err, %holiday, "invalid holiday in config"))
+				.ok()
+		})
+		.filter(|&holiday| (start..=end).contains(&holiday) && !is_weekend(holiday))
+		.collect::<BTreeSet<_>>();
+
+	count -= holidays.len() as i32;
+
+	Ok(tr!("calendar-workdays", count = count))
+}
+
+const fn weekday(day: Weekday) -> JiffWeekday {
+	match day {
+		Weekday::Monday => JiffWeekday::Monday,
+		Weekday::Tuesday => JiffWeekday::Tuesday,
+		Weekday::Wednesday => JiffWeekday::Wednesday,
+		Weekday::Thursday => JiffWeekday::Thursday,
+		Weekday::Friday => JiffWeekday::Friday,
+		Weekday::Saturday => JiffWeekday::Saturday,
+		Weekday::Sunday => JiffWeekday::Sunday,
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use std::time::{Duration, Instant};
+
+	use proptest::prelude::*;
+
+	use super::*;
+
+	const DATE: &str = "-?[0-9]{4}-[0-9]{2}-[0-9]{2}|today";
+
+	#[test]
+	fn easter_sunday() {
+		assert_eq!(
+			easter("2026"),
+			Ok(tr!("calendar-easter", date = "2026-04-05".to_string()))
+		);
+		assert_eq!(
+			easter("1582"),
+			Err(tr!("calendar-invalid-year", year = "1582"))
+		);
+	}
+
+	#[test]
+	fn workdays_in_a_week() {
+		assert_eq!(
+			eval(
+				"workdays between 2025-03-10 and 2025-03-16",
+				&CalendarConfig::default()
+			),
+			Some(Ok(tr!("calendar-workdays", count = 5)))
+		);
+	}
+
+	#[test]
+	fn workdays_from_the_first_date() {
+		assert_eq!(
+			workdays(
+				&Date::MIN.to_string(),
+				&Date::MIN.tomorrow().unwrap().to_string(),
+				&CalendarConfig::default()
+			),
+			Ok(tr!("calendar-workdays", count = 2))
+		);
+	}
+
+	proptest! {
+		#[test]
+		fn eval_any_input(input in any::<String>()) {
+			let _ = eval(&input, &CalendarConfig::default());
+		}
+
+		#[test]
+		fn eval_any_date(date in DATE, year in "-?[0-9]{1,5}") {
+			let config = CalendarConfig::default();
+			let (week, easter) = (format!("week {date}"), format!("easter {year}"));
+
+			prop_assert!(eval(&week, &config).is_some());
+			prop_assert!(eval(&easter, &config).is_some());
+		}
+
+		#[test]
+		fn workdays_between_any_dates(
+			(year, month, day) in (-9999_i16..=9999, 1_i8..=12, 1_i8..=28),
+			days in 0_i64..4000,
+		) {
+			let start = Date::new(year, month, day).unwrap();
+			let end = start.saturating_add(days.days());
+			let instant = Instant::now();
+			let result = workdays(&start.to_string(), &end.to_string(), &CalendarConfig::default());
+
+			prop_assert!(result.is_ok(), "{result:?}");
+			prop_assert!(instant.elapsed() < Duration::from_secs(1));
+		}
+	}
+}
@@ -0,0 +1,159 @@
+use std::{
+	path::{Path, PathBuf},
+	process::Command,
+};
+
+use tracing::debug;
+
+use crate::config::OcrConfig;
+
+#[cfg(any(windows, target_os = "macos"))]
+const FILE_NAME: &str = "quicalc-capture.png";
+
+/// Let the user select a region of the screen, and recognize the text in it
+///
+/// Returns `None` if the selection was cancelled.
+pub async fn capture(config: OcrConfig) -> Result<Option<String>, String> {
+	let Some(image) = screenshot().await? else {
+		debug!("screenshot cancelled");
+		return Ok(None);
+	};
+
+	tokio::task::spawn_blocking(move || recognize(&config.tesseract, &image))
+		.await
+		.map_err(|e| e.to_string())?
+		.map(Some)
+}
+
+/// Recognize text with tesseract, joining it into a single line
+fn recognize(tesseract: &Path, image: &Path) -> Result<String, String> {
+	let mut command = Command::new(tesseract);
+	command.arg(image).args(["stdout", "--psm", "6"]);
+
+	#[cfg(windows)]
+	{
+		use std::os::windows::process::CommandExt;
+
+		// CREATE_NO_WINDOW, to not flash a console window when starting
+		command.creation_flags(0x0800_0000);
+	}
+
+	let output = command.output().map_err(|e| e.to_string())?;
+
+	if !output.status.success() {
+		return Err(String::from_utf8_lossy(&output.stderr).trim().to_string());
+	}
+
+	Ok(String::from_utf8_lossy(&output.stdout)
+		.split_whitespace()
+		.collect::<Vec<_>>()
+		.join(" "))
+}
+
+/// Take a screenshot of a region using the system's own selection
+#[cfg(target_os = "macos")]
+async fn screenshot() -> Result<Option<PathBuf>, String> {
+	let path = std::env::temp_dir().join(FILE_NAME);
+
+	tokio::task::spawn_blocking(move || {
+		std::fs::remove_file(&path).ok();
+
+		Command::new("screencapture")
+			.args(["-i", "-x"])
+			.arg(&path)
+			.status()
+			.map_err(|e| e.to_string())?;
+
+		// nothing is saved if the selection is cancelled
+		Ok(path.exists().then_some(path))
+	})
+	.await
+	.map_err(|e| e.to_string())?
+}
+
+/// Take a screenshot of a region using the system's own selection
+#[cfg(target_os = "linux")]
+async fn screenshot() -> Result<Option<PathBuf>, String> {
+	use ashpd::{
+		Error,
+		desktop::{ResponseError, screenshot::Screenshot},
+	};
+
+	let response = Screenshot::request()
+		.interactive(true)
+		.modal(true)
+		.send()
+		.await
+		.and_then(|request| request.response());
+
+	match response {
+		Ok(screenshot) => screenshot
+			.uri()
+			.to_file_path()
+			.map(Some)
+			.map_err(|()| format!("screenshot is not a file: {}", screenshot.uri())),
+		Err(Error::Response(ResponseError::Cancelled)) => Ok(None),
+		Err(err) => Err(err.to_string()),
+	}
+}
+
+/// Take a screenshot of a region using the system's own selection
+///
+/// The snipping tool only copies the screenshot to the clipboard, so the
+/// clipboard is watched until a new image appears there.
+#[cfg(windows)]
+async fn screenshot() -> Result<Option<PathBuf>, String> {
+	use std::{
+		thread,
+		time::{Duration, Instant},
+	};
+
+	use image::{ImageFormat, RgbaImage};
+
+	const TIMEOUT: Duration = Duration::from_secs(60);
+	const POLL_INTERVAL: Duration = Duration::from_millis(200);
+
+	let path = std::env::temp_dir().join(FILE_NAME);
+
+	tokio::task::spawn_blocking(move || {
+		let mut clipboard = arboard::Clipboard::new().map_err(|e| e.to_string())?;
+		let previous = clipboard
+			.get_image()
+			.ok()
+			.map(|image| image.bytes.into_owned());
+
+		open::that("ms-screenclip:").map_err(|e| e.to_string())?;
+
+		let started = Instant::now();
+		while started.elapsed() < TIMEOUT {
+			thread::sleep(POLL_INTERVAL);
+
+			let Ok(image) = clipboard.get_image() else {
+				continue;
+			};
+
+			if previous.as_deref() == Some(&*image.bytes) {
+				continue;
+			}
+
+			let width = u32::try_from(image.width).map_err(|e| e.to_string())?;
+			let height = u32::try_from(image.height).map_err(|e| e.to_string())?;
+
+			RgbaImage::from_raw(width, height, image.bytes.into_owned())
+				.ok_or("invalid screenshot")?
+				.save_with_format(&path, ImageFormat::Png)
+				.map_err(|e| e.to_string())?;
+
+			return Ok(Some(path));
+		}
+
+		Ok(None)
+	})
+	.await
+	.map_err(|e| e.to_string())?
+}
+
+#[cfg(not(any(windows, target_os = "macos", target_os = "linux")))]
+async fn screenshot() -> Result<Option<PathBuf>, String> {
+	Err("capturing the screen is not supported on this platform".to_string())
+}
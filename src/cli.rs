@@ -0,0 +1,121 @@
+use std::{env, path::PathBuf};
+
+use crate::{autostart, paths, settings};
+
+pub const USAGE: &str = "\
+Usage: quicalc [OPTIONS]
+
+Options:
+  --profile <NAME>            Use the separate files of an existing profile
+  --new-profile <NAME>        Create a profile and use it
+  --software-rendering        Render without the GPU
+  --startup-profile           Print how long each phase of startup took
+  --capture                   Capture an expression from the screen right away (with OCR)
+  --install-autostart         Start quicalc when logging in
+  --uninstall-autostart       Stop starting quicalc when logging in
+  --export-settings <PATH>    Write the settings to an archive
+  --import-settings <PATH>    Replace the settings with the ones in an archive
+  --paths                     Print where quicalc keeps its files
+  -h, --help                  Print this help";
+
+#[derive(Debug, Default)]
+pub struct Args {
+	pub action: Option<Action>,
+	pub software_rendering: bool,
+	pub startup_profile: bool,
+	/// The name of the profile with a separate config
+	pub profile: Option<String>,
+	/// Create the profile if it doesn't exist yet
+	pub new_profile: bool,
+	/// Capture an expression from the screen right away
+	pub capture: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Action {
+	InstallAutostart,
+	UninstallAutostart,
+	/// Write the settings to an archive at the given path
+	ExportSettings(PathBuf),
+	/// Replace the settings with the ones in the archive at the given path
+	ImportSettings(PathBuf),
+	/// Print where quicalc keeps its files
+	PrintPaths,
+	PrintUsage,
+}
+
+impl Args {
+	pub fn parse() -> Result<Self, String> {
+		let mut args = Self::default();
+
+		let mut iter = env::args().skip(1);
+
+		while let Some(arg) = iter.next() {
+			match arg.as_str() {
+				"--install-autostart" => args.action = Some(Action::InstallAutostart),
+				"--uninstall-autostart" => args.action = Some(Action::UninstallAutostart),
+				"--export-settings" => {
+					let path = iter
+						.next()
+						.ok_or("missing archive path for --export-settings")?;
+					args.action = Some(Action::ExportSettings(path.into()));
+				}
+				"--import-settings" => {
+					let path = iter
+						.next()
+						.ok_or("missing archive path for --import-settings")?;
+					args.action = Some(Action::ImportSettings(path.into()));
+				}
+				"--profile" | "--new-profile" => {
+					let name = iter
+						.next()
+						.ok_or_else(|| format!("missing name for {arg}"))?;
+
+					if !paths::is_valid_profile(&name) {
+						return Err(format!(
+							"invalid profile name: {name} (names can contain letters, digits, \
+							 `-`, and `_`)"
+						));
+					}
+
+					args.profile = Some(name);
+					args.new_profile = arg == "--new-profile";
+				}
+				"--paths" => args.action = Some(Action::PrintPaths),
+				"-h" | "--help" => args.action = Some(Action::PrintUsage),
+				"--software-rendering" => args.software_rendering = true,
+				"--startup-profile" => args.startup_profile = true,
+				#[cfg(feature = "ocr")]
+				"--capture" => args.capture = true,
+				_ => return Err(format!("unknown argument: {arg}")),
+			}
+		}
+
+		Ok(args)
+	}
+}
+
+impl Action {
+	pub fn run(&self) -> Result<(), String> {
+		match self {
+			Self::InstallAutostart => autostart::enable(),
+			Self::UninstallAutostart => autostart::disable(),
+			Self::ExportSettings(path) => settings::export(path),
+			Self::ImportSettings(path) => settings::import(path),
+			Self::PrintPaths => {
+				for (name, path) in paths::all() {
+					match path {
+						Some(path) => println!("{name}: {}", path.display()),
+						None => println!("{name}: unknown"),
+					}
+				}
+
+				Ok(())
+			}
+			Self::PrintUsage => {
+				println!("{USAGE}");
+				Ok(())
+			}
+		}
+	}
+}
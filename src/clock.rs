@@ -0,0 +1,109 @@
+use jiff::{Timestamp, tz::TimeZone};
+use tracing::warn;
+
+use crate::{
+	config::ClockConfig,
+	fuzzy::{Search, Searchable},
+	locale::tr,
+};
+
+/// A time zone, named after its city, like `New York` for `America/New_York`
+#[derive(Debug, Clone)]
+pub struct Zone {
+	/// The time zone's IANA name
+	pub name: String,
+	pub city: String,
+	tz: TimeZone,
+}
+
+impl Zone {
+	fn new(name: &str, tz: TimeZone) -> Self {
+		let city = name.rsplit('/').next().unwrap_or(name).replace('_', " ");
+
+		Self {
+			name: name.to_string(),
+			city,
+			tz,
+		}
+	}
+
+	fn local() -> Self {
+		let tz = TimeZone::system();
+
+		Self {
+			name: tz.iana_name().unwrap_or_default().to_string(),
+			city: tr!("clock-local"),
+			tz,
+		}
+	}
+}
+
+impl Searchable for Zone {
+	type Id = String;
+
+	fn id(&self) -> String {
+		self.name.clone()
+	}
+
+	fn text(&self) -> String {
+		// the city first, so that it's preferred over the region
+		format!("{} {}", self.city, self.name.replace(['/', '_'], " "))
+	}
+
+	/// The city with its current time and time zone abbreviation
+	fn summary(&self) -> String {
+		let now = Timestamp::now().to_zoned(self.tz.clone());
+
+		tr!(
+			"clock-time",
+			city = self.city.as_str(),
+			time = now.strftime("%H:%M").to_string(),
+			day = now.strftime("%a").to_string(),
+			zone = now.strftime("%Z").to_string()
+		)
+	}
+}
+
+/// All of the known time zones, and the ones from the config after the local
+/// one
+pub fn list(config: &ClockConfig) -> (Vec<Zone>, Vec<Zone>) {
+	let db = jiff::tz::db();
+
+	let zones = db
+		.available()
+		// zones like `EST5EDT` or `Etc/GMT+2` aren't cities
+		.filter(|name| name.as_str().contains('/') && !name.as_str().starts_with("Etc/"))
+		.filter_map(|name| Some(Zone::new(name.as_str(), db.get(name.as_str()).ok()?)))
+		.collect::<Vec<_>>();
+
+	let shown = [Zone::local()]
+		.into_iter()
+		.chain(config.zones.iter().filter_map(|name| {
+			let zone = find(&zones, name);
+
+			if zone.is_none() {
+				warn!(%name, "unknown time zone in config");
+			}
+
+			zone
+		}))
+		.collect();
+
+	(zones, shown)
+}
+
+/// The zone with an IANA name like `Europe/Ljubljana`, or the best match for a
+/// city name like `ljubljana`
+fn find(zones: &[Zone], name: &str) -> Option<Zone> {
+	if let Some(zone) = zones
+		.iter()
+		.find(|zone| zone.name.eq_ignore_ascii_case(name))
+	{
+		return Some(zone.clone());
+	}
+
+	let mut search = Search::default();
+	search.set_items(zones.to_vec());
+	search.search(name);
+	search.selected().cloned()
+}
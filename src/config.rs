@@ -0,0 +1,596 @@
+use std::{collections::BTreeMap, env, fs, io::ErrorKind, path::PathBuf};
+
+use global_hotkey::hotkey::{Code, HotKey, Modifiers};
+use serde::{Deserialize, Serialize};
+use toml::{Table, Value};
+use tracing::{debug, error, info, warn};
+
+use crate::paths;
+
+/// The prefix of environment variables overriding config options
+const ENV_PREFIX: &str = "QUICALC_";
+/// Environment variables with the prefix that aren't config options
+const ENV_IGNORED: &[&str] = &["QUICALC_LOG"];
+/// Sections whose main option is overridden by the environment variable named
+/// after the section itself, like `QUICALC_HOTKEY`
+const ENV_SECTION_OPTIONS: &[(&str, &str)] = &[("hotkey", "key")];
+
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default, rename_all = "kebab-case")]
+pub struct Config {
+	pub language: Option<String>,
+	pub renderer: Renderer,
+	pub theme: Theme,
+	pub updates: UpdateConfig,
+	pub tray: TrayConfig,
+	pub hotkey: HotkeyConfig,
+	pub keys: KeysConfig,
+	pub eval: EvalConfig,
+	pub hide: HideConfig,
+	pub session: SessionConfig,
+	pub go: GoConfig,
+	pub timer: TimerConfig,
+	pub clock: ClockConfig,
+	pub calendar: CalendarConfig,
+	pub weather: WeatherConfig,
+	pub price: PriceConfig,
+	pub tax: TaxConfig,
+	/// Text shown in the empty input instead of the default, by mode command
+	pub prompts: BTreeMap<String, String>,
+	/// Hex colors of the input border and result, by mode command
+	pub accents: BTreeMap<String, String>,
+	/// URLs opened by web query commands like `npm tokio`, with `{q}` replaced
+	/// by the query, by command
+	pub queries: BTreeMap<String, String>,
+	/// Shell commands run by action commands like `vpn up`, with the rest of
+	/// the input as arguments, by command
+	pub actions: BTreeMap<String, String>,
+	pub python: PythonConfig,
+	pub ocr: OcrConfig,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Renderer {
+	/// Use the GPU, falling back to software rendering if it can't be used or
+	/// fails while running
+	#[default]
+	Auto,
+	Gpu,
+	Software,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Theme {
+	#[default]
+	Dark,
+	Light,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, rename_all = "kebab-case")]
+pub struct UpdateConfig {
+	pub check: bool,
+	pub interval_hours: u64,
+}
+
+impl Default for UpdateConfig {
+	fn default() -> Self {
+		Self {
+			check: false,
+			interval_hours: 24,
+		}
+	}
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, rename_all = "kebab-case")]
+pub struct TrayConfig {
+	pub result_tooltip: bool,
+	pub icon: TrayIconStyle,
+}
+
+impl Default for TrayConfig {
+	fn default() -> Self {
+		Self {
+			result_tooltip: true,
+			icon: TrayIconStyle::default(),
+		}
+	}
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum TrayIconStyle {
+	#[default]
+	Color,
+	Monochrome,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, rename_all = "kebab-case")]
+pub struct HotkeyConfig {
+	/// The hotkey showing the window, if it isn't used by another program
+	pub key: HotKey,
+	pub fallbacks: Vec<HotKey>,
+	pub double_tap: Option<TapKey>,
+	pub chord: Vec<HotKey>,
+	pub gesture_timeout_ms: u64,
+	pub selection: Option<HotKey>,
+}
+
+impl Default for HotkeyConfig {
+	fn default() -> Self {
+		Self {
+			key: HotKey::new(Some(Modifiers::ALT), Code::Enter),
+			fallbacks: vec![
+				HotKey::new(Some(Modifiers::ALT | Modifiers::SHIFT), Code::Enter),
+				HotKey::new(Some(Modifiers::CONTROL | Modifiers::ALT), Code::Space),
+			],
+			double_tap: None,
+			chord: Vec::new(),
+			gesture_timeout_ms: 400,
+			selection: None,
+		}
+	}
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum TapKey {
+	Ctrl,
+	Alt,
+	Shift,
+	Super,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, rename_all = "kebab-case")]
+pub struct KeysConfig {
+	pub confirm: Vec<HotKey>,
+	/// The editing shortcuts in the input
+	pub editing: EditingStyle,
+}
+
+/// The set of editing shortcuts available in the input
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum EditingStyle {
+	/// Readline-style shortcuts, like in most terminals
+	#[default]
+	Emacs,
+	/// Modal editing like in vi, starting in insert mode and switching to
+	/// normal mode with Escape
+	Vi,
+	/// Only the shortcuts that inputs have anyway
+	None,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, rename_all = "kebab-case")]
+pub struct EvalConfig {
+	/// How long typing must pause before the input is evaluated
+	pub debounce_ms: u64,
+	/// Fill the input with the clipboard when the window is shown, if it can
+	/// be evaluated in the current mode
+	pub clipboard: bool,
+	/// Show how long the last evaluation took after the result
+	pub show_duration: bool,
+}
+
+impl Default for EvalConfig {
+	fn default() -> Self {
+		Self {
+			debounce_ms: 80,
+			clipboard: false,
+			show_duration: false,
+		}
+	}
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, rename_all = "kebab-case")]
+pub struct HideConfig {
+	/// Start with an empty input the next time the window is shown
+	pub clear_input: bool,
+	/// Forget the variables and functions defined in kalk
+	pub reset_kalk: bool,
+	/// Forget the variables and functions defined in Python, and re-run the
+	/// configured imports
+	pub reset_python: bool,
+	/// Hide the window when it loses focus, not only with Escape
+	pub on_unfocus: bool,
+}
+
+impl Default for HideConfig {
+	fn default() -> Self {
+		Self {
+			clear_input: false,
+			reset_kalk: true,
+			reset_python: true,
+			on_unfocus: true,
+		}
+	}
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, rename_all = "kebab-case")]
+pub struct SessionConfig {
+	/// Keep the variables and functions defined in kalk and Python when
+	/// quicalc is restarted
+	pub persist: bool,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, rename_all = "kebab-case")]
+pub struct TimerConfig {
+	/// Play a sound along with the notification when a timer is done
+	pub sound: bool,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, rename_all = "kebab-case")]
+pub struct ClockConfig {
+	/// Time zones shown in the clock mode after the local time, by IANA name
+	/// or city
+	pub zones: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, rename_all = "kebab-case")]
+pub struct CalendarConfig {
+	/// Days of the week that aren't counted as workdays
+	pub weekend: Vec<Weekday>,
+	/// Dates that aren't counted as workdays, like `2025-12-25`
+	pub holidays: Vec<String>,
+}
+
+impl Default for CalendarConfig {
+	fn default() -> Self {
+		Self {
+			weekend: vec![Weekday::Saturday, Weekday::Sunday],
+			holidays: Vec::new(),
+		}
+	}
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default, rename_all = "kebab-case")]
+pub struct TaxConfig {
+	/// The tax rate used by `net` and `gross`, in percent
+	pub rate: Option<f64>,
+	/// Other tax rates that can be used by name, like `gross 120 reduced`, in
+	/// percent
+	pub rates: BTreeMap<String, f64>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Weekday {
+	Monday,
+	Tuesday,
+	Wednesday,
+	Thursday,
+	Friday,
+	Saturday,
+	Sunday,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, rename_all = "kebab-case")]
+pub struct WeatherConfig {
+	pub provider: WeatherProvider,
+	/// The place whose weather is shown when none is entered
+	pub location: String,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum WeatherProvider {
+	#[default]
+	OpenMeteo,
+	/// wttr.in
+	Wttr,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, rename_all = "kebab-case")]
+pub struct PriceConfig {
+	/// Where prices come from, tried in order until one has the symbol
+	pub sources: Vec<PriceSource>,
+}
+
+impl Default for PriceConfig {
+	fn default() -> Self {
+		Self {
+			sources: vec![
+				PriceSource {
+					url: "https://api.coinbase.com/v2/prices/{symbol}-USD/spot".to_string(),
+					pointer: "/data/amount".to_string(),
+				},
+				PriceSource {
+					url: "https://query1.finance.yahoo.com/v8/finance/chart/{symbol}".to_string(),
+					pointer: "/chart/result/0/meta/regularMarketPrice".to_string(),
+				},
+			],
+		}
+	}
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct PriceSource {
+	/// The URL of a JSON API, with `{symbol}` replaced by the uppercase symbol
+	pub url: String,
+	/// A JSON pointer to the price in the API's response
+	pub pointer: String,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, rename_all = "kebab-case")]
+pub struct GoConfig {
+	/// URLs opened in the go mode, by name
+	pub bookmarks: BTreeMap<String, String>,
+	/// A browser whose bookmarks are searched along with these
+	pub import: Option<Browser>,
+}
+
+/// A Chromium-based browser, which all keep their bookmarks the same way
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Browser {
+	Chrome,
+	Chromium,
+	Edge,
+	Brave,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, rename_all = "kebab-case")]
+pub struct PythonConfig {
+	pub backend: PythonBackend,
+	pub executable: Option<PathBuf>,
+	pub home: Option<PathBuf>,
+	pub venv: Option<PathBuf>,
+	pub imports: Vec<String>,
+	pub timeout_ms: u64,
+	pub restricted: bool,
+	pub memory_limit_mb: u64,
+}
+
+impl Default for PythonConfig {
+	fn default() -> Self {
+		Self {
+			backend: if cfg!(feature = "python-embedded") {
+				PythonBackend::Embedded
+			} else {
+				PythonBackend::Subprocess
+			},
+			executable: None,
+			home: None,
+			venv: None,
+			imports: Vec::new(),
+			timeout_ms: 5000,
+			restricted: false,
+			memory_limit_mb: 512,
+		}
+	}
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum PythonBackend {
+	Embedded,
+	Subprocess,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, rename_all = "kebab-case")]
+pub struct OcrConfig {
+	pub tesseract: PathBuf,
+	/// A global hotkey for capturing an expression from the screen
+	pub hotkey: Option<HotKey>,
+}
+
+impl Default for OcrConfig {
+	fn default() -> Self {
+		Self {
+			tesseract: PathBuf::from("tesseract"),
+			hotkey: None,
+		}
+	}
+}
+
+impl Config {
+	pub fn path() -> Option<PathBuf> {
+		paths::config().map(|dir| dir.join("config.toml"))
+	}
+
+	pub fn create_if_missing() -> Result<PathBuf, String> {
+		let path = Self::path().ok_or("could not determine config file location")?;
+
+		if !path.exists() {
+			if let Some(dir) = path.parent() {
+				fs::create_dir_all(dir).map_err(|e| e.to_string())?;
+			}
+
+			let config = toml::to_string_pretty(&Self::default()).map_err(|e| e.to_string())?;
+			fs::write(&path, config).map_err(|e| e.to_string())?;
+			info!(?path, "created default config");
+		}
+
+		Ok(path)
+	}
+
+	pub fn load() -> Self {
+		let Some(path) = Self::path() else {
+			error!("could not determine config file location");
+			return Self::default();
+		};
+
+		match fs::read_to_string(&path) {
+			Ok(config) => Self::parse(&config)
+				.inspect(|_| info!(?path, "loaded config"))
+				.inspect_err(|err| error!(?err, ?path, "error parsing config"))
+				.unwrap_or_default(),
+			Err(err) => {
+				debug!(?err, ?path, "error reading config, using defaults");
+				Self::parse("")
+					.inspect_err(|err| error!(?err, "error applying config overrides"))
+					.unwrap_or_default()
+			}
+		}
+	}
+
+	/// Load the config again after it has changed, without hiding errors
+	pub fn reload() -> Result<Self, String> {
+		let path = Self::path().ok_or("could not determine config file location")?;
+
+		match fs::read_to_string(&path) {
+			Ok(config) => Self::parse(&config),
+			Err(err) if err.kind() == ErrorKind::NotFound => Self::parse(""),
+			Err(err) => Err(err.to_string()),
+		}
+	}
+
+	/// Parse a config file, with its options overridden by environment
+	/// variables
+	fn parse(config: &str) -> Result<Self, String> {
+		let mut table = config.parse::<Table>().map_err(|e| e.to_string())?;
+		let overrides = apply_env_overrides(&mut table, env::vars())?;
+		let config: Self = Value::Table(table).try_into().map_err(|e| e.to_string())?;
+
+		// unknown options are dropped when parsing, so they're missing from the
+		// parsed config, unlike the known ones that were set
+		let parsed = Value::try_from(&config).map_err(|e| e.to_string())?;
+
+		for (var, path) in overrides {
+			if path
+				.iter()
+				.try_fold(&parsed, |value, key| value.get(key))
+				.is_none()
+			{
+				warn!(%var, "environment variable doesn't match any config option, ignoring it");
+			}
+		}
+
+		Ok(config)
+	}
+}
+
+/// Override options with `QUICALC_<SECTION>_<OPTION>` environment variables,
+/// or `QUICALC_<OPTION>` for the ones outside of a section, returning the
+/// variables used along with the path of the option each of them overrides
+fn apply_env_overrides(
+	table: &mut Table,
+	vars: impl IntoIterator<Item = (String, String)>,
+) -> Result<Vec<(String, Vec<String>)>, String> {
+	let Value::Table(defaults) = Value::try_from(Config::default()).map_err(|e| e.to_string())?
+	else {
+		return Err("default config isn't a table".to_string());
+	};
+
+	let sections = defaults
+		.iter()
+		.filter(|(_, value)| value.is_table())
+		.map(|(section, _)| section.as_str())
+		.collect::<Vec<_>>();
+
+	let mut overrides = Vec::new();
+
+	for (var, raw) in vars {
+		let Some(name) = var
+			.strip_prefix(ENV_PREFIX)
+			.filter(|_| !ENV_IGNORED.contains(&var.as_str()))
+		else {
+			continue;
+		};
+
+		let mut name = name.to_lowercase().replace('_', "-");
+
+		if let Some((section, option)) = ENV_SECTION_OPTIONS
+			.iter()
+			.find(|(section, _)| *section == name)
+		{
+			name = format!("{section}-{option}");
+		} else if sections.contains(&name.as_str()) {
+			warn!(%var, "config sections can't be overridden, only their options");
+			continue;
+		}
+
+		// values are TOML, but plain strings don't need to be quoted
+		let value = format!("value = {raw}")
+			.parse::<Table>()
+			.ok()
+			.and_then(|mut parsed| parsed.remove("value"))
+			.unwrap_or(Value::String(raw));
+
+		let (section, option) = match name.split_once('-') {
+			Some((section, option)) if sections.contains(&section) => (Some(section), option),
+			_ => (None, name.as_str()),
+		};
+
+		let table = match section {
+			Some(section) => table
+				.entry(section)
+				.or_insert_with(|| Value::Table(Table::new()))
+				.as_table_mut()
+				.ok_or_else(|| format!("{section} isn't a section"))?,
+			None => &mut *table,
+		};
+
+		info!(%var, ?section, option, "overriding config option from environment");
+		table.insert(option.to_string(), value);
+
+		let path = section
+			.into_iter()
+			.chain([option])
+			.map(str::to_string)
+			.collect();
+		overrides.push((var, path));
+	}
+
+	Ok(overrides)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn overridden(vars: &[(&str, &str)]) -> (Config, Vec<(String, Vec<String>)>) {
+		let mut table = Table::new();
+		let vars = vars
+			.iter()
+			.map(|(var, raw)| (var.to_string(), raw.to_string()));
+		let overrides = apply_env_overrides(&mut table, vars).unwrap();
+		(Value::Table(table).try_into().unwrap(), overrides)
+	}
+
+	#[test]
+	fn section_variable_overrides_main_option() {
+		let (config, overrides) = overridden(&[("QUICALC_HOTKEY", "control+alt+KeyQ")]);
+
+		assert_eq!(
+			config.hotkey.key,
+			HotKey::new(Some(Modifiers::CONTROL | Modifiers::ALT), Code::KeyQ)
+		);
+		assert_eq!(overrides[0].1, ["hotkey", "key"]);
+	}
+
+	#[test]
+	fn top_level_and_section_options_are_overridden() {
+		let (config, overrides) = overridden(&[
+			("QUICALC_THEME", "light"),
+			("QUICALC_EVAL_DEBOUNCE_MS", "200"),
+			("QUICALC_LOG", "debug"),
+		]);
+
+		assert_eq!(config.theme, Theme::Light);
+		assert_eq!(config.eval, EvalConfig {
+			debounce_ms: 200,
+			..EvalConfig::default()
+		});
+		assert_eq!(overrides.len(), 2);
+	}
+}
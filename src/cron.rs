@@ -0,0 +1,327 @@
+use std::collections::BTreeSet;
+
+use jiff::{Zoned, civil::Date};
+
+use crate::locale::tr;
+
+/// How many of the next run times are shown
+const NEXT_RUNS: usize = 5;
+/// How far ahead run times are looked for, in days, since some schedules
+/// (like on February 30th) never run
+const MAX_DAYS: usize = 5 * 366;
+
+const MONTHS: &[&str] = &[
+	"JAN", "FEB", "MAR", "APR", "MAY", "JUN", "JUL", "AUG", "SEP", "OCT", "NOV", "DEC",
+];
+const WEEKDAYS: &[&str] = &["SUN", "MON", "TUE", "WED", "THU", "FRI", "SAT"];
+
+/// A cron schedule, like `*/15 2-6 * * MON-FRI`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Schedule {
+	minutes: Field,
+	hours: Field,
+	days: Field,
+	months: Field,
+	weekdays: Field,
+}
+
+/// The values allowed by one of a schedule's fields
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Field {
+	values: BTreeSet<u8>,
+	kind: Kind,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Kind {
+	/// `*`
+	Any,
+	/// `*/n`
+	Every(u8),
+	/// Anything else, like `1,15` or `MON-FRI`
+	Values,
+}
+
+impl Schedule {
+	pub fn parse(expression: &str) -> Result<Self, String> {
+		let [minutes, hours, days, months, weekdays] = expression
+			.split_whitespace()
+			.collect::<Vec<_>>()
+			.try_into()
+			.map_err(|_| tr!("cron-field-count"))?;
+
+		let mut weekdays = Field::parse(weekdays, 0, 7, WEEKDAYS, 0)?;
+
+		// both 0 and 7 are Sunday
+		if weekdays.values.remove(&7) {
+			weekdays.values.insert(0);
+		}
+
+		Ok(Self {
+			minutes: Field::parse(minutes, 0, 59, &[], 0)?,
+			hours: Field::parse(hours, 0, 23, &[], 0)?,
+			days: Field::parse(days, 1, 31, &[], 0)?,
+			months: Field::parse(months, 1, 12, MONTHS, 1)?,
+			weekdays,
+		})
+	}
+
+	/// The schedule in plain language, followed by the next times it runs in
+	/// the local time zone
+	pub fn explain(&self) -> String {
+		let runs = self.next_runs(&Zoned::now());
+
+		[self.describe()]
+			.into_iter()
+			.chain(
+				runs.iter()
+					.map(|run| format!("→ {}", run.strftime("%a %Y-%m-%d %H:%M"))),
+			)
+			.chain(runs.is_empty().then(|| tr!("cron-never")))
+			.collect::<Vec<_>>()
+			.join("\n")
+	}
+
+	fn describe(&self) -> String {
+		let (minutes, hours) = match (self.minutes.kind, self.hours.kind) {
+			// a single minute of some hours reads like a time, like `At 04:05`
+			(Kind::Values, Kind::Values) if self.minutes.values.len() == 1 => {
+				let minute = self.minutes.values.first().copied().unwrap_or_default();
+				let times = list(&self.hours, |hour| format!("{hour:02}:{minute:02}"));
+
+				(tr!("cron-at", times = times), None)
+			}
+			(minutes, hours) => (
+				match minutes {
+					Kind::Any => tr!("cron-every-minute"),
+					Kind::Every(step) => tr!("cron-every-minutes", step = step),
+					Kind::Values => {
+						tr!(
+							"cron-minutes",
+							minutes = list(&self.minutes, |m| m.to_string())
+						)
+					}
+				},
+				match hours {
+					Kind::Any => None,
+					Kind::Every(step) => Some(tr!("cron-every-hours", step = step)),
+					Kind::Values => Some(tr!(
+						"cron-hours",
+						hours = list(&self.hours, |h| h.to_string())
+					)),
+				},
+			),
+		};
+
+		let days = (self.days.kind != Kind::Any)
+			.then(|| tr!("cron-days", days = list(&self.days, |d| d.to_string())));
+
+		let months = (self.months.kind != Kind::Any).then(|| {
+			tr!(
+				"cron-months",
+				months = list(&self.months, |m| title_case(MONTHS[usize::from(m) - 1]))
+			)
+		});
+
+		let weekdays = (self.weekdays.kind != Kind::Any).then(|| {
+			tr!(
+				"cron-weekdays",
+				weekdays = list(&self.weekdays, |d| title_case(WEEKDAYS[usize::from(d)]))
+			)
+		});
+
+		[Some(minutes), hours, days, months, weekdays]
+			.into_iter()
+			.flatten()
+			.collect::<Vec<_>>()
+			.join(", ")
+	}
+
+	/// The next times this schedule runs after `now`, in `now`'s time zone
+	fn next_runs(&self, now: &Zoned) -> Vec<Zoned> {
+		let mut runs = Vec::new();
+		let mut date = now.date();
+
+		for _ in 0..MAX_DAYS {
+			if self.runs_on(date) {
+				for &hour in &self.hours.values {
+					for &minute in &self.minutes.values {
+						let Ok(run) = date
+							.at(hour as i8, minute as i8, 0, 0)
+							.to_zoned(now.time_zone().clone())
+						else {
+							continue;
+						};
+
+						if run.timestamp() > now.timestamp() {
+							runs.push(run);
+
+							if runs.len() == NEXT_RUNS {
+								return runs;
+							}
+						}
+					}
+				}
+			}
+
+			let Ok(next) = date.tomorrow() else {
+				break;
+			};
+
+			date = next;
+		}
+
+		runs
+	}
+
+	/// Whether this schedule runs at some time on `date`
+	fn runs_on(&self, date: Date) -> bool {
+		let day = self.days.values.contains(&(date.day() as u8));
+		let weekday = self
+			.weekdays
+			.values
+			.contains(&(date.weekday().to_sunday_zero_offset() as u8));
+
+		// like in cron, if both are restricted, either one is enough
+		let matches_day = if self.days.kind == Kind::Any || self.weekdays.kind == Kind::Any {
+			day && weekday
+		} else {
+			day || weekday
+		};
+
+		matches_day && self.months.values.contains(&(date.month() as u8))
+	}
+}
+
+impl Field {
+	/// Parse a field with values from `min` to `max`, which can also be given
+	/// by the `names` of the values from `offset` on
+	fn parse(text: &str, min: u8, max: u8, names: &[&str], offset: u8) -> Result<Self, String> {
+		let invalid = || tr!("cron-invalid-field", field = text);
+
+		let value = |value: &str| {
+			let value = names
+				.iter()
+				.position(|name| name.eq_ignore_ascii_case(value))
+				.map(|index| index as u8 + offset)
+				.or_else(|| value.parse().ok())?;
+
+			(min..=max).contains(&value).then_some(value)
+		};
+
+		let mut values = BTreeSet::new();
+
+		for part in text.split(',') {
+			let (range, step) = match part.split_once('/') {
+				Some((range, step)) => (
+					range,
+					Some(
+						step.parse::<u8>()
+							.ok()
+							.filter(|&step| step > 0)
+							.ok_or_else(invalid)?,
+					),
+				),
+				None => (part, None),
+			};
+
+			let (start, end) = match range.split_once('-') {
+				_ if range == "*" => (min, max),
+				Some((start, end)) => (
+					value(start).ok_or_else(invalid)?,
+					value(end).ok_or_else(invalid)?,
+				),
+				// like `5/10`, which starts at 5
+				None if step.is_some() => (value(range).ok_or_else(invalid)?, max),
+				None => {
+					let value = value(range).ok_or_else(invalid)?;
+					(value, value)
+				}
+			};
+
+			if start > end {
+				return Err(invalid());
+			}
+
+			values.extend((start..=end).step_by(step.unwrap_or(1).into()));
+		}
+
+		let kind = match text.strip_prefix("*/") {
+			_ if text == "*" => Kind::Any,
+			Some(step) if !step.contains(',') => Kind::Every(step.parse().map_err(|_| invalid())?),
+			_ => Kind::Values,
+		};
+
+		Ok(Self { values, kind })
+	}
+}
+
+/// The values of a field, with consecutive ones as ranges like `Mon–Fri`
+fn list(field: &Field, name: impl Fn(u8) -> String) -> String {
+	let mut runs = Vec::<(u8, u8)>::new();
+
+	for &value in &field.values {
+		match runs.last_mut() {
+			Some((_, end)) if *end + 1 == value => *end = value,
+			_ => runs.push((value, value)),
+		}
+	}
+
+	runs.into_iter()
+		.flat_map(|(start, end)| match end - start {
+			0 => vec![name(start)],
+			1 => vec![name(start), name(end)],
+			_ => vec![format!("{}–{}", name(start), name(end))],
+		})
+		.collect::<Vec<_>>()
+		.join(", ")
+}
+
+/// `MON` as `Mon`
+fn title_case(name: &str) -> String {
+	name[..1].to_string() + &name[1..].to_lowercase()
+}
+
+#[cfg(test)]
+mod tests {
+	use std::time::{Duration, Instant};
+
+	use proptest::prelude::*;
+
+	use super::*;
+
+	const FIELD: &str = "(\\*|[0-9]{1,2}|[A-Za-z]{3})([-/,](\\*|[0-9]{1,2}|[A-Za-z]{3})){0,3}";
+
+	#[test]
+	fn parse_schedule() {
+		let schedule = Schedule::parse("*/15 2-6 * * MON-FRI").unwrap();
+
+		assert_eq!(schedule.minutes.kind, Kind::Every(15));
+		assert_eq!(schedule.minutes.values, [0, 15, 30, 45].into());
+		assert_eq!(schedule.hours.values, (2..=6).collect());
+		assert_eq!(schedule.weekdays.values, (1..=5).collect());
+		assert_eq!(Schedule::parse("* * *"), Err(tr!("cron-field-count")));
+		assert_eq!(
+			Schedule::parse("60 * * * *"),
+			Err(tr!("cron-invalid-field", field = "60"))
+		);
+	}
+
+	proptest! {
+		#[test]
+		fn parse_any_expression(expression in any::<String>()) {
+			let _ = Schedule::parse(&expression);
+		}
+
+		#[test]
+		fn explain_any_schedule(fields in proptest::collection::vec(FIELD, 5)) {
+			if let Ok(schedule) = Schedule::parse(&fields.join(" ")) {
+				let start = Instant::now();
+				let explanation = schedule.explain();
+
+				prop_assert!(!explanation.is_empty());
+				prop_assert!(start.elapsed() < Duration::from_secs(1));
+			}
+		}
+	}
+}
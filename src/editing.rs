@@ -0,0 +1,256 @@
+use iced::{
+	keyboard::{
+		Key, Modifiers as IcedModifiers,
+		key::{Named, Physical},
+	},
+	widget::text_editor::{Binding, KeyPress, Motion},
+};
+
+use crate::Message;
+
+/// An edit made with a readline-style shortcut
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LineEdit {
+	/// Move the cursor to the start of the line
+	Home,
+	/// Move the cursor to the end of the line
+	End,
+	/// Delete the line before the cursor
+	KillLine,
+	/// Delete the whitespace-separated word before the cursor
+	KillWord,
+	/// Delete the alphanumeric word before the cursor
+	KillAlphanumericWord,
+}
+
+impl LineEdit {
+	/// The edit for a key combination, if there is one
+	///
+	/// Letters are matched by the letter the key types instead of where it is,
+	/// so that the shortcuts follow the keyboard layout.
+	pub fn from_key(modifiers: IcedModifiers, key: &Key, physical_key: Physical) -> Option<Self> {
+		if modifiers == IcedModifiers::CTRL {
+			match key.to_latin(physical_key)? {
+				'a' => Some(Self::Home),
+				'e' => Some(Self::End),
+				'u' => Some(Self::KillLine),
+				'w' => Some(Self::KillWord),
+				_ => None,
+			}
+		// alt+Backspace already deletes words on macOS
+		} else if modifiers == IcedModifiers::ALT
+			&& *key == Key::Named(Named::Backspace)
+			&& !cfg!(target_os = "macos")
+		{
+			Some(Self::KillAlphanumericWord)
+		} else {
+			None
+		}
+	}
+
+	/// Whether this edit changes the text, instead of only moving the cursor
+	pub const fn is_edit(self) -> bool {
+		!matches!(self, Self::Home | Self::End)
+	}
+
+	/// Apply this edit to a single-line `input` with the cursor at its end
+	pub fn apply(self, input: &str) -> String {
+		let kept = match self {
+			Self::Home | Self::End => input,
+			Self::KillLine => "",
+			Self::KillWord => input
+				.trim_end()
+				.trim_end_matches(|c: char| !c.is_whitespace()),
+			Self::KillAlphanumericWord => input
+				.trim_end_matches(|c: char| !c.is_alphanumeric())
+				.trim_end_matches(char::is_alphanumeric),
+		};
+
+		kept.to_string()
+	}
+
+	/// The multi-line editor binding doing this edit
+	pub fn binding<Message>(self) -> Binding<Message> {
+		match self {
+			Self::Home => Binding::Move(Motion::Home),
+			Self::End => Binding::Move(Motion::End),
+			Self::KillLine => {
+				Binding::Sequence(vec![Binding::Select(Motion::Home), Binding::Backspace])
+			}
+			Self::KillWord | Self::KillAlphanumericWord => {
+				Binding::Sequence(vec![Binding::Select(Motion::WordLeft), Binding::Backspace])
+			}
+		}
+	}
+}
+
+/// The state of the vi editing style
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Vi {
+	/// Whether keys run commands instead of typing, like in vi's normal mode
+	pub normal: bool,
+	/// The command waiting for the rest of its keys
+	pending: Pending,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+enum Pending {
+	#[default]
+	None,
+	/// An operator waiting for a motion, like the `d` in `dw`
+	Operator(Operator),
+	/// An operator waiting for an inner text object, like the `ci` in `ciw`
+	Inner(Operator),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Operator {
+	Delete,
+	Change,
+}
+
+impl Operator {
+	/// The key starting this operator in normal mode
+	const fn key(self) -> char {
+		match self {
+			Self::Delete => 'd',
+			Self::Change => 'c',
+		}
+	}
+
+	/// Delete the text selected by `select`, then go to the mode this operator
+	/// leaves the input in
+	fn apply(self, select: Binding<Message>) -> Binding<Message> {
+		let next = match self {
+			Self::Delete => Vi::NORMAL,
+			Self::Change => Vi::INSERT,
+		};
+
+		Binding::Sequence(vec![
+			select,
+			Binding::Delete,
+			Binding::Custom(Message::Vi(next)),
+		])
+	}
+}
+
+impl Vi {
+	pub const INSERT: Self = Self {
+		normal: false,
+		pending: Pending::None,
+	};
+	pub const NORMAL: Self = Self {
+		normal: true,
+		pending: Pending::None,
+	};
+
+	/// Whether a command is waiting for more keys
+	pub fn is_pending(self) -> bool {
+		self.pending != Pending::None
+	}
+
+	/// The character typed with a key press, if it's a normal mode command
+	pub fn command_key(key_press: &KeyPress) -> Option<char> {
+		if key_press.modifiers.control() || key_press.modifiers.alt() {
+			return None;
+		}
+
+		key_press
+			.text
+			.as_deref()?
+			.chars()
+			.next()
+			.filter(|c| !c.is_control())
+	}
+
+	/// The editor binding for a command key in normal mode
+	///
+	/// Keys that aren't commands do nothing, instead of typing.
+	pub fn binding(self, key: char) -> Option<Binding<Message>> {
+		let with_pending = |pending| Binding::Custom(Message::Vi(Self { pending, ..self }));
+		let insert_after = |motion| {
+			Binding::Sequence(vec![
+				Binding::Move(motion),
+				Binding::Custom(Message::Vi(Self::INSERT)),
+			])
+		};
+
+		let motion = match key {
+			'h' => Some(Motion::Left),
+			'l' => Some(Motion::Right),
+			'j' => Some(Motion::Down),
+			'k' => Some(Motion::Up),
+			'w' => Some(Motion::WordRight),
+			'b' => Some(Motion::WordLeft),
+			'0' | '^' => Some(Motion::Home),
+			'$' => Some(Motion::End),
+			_ => None,
+		};
+
+		let binding = match (self.pending, key, motion) {
+			(Pending::Inner(operator), 'w', _) => operator.apply(Binding::SelectWord),
+			(Pending::Inner(_), ..) => Binding::Custom(Message::Vi(Self::NORMAL)),
+			(Pending::Operator(operator), 'i', _) => with_pending(Pending::Inner(operator)),
+			// doubling an operator applies it to the whole line, like `dd`
+			(Pending::Operator(operator), ..) if key == operator.key() => {
+				operator.apply(Binding::SelectLine)
+			}
+			(Pending::Operator(operator), _, Some(motion)) => {
+				operator.apply(Binding::Select(motion))
+			}
+			(Pending::Operator(_), _, None) => Binding::Custom(Message::Vi(Self::NORMAL)),
+			(Pending::None, _, Some(motion)) => Binding::Move(motion),
+			(Pending::None, 'i', None) => Binding::Custom(Message::Vi(Self::INSERT)),
+			(Pending::None, 'a', None) => insert_after(Motion::Right),
+			(Pending::None, 'A', None) => insert_after(Motion::End),
+			(Pending::None, 'I', None) => insert_after(Motion::Home),
+			(Pending::None, 'x', None) => Binding::Delete,
+			(Pending::None, 'X', None) => Binding::Backspace,
+			(Pending::None, 'D', None) => Operator::Delete.apply(Binding::Select(Motion::End)),
+			(Pending::None, 'C', None) => Operator::Change.apply(Binding::Select(Motion::End)),
+			(Pending::None, 'u', None) => Binding::Custom(Message::Undo),
+			(Pending::None, 'd', None) => with_pending(Pending::Operator(Operator::Delete)),
+			(Pending::None, 'c', None) => with_pending(Pending::Operator(Operator::Change)),
+			(Pending::None, _, None) => return None,
+		};
+
+		Some(binding)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use iced::keyboard::key::Code as KeyCode;
+
+	use super::*;
+
+	#[test]
+	fn shortcuts_follow_the_keyboard_layout() {
+		// the key typing `a` on an AZERTY keyboard is where `q` is on a QWERTY one
+		assert_eq!(
+			LineEdit::from_key(
+				IcedModifiers::CTRL,
+				&Key::Character("a".into()),
+				Physical::Code(KeyCode::KeyQ)
+			),
+			Some(LineEdit::Home)
+		);
+		assert_eq!(
+			LineEdit::from_key(
+				IcedModifiers::CTRL,
+				&Key::Character("q".into()),
+				Physical::Code(KeyCode::KeyA)
+			),
+			None
+		);
+		// letters of other alphabets fall back to where the key is
+		assert_eq!(
+			LineEdit::from_key(
+				IcedModifiers::CTRL,
+				&Key::Character("у".into()),
+				Physical::Code(KeyCode::KeyE)
+			),
+			Some(LineEdit::End)
+		);
+	}
+}
@@ -0,0 +1,262 @@
+use std::thread;
+
+use global_hotkey::{GlobalHotKeyEvent, HotKeyState};
+use iced::futures::{
+	Stream,
+	channel::mpsc::{self, UnboundedSender},
+};
+use tracing::{debug, error, trace};
+use tray_icon::{MouseButton, MouseButtonState, TrayIconEvent, menu::MenuEvent};
+
+use crate::{
+	Message,
+	hotkey::{self, Gesture},
+	tray::{self, MENU_ABOUT, MENU_AUTOSTART, MENU_CONFIG, MENU_EXIT, MENU_SHOW},
+};
+
+/// Messages for global hotkey, hotkey gesture, and tray icon events
+///
+/// Those events are received from blocking channels, so they are forwarded from
+/// a separate thread instead of blocking the async executor.
+pub fn stream() -> impl Stream<Item = Message> {
+	let (sender, receiver) = mpsc::unbounded();
+
+	thread::Builder::new()
+		.name("events".to_string())
+		.spawn(move || forward(&sender))
+		.inspect_err(|err| error!(?err, "error spawning event forwarding thread"))
+		.ok();
+
+	receiver
+}
+
+fn forward(sender: &UnboundedSender<Message>) {
+	loop {
+		let message = crossbeam_channel::select! {
+			recv(GlobalHotKeyEvent::receiver()) -> msg => msg
+				.inspect_err(|err| error!(?err, "error receiving global hotkey event"))
+				.ok()
+				.and_then(|event| hotkey_message(&event)),
+			recv(hotkey::receiver()) -> msg => msg
+				.inspect_err(|err| error!(?err, "error receiving hotkey gesture"))
+				.ok()
+				.map(gesture_message),
+			recv(MenuEvent::receiver()) -> msg => msg
+				.inspect_err(|err| error!(?err, "error receiving tray icon menu event"))
+				.ok()
+				.and_then(|event| menu_message(&event)),
+			recv(TrayIconEvent::receiver()) -> msg => msg
+				.inspect_err(|err| error!(?err, "error receiving tray icon event"))
+				.ok()
+				.and_then(|event| tray_message(&event)),
+		};
+
+		if let Some(message) = message
+			&& sender.unbounded_send(message).is_err()
+		{
+			debug!("no longer forwarding events");
+			break;
+		}
+	}
+}
+
+fn hotkey_message(event: &GlobalHotKeyEvent) -> Option<Message> {
+	debug!(?event, "new hotkey event");
+
+	if event.state() != HotKeyState::Pressed {
+		return None;
+	}
+
+	#[cfg(feature = "ocr")]
+	if hotkey::is_capture(event.id()) {
+		return Some(Message::Capture);
+	}
+
+	if hotkey::is_selection(event.id()) {
+		return Some(Message::EvaluateSelection);
+	}
+
+	hotkey::matches(event.id()).then_some(Message::ShowWindow(None))
+}
+
+fn gesture_message(gesture: Gesture) -> Message {
+	debug!(?gesture, "new hotkey gesture");
+	Message::ShowWindow(None)
+}
+
+fn menu_message(event: &MenuEvent) -> Option<Message> {
+	debug!(?event, "new tray icon menu event");
+
+	let id = event.id();
+
+	#[cfg(feature = "updater")]
+	if id == &*tray::MENU_UPDATE {
+		return Some(Message::InstallUpdate);
+	}
+
+	#[cfg(feature = "ocr")]
+	if id == &*tray::MENU_CAPTURE {
+		return Some(Message::Capture);
+	}
+
+	if id == &*MENU_SHOW {
+		Some(Message::ShowWindow(None))
+	} else if let Some(mode) = tray::mode_for_menu_id(id) {
+		Some(Message::ShowWindow(Some(mode)))
+	} else if id == &*MENU_AUTOSTART {
+		Some(Message::ToggleAutostart)
+	} else if id == &*MENU_CONFIG {
+		Some(Message::OpenConfig)
+	} else if id == &*MENU_ABOUT {
+		Some(Message::ShowAbout)
+	} else if id == &*MENU_EXIT {
+		Some(Message::Exit)
+	} else {
+		error!(?id, "unknown menu item event id");
+		None
+	}
+}
+
+fn tray_message(event: &TrayIconEvent) -> Option<Message> {
+	trace!(?event, "new tray icon event");
+
+	match event {
+		TrayIconEvent::Click {
+			button: MouseButton::Left,
+			button_state: MouseButtonState::Up,
+			..
+		} => Some(Message::ShowWindow(None)),
+		TrayIconEvent::Enter { .. } => Some(Message::RefreshTray),
+		_ => None,
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use tray_icon::{Rect, TrayIconId, dpi::PhysicalPosition, menu::MenuId};
+
+	use super::*;
+	use crate::{QuicalcMode, config::HotkeyConfig};
+
+	fn hotkey_event(id: u32, state: HotKeyState) -> GlobalHotKeyEvent {
+		GlobalHotKeyEvent { id, state }
+	}
+
+	fn menu_event(id: &MenuId) -> MenuEvent {
+		MenuEvent { id: id.clone() }
+	}
+
+	fn click(button: MouseButton, button_state: MouseButtonState) -> TrayIconEvent {
+		TrayIconEvent::Click {
+			id: TrayIconId::new("tray"),
+			position: PhysicalPosition::default(),
+			rect: Rect::default(),
+			button,
+			button_state,
+		}
+	}
+
+	#[test]
+	fn hotkey_press_shows_window() {
+		let event = hotkey_event(HotkeyConfig::default().key.id(), HotKeyState::Pressed);
+		assert!(matches!(
+			hotkey_message(&event),
+			Some(Message::ShowWindow(None))
+		));
+	}
+
+	#[test]
+	fn hotkey_release_is_ignored() {
+		let event = hotkey_event(HotkeyConfig::default().key.id(), HotKeyState::Released);
+		assert!(hotkey_message(&event).is_none());
+	}
+
+	#[test]
+	fn unknown_hotkey_is_ignored() {
+		let event = hotkey_event(
+			HotkeyConfig::default().key.id().wrapping_add(1),
+			HotKeyState::Pressed,
+		);
+		assert!(hotkey_message(&event).is_none());
+	}
+
+	#[test]
+	fn gestures_show_window() {
+		for gesture in [Gesture::DoubleTap, Gesture::Chord] {
+			assert!(matches!(
+				gesture_message(gesture),
+				Message::ShowWindow(None)
+			));
+		}
+	}
+
+	#[test]
+	fn menu_items_map_to_messages() {
+		assert!(matches!(
+			menu_message(&menu_event(&MENU_SHOW)),
+			Some(Message::ShowWindow(None))
+		));
+		assert!(matches!(
+			menu_message(&menu_event(&MENU_AUTOSTART)),
+			Some(Message::ToggleAutostart)
+		));
+		assert!(matches!(
+			menu_message(&menu_event(&MENU_CONFIG)),
+			Some(Message::OpenConfig)
+		));
+		assert!(matches!(
+			menu_message(&menu_event(&MENU_ABOUT)),
+			Some(Message::ShowAbout)
+		));
+		assert!(matches!(
+			menu_message(&menu_event(&MENU_EXIT)),
+			Some(Message::Exit)
+		));
+	}
+
+	#[test]
+	fn mode_menu_items_show_window_in_mode() {
+		for &mode in QuicalcMode::ALL {
+			assert!(matches!(
+				menu_message(&menu_event(&tray::mode_menu_id(mode))),
+				Some(Message::ShowWindow(Some(shown))) if shown == mode
+			));
+		}
+	}
+
+	#[test]
+	fn unknown_menu_item_is_ignored() {
+		assert!(menu_message(&menu_event(&MenuId::new("unknown"))).is_none());
+	}
+
+	#[test]
+	fn left_click_release_shows_window() {
+		let event = click(MouseButton::Left, MouseButtonState::Up);
+		assert!(matches!(
+			tray_message(&event),
+			Some(Message::ShowWindow(None))
+		));
+	}
+
+	#[test]
+	fn other_clicks_are_ignored() {
+		for event in [
+			click(MouseButton::Left, MouseButtonState::Down),
+			click(MouseButton::Right, MouseButtonState::Up),
+			click(MouseButton::Middle, MouseButtonState::Up),
+		] {
+			assert!(tray_message(&event).is_none());
+		}
+	}
+
+	#[test]
+	fn hovering_refreshes_tray() {
+		let event = TrayIconEvent::Enter {
+			id: TrayIconId::new("tray"),
+			position: PhysicalPosition::default(),
+			rect: Rect::default(),
+		};
+
+		assert!(matches!(tray_message(&event), Some(Message::RefreshTray)));
+	}
+}
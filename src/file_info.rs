@@ -0,0 +1,87 @@
+use std::{
+	fs::File,
+	io::{ErrorKind, Read},
+	path::PathBuf,
+};
+
+use sha2::{Digest, Sha256};
+
+const CHUNK_SIZE: usize = 64 * 1024;
+
+/// What's shown about a file dropped onto the window
+#[derive(Debug, Clone)]
+pub struct FileInfo {
+	pub name: String,
+	pub size: u64,
+	/// The hex-encoded SHA-256 hash of the contents
+	pub sha256: String,
+	/// The number of lines, if the file looks like text
+	pub lines: Option<u64>,
+}
+
+/// Read the whole file, which may take a while for large ones
+pub async fn inspect(path: PathBuf) -> Result<FileInfo, String> {
+	tokio::task::spawn_blocking(move || read(path))
+		.await
+		.map_err(|e| e.to_string())?
+}
+
+fn read(path: PathBuf) -> Result<FileInfo, String> {
+	let mut file = File::open(&path).map_err(|e| e.to_string())?;
+	let mut hasher = Sha256::new();
+	let mut buffer = vec![0; CHUNK_SIZE];
+	let mut size = 0;
+	let mut newlines = 0;
+	let mut last = None;
+	let mut is_text = true;
+
+	loop {
+		let read = match file.read(&mut buffer) {
+			Ok(0) => break,
+			Ok(read) => read,
+			Err(err) if err.kind() == ErrorKind::Interrupted => continue,
+			Err(err) => return Err(err.to_string()),
+		};
+		let chunk = &buffer[..read];
+
+		hasher.update(chunk);
+		size += read as u64;
+		newlines += chunk.iter().filter(|&&byte| byte == b'\n').count() as u64;
+		is_text &= !chunk.contains(&0);
+		last = chunk.last().copied();
+	}
+
+	Ok(FileInfo {
+		name: path.file_name().map_or_else(
+			|| path.display().to_string(),
+			|name| name.to_string_lossy().into_owned(),
+		),
+		size,
+		sha256: format!("{:x}", hasher.finalize()),
+		// the last line doesn't need to end with a newline
+		lines: is_text.then(|| newlines + u64::from(last.is_some_and(|byte| byte != b'\n'))),
+	})
+}
+
+/// Format a size in bytes with a binary unit
+pub fn format_size(size: u64) -> String {
+	const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+
+	if size < 1024 {
+		return format!("{size} B");
+	}
+
+	let mut value = size as f64 / 1024.0;
+	let mut unit = UNITS[0];
+
+	for next in &UNITS[1..] {
+		if value < 1024.0 {
+			break;
+		}
+
+		value /= 1024.0;
+		unit = next;
+	}
+
+	format!("{value:.1} {unit}")
+}
@@ -0,0 +1,215 @@
+use crate::locale::tr;
+
+pub const COMPOUND_COMMAND: &str = "compound";
+
+/// How often interest is compounded, by how it's entered and how many times it
+/// is compounded per year
+const FREQUENCIES: &[(&str, f64)] = &[
+	("yearly", 1.0),
+	("annually", 1.0),
+	("quarterly", 4.0),
+	("monthly", 12.0),
+	("weekly", 52.0),
+	("daily", 365.0),
+];
+
+/// A loan like `250000 at 3.6% over 25y`, or compound interest like
+/// `compound 10000 at 5% for 8y monthly`, or `None` if the input is empty
+pub fn eval(input: &str) -> Option<Result<String, String>> {
+	if input.trim().is_empty() {
+		return None;
+	}
+
+	Some(match input.split_once(char::is_whitespace) {
+		Some((COMPOUND_COMMAND, investment)) => compound(investment),
+		_ => loan(input),
+	})
+}
+
+/// The monthly payment and total interest of a loan that's paid off in equal
+/// monthly payments
+pub fn loan(input: &str) -> Result<String, String> {
+	let (amount, rate, years, frequency) = parse(input).ok_or_else(|| tr!("loan-invalid"))?;
+
+	let months = (years * 12.0).round();
+
+	// a loan has to be paid off in at least one payment
+	if frequency.is_some() || months < 1.0 {
+		return Err(tr!("loan-invalid"));
+	}
+
+	let monthly_rate = rate / 12.0;
+
+	// `1 - (1 + rate)^-months`, without losing tiny rates to rounding
+	let paid_off = -(-months * monthly_rate.ln_1p()).exp_m1();
+
+	let payment = if monthly_rate == 0.0 {
+		amount / months
+	} else {
+		amount * monthly_rate / paid_off
+	};
+
+	Ok(tr!(
+		"loan-result",
+		payment = format!("{payment:.2}"),
+		// rounding can make the interest slightly negative at tiny rates
+		interest = format!("{:.2}", (payment * months - amount).max(0.0))
+	))
+}
+
+/// The value of an investment after some time, with interest compounded
+/// yearly unless entered otherwise
+pub fn compound(input: &str) -> Result<String, String> {
+	let (amount, rate, years, frequency) = parse(input).ok_or_else(|| tr!("compound-invalid"))?;
+	let per_year = frequency.unwrap_or(1.0);
+
+	let value = amount * (1.0 + rate / per_year).powf(per_year * years);
+
+	Ok(tr!(
+		"compound-result",
+		value = format!("{value:.2}"),
+		interest = format!("{:.2}", value - amount)
+	))
+}
+
+/// An amount, yearly interest rate, term in years, and compounding frequency
+/// from an input like `10000 at 5% for 8y monthly`
+fn parse(input: &str) -> Option<(f64, f64, f64, Option<f64>)> {
+	let input = input.to_lowercase().replace('_', "");
+	let words = input.split_whitespace().collect::<Vec<_>>();
+
+	let (amount, rate, term) = match words.as_slice() {
+		[amount, "at", rate, "over" | "for", term @ ..] => (*amount, *rate, term),
+		_ => return None,
+	};
+
+	let amount = number(amount)?;
+	let rate = number(rate.strip_suffix('%')?)? / 100.0;
+
+	let (years, frequency) = match term_years(term.first()?, None) {
+		Some(years) => (years, &term[1..]),
+		None => (term_years(term.first()?, Some(term.get(1)?))?, &term[2..]),
+	};
+
+	let frequency = match frequency {
+		[] => None,
+		[frequency] => Some(
+			FREQUENCIES
+				.iter()
+				.find(|(name, _)| name == frequency)
+				.map(|&(_, per_year)| per_year)?,
+		),
+		_ => return None,
+	};
+
+	(years > 0.0).then_some((amount, rate, years, frequency))
+}
+
+/// A term like `25y`, `300m`, or `25` followed by the unit `years`, in years
+fn term_years(term: &str, unit: Option<&str>) -> Option<f64> {
+	let split = term
+		.find(|c: char| c.is_ascii_alphabetic())
+		.unwrap_or(term.len());
+	let (count, suffix) = term.split_at(split);
+	let count = number(count)?;
+
+	match unit.unwrap_or(suffix) {
+		"y" | "yr" | "yrs" | "year" | "years" => Some(count),
+		"m" | "mo" | "month" | "months" => Some(count / 12.0),
+		_ => None,
+	}
+}
+
+fn number(text: &str) -> Option<f64> {
+	text.parse::<f64>()
+		.ok()
+		.filter(|number| number.is_finite() && *number >= 0.0)
+}
+
+#[cfg(test)]
+mod tests {
+	use proptest::prelude::*;
+
+	use super::*;
+
+	#[test]
+	fn loan_payment() {
+		assert_eq!(
+			loan("1200 at 0% over 1y"),
+			Ok(tr!(
+				"loan-result",
+				payment = "100.00".to_string(),
+				interest = "0.00".to_string()
+			))
+		);
+		assert!(loan("250000 at 3.6% over 25y").is_ok());
+		assert!(loan("250000 at 3.6% over 300 months").is_ok());
+	}
+
+	#[test]
+	fn loan_at_tiny_rate() {
+		assert_eq!(
+			loan("1200 at 0.00000000000000000001% over 1y"),
+			Ok(tr!(
+				"loan-result",
+				payment = "100.00".to_string(),
+				interest = "0.00".to_string()
+			))
+		);
+		assert_eq!(
+			loan("0 at 0.00000000000000000001% over 1y"),
+			Ok(tr!(
+				"loan-result",
+				payment = "0.00".to_string(),
+				interest = "0.00".to_string()
+			))
+		);
+	}
+
+	#[test]
+	fn loan_without_payments() {
+		assert_eq!(loan("1000 at 5% over 0.01y"), Err(tr!("loan-invalid")));
+		assert_eq!(loan("1000 at 0% over 0.4m"), Err(tr!("loan-invalid")));
+		assert_eq!(loan("1000 at 5% over 0y"), Err(tr!("loan-invalid")));
+	}
+
+	#[test]
+	fn compound_less_than_a_month() {
+		assert!(compound("1000 at 5% for 0.01y").is_ok());
+	}
+
+	proptest! {
+		#[test]
+		fn eval_any_input(input in any::<String>()) {
+			let _ = eval(&input);
+		}
+
+		#[test]
+		fn parse_any_terms(
+			amount in "-?[0-9_.e]{0,8}|inf|NaN",
+			rate in "-?[0-9_.e]{0,8}|inf|NaN",
+			term in "-?[0-9.]{0,6}[a-z]{0,6}",
+			rest in "( [a-z]{0,8}){0,2}",
+		) {
+			let input = format!("{amount} at {rate}% over {term}{rest}");
+
+			if let Some((amount, rate, years, frequency)) = parse(&input) {
+				prop_assert!(amount.is_finite() && amount >= 0.0);
+				prop_assert!(rate.is_finite() && rate >= 0.0);
+				prop_assert!(years.is_finite() && years > 0.0);
+				prop_assert!(frequency.is_none_or(|per_year| per_year >= 1.0));
+			}
+		}
+
+		#[test]
+		fn loan_payment_is_finite(
+			amount in 0.0..1e12,
+			rate in prop_oneof![0.0..1000.0, (-30..-10).prop_map(|exp| 10_f64.powi(exp))],
+			months in 1_u32..1200,
+		) {
+			if let Ok(result) = loan(&format!("{amount} at {rate}% over {months}m")) {
+				prop_assert!(!result.contains("inf") && !result.contains("NaN"), "{result}");
+			}
+		}
+	}
+}
@@ -0,0 +1,154 @@
+use std::time::{Duration, Instant};
+
+/// How long a list is searched before it's fetched again
+const MAX_AGE: Duration = Duration::from_secs(3);
+
+/// Something that can be found by typing part of its name
+pub trait Searchable: Clone {
+	type Id: PartialEq;
+
+	/// What stays the same when the list is fetched again
+	fn id(&self) -> Self::Id;
+
+	/// The text matched against the search
+	fn text(&self) -> String;
+
+	/// The line showing this in the list of matches
+	fn summary(&self) -> String;
+}
+
+/// Searching a list of things that change while quicalc is running, like
+/// processes or windows
+#[derive(Debug)]
+pub struct Search<T> {
+	items: Vec<T>,
+	listed: Option<Instant>,
+	/// The items matching the search, best matches first
+	pub matches: Vec<T>,
+	/// The match that's used when the input is submitted
+	pub selected: usize,
+}
+
+impl<T> Default for Search<T> {
+	fn default() -> Self {
+		Self {
+			items: Vec::new(),
+			listed: None,
+			matches: Vec::new(),
+			selected: 0,
+		}
+	}
+}
+
+impl<T: Searchable> Search<T> {
+	/// Whether the list should be fetched again before searching it
+	pub fn is_stale(&self) -> bool {
+		self.listed.is_none_or(|listed| listed.elapsed() > MAX_AGE)
+	}
+
+	/// Whether the list was fetched at all, for lists that don't change
+	pub fn is_listed(&self) -> bool {
+		self.listed.is_some()
+	}
+
+	pub fn set_items(&mut self, items: Vec<T>) {
+		self.items = items;
+		self.listed = Some(Instant::now());
+	}
+
+	/// Forget an item that no longer exists
+	pub fn remove(&mut self, id: &T::Id) {
+		self.items.retain(|item| item.id() != *id);
+		self.matches.retain(|item| item.id() != *id);
+		self.selected = self.selected.min(self.matches.len().saturating_sub(1));
+	}
+
+	/// Find the items matching `query`, keeping the selected one selected if it
+	/// still matches
+	///
+	/// Items that match equally well stay in the order they were listed in.
+	pub fn search(&mut self, query: &str) {
+		let selected = self.selected().map(Searchable::id);
+		let query = query.trim().to_lowercase();
+
+		let mut matches = if query.is_empty() {
+			Vec::new()
+		} else {
+			self.items
+				.iter()
+				.filter_map(|item| Some((score(&item.text().to_lowercase(), &query)?, item)))
+				.collect::<Vec<_>>()
+		};
+
+		matches.sort_by_key(|(score, _)| *score);
+
+		self.matches = matches.into_iter().map(|(_, item)| item.clone()).collect();
+		self.selected = self
+			.matches
+			.iter()
+			.position(|item| Some(item.id()) == selected)
+			.unwrap_or_default();
+	}
+
+	pub fn selected(&self) -> Option<&T> {
+		self.matches.get(self.selected)
+	}
+
+	/// The lines showing the matches
+	pub fn summaries(&self) -> Vec<String> {
+		self.matches.iter().map(Searchable::summary).collect()
+	}
+}
+
+/// Choosing one of the matches of a search, whatever they are
+pub trait Selection {
+	fn select(&mut self, index: usize);
+
+	/// Select the next match, or the previous one, wrapping around
+	fn select_next(&mut self, forward: bool);
+}
+
+impl<T> Selection for Search<T> {
+	fn select(&mut self, index: usize) {
+		if index < self.matches.len() {
+			self.selected = index;
+		}
+	}
+
+	fn select_next(&mut self, forward: bool) {
+		let len = self.matches.len();
+
+		if len == 0 {
+			return;
+		}
+
+		self.selected = if forward {
+			(self.selected + 1) % len
+		} else {
+			(self.selected + len - 1) % len
+		};
+	}
+}
+
+/// How well a lowercase `text` matches a lowercase `query`, lower being
+/// better, or `None` if the query's characters don't appear in the text in
+/// order
+fn score(text: &str, query: &str) -> Option<usize> {
+	if text.starts_with(query) {
+		return Some(0);
+	}
+
+	if text.contains(query) {
+		return Some(1);
+	}
+
+	// characters between the ones matching the query make a worse match
+	let mut gaps = 0;
+	let mut chars = text.chars();
+
+	for wanted in query.chars() {
+		gaps += chars.position(|c| c == wanted)?;
+	}
+
+	Some(2 + gaps)
+}
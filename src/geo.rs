@@ -0,0 +1,135 @@
+use std::sync::LazyLock;
+
+use crate::locale::tr;
+
+/// The mean radius of the earth, in km
+const EARTH_RADIUS: f64 = 6371.0088;
+const KM_PER_MILE: f64 = 1.609344;
+/// Arrows pointing in the eight compass directions, from north clockwise
+const ARROWS: [&str; 8] = ["↑", "↗", "→", "↘", "↓", "↙", "←", "↖"];
+
+/// The cities that can be entered by name, from
+/// `name,latitude,longitude,time zone` lines
+static CITIES: LazyLock<Vec<(&str, Place)>> = LazyLock::new(|| {
+	include_str!("../assets/cities.csv")
+		.lines()
+		.filter_map(|line| {
+			let (name, rest) = line.split_once(',')?;
+			let (coordinates, zone) = rest.rsplit_once(',')?;
+
+			Some((name, Place {
+				point: Point::parse(coordinates)?,
+				zone: Some(zone),
+			}))
+		})
+		.collect()
+});
+
+/// A point on the earth, in degrees
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Point {
+	pub latitude: f64,
+	pub longitude: f64,
+}
+
+/// Coordinates or a city
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Place {
+	pub point: Point,
+	/// The IANA name of a city's time zone
+	pub zone: Option<&'static str>,
+}
+
+impl Point {
+	/// Coordinates like `52.52,13.40`
+	fn parse(text: &str) -> Option<Self> {
+		let (latitude, longitude) = text.split_once(',')?;
+		let (latitude, longitude) = (
+			latitude.trim().parse::<f64>().ok()?,
+			longitude.trim().parse::<f64>().ok()?,
+		);
+
+		((-90.0..=90.0).contains(&latitude) && (-180.0..=180.0).contains(&longitude)).then_some(
+			Self {
+				latitude,
+				longitude,
+			},
+		)
+	}
+
+	/// The great-circle distance to another point in km, with the haversine
+	/// formula
+	fn distance(self, other: Self) -> f64 {
+		let (lat1, lat2) = (self.latitude.to_radians(), other.latitude.to_radians());
+		let dlat = lat2 - lat1;
+		let dlon = (other.longitude - self.longitude).to_radians();
+
+		let a = (dlat / 2.0).sin().powi(2) + lat1.cos() * lat2.cos() * (dlon / 2.0).sin().powi(2);
+
+		2.0 * EARTH_RADIUS * a.sqrt().asin()
+	}
+
+	/// The initial bearing towards another point, in degrees clockwise from
+	/// north
+	fn bearing(self, other: Self) -> f64 {
+		let (lat1, lat2) = (self.latitude.to_radians(), other.latitude.to_radians());
+		let dlon = (other.longitude - self.longitude).to_radians();
+
+		let y = dlon.sin() * lat2.cos();
+		let x = lat1.cos() * lat2.sin() - lat1.sin() * lat2.cos() * dlon.cos();
+
+		y.atan2(x).to_degrees().rem_euclid(360.0)
+	}
+}
+
+/// Coordinates like `52.52,13.40`, or the name of a city in the gazetteer
+pub fn find(text: &str) -> Result<Place, String> {
+	let text = text.trim();
+	let lowercase = text.to_lowercase();
+
+	Point::parse(text)
+		.map(|point| Place { point, zone: None })
+		.or_else(|| {
+			CITIES
+				.iter()
+				.find(|(name, _)| name.to_lowercase() == lowercase)
+				.map(|&(_, place)| place)
+		})
+		.ok_or_else(|| tr!("geo-unknown-place", place = text))
+}
+
+/// The distance and bearing between two places, like `52.52,13.40 to London`,
+/// or `None` if the input is empty
+pub fn eval(input: &str) -> Option<Result<String, String>> {
+	if input.trim().is_empty() {
+		return None;
+	}
+
+	Some(match input.split_once(" to ") {
+		Some((from, to)) => between(from, to),
+		None => Err(tr!("geo-hint")),
+	})
+}
+
+fn between(from: &str, to: &str) -> Result<String, String> {
+	let (from, to) = (find(from)?.point, find(to)?.point);
+	let km = from.distance(to);
+	let bearing = from.bearing(to);
+
+	Ok(tr!(
+		"geo-distance",
+		km = round(km),
+		mi = round(km / KM_PER_MILE),
+		bearing = format!("{bearing:.0}"),
+		arrow = ARROWS[(bearing / 45.0).round() as usize % ARROWS.len()]
+	))
+}
+
+/// A distance with fewer decimals the longer it is
+fn round(distance: f64) -> String {
+	match distance {
+		..10.0 => format!("{distance:.2}"),
+		..100.0 => format!("{distance:.1}"),
+		_ => format!("{distance:.0}"),
+	}
+}
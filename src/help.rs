@@ -0,0 +1,183 @@
+use iced::{
+	Element, Length,
+	widget::{Column, column, row, scrollable, text},
+};
+
+#[cfg(feature = "python")]
+use crate::python;
+use crate::{
+	Message, Quicalc, QuicalcMode, about, bookmarks, hotkey, locale::tr, session, shortcuts,
+	system::SystemCommand, timers,
+};
+
+pub const COMMAND: &str = "help";
+
+const KALK_FUNCTIONS: &[(&str, &str)] = &[
+	("x = 5", "help-fn-variable"),
+	("f(x) = x^2 + 1", "help-fn-function"),
+	("sqrt(x), cbrt(x)", "help-fn-root"),
+	("sin, cos, tan", "help-fn-trig"),
+	("asin, acos, atan", "help-fn-inverse-trig"),
+	("ln(x), log(x), exp(x)", "help-fn-log"),
+	("abs, floor, ceil, round", "help-fn-rounding"),
+	("min, max, gcd, lcm", "help-fn-comparison"),
+	("n!", "help-fn-factorial"),
+	("sum(n=1, 10, n)", "help-fn-sum"),
+	("prod(n=1, 5, n)", "help-fn-product"),
+	("integrate(0, pi, sin(x) dx)", "help-fn-integral"),
+	("f'(2)", "help-fn-derivative"),
+	("pi, e, tau, phi", "help-fn-constants"),
+	("week 2025-03-14", "help-fn-week"),
+	("easter 2026", "help-fn-easter"),
+	("workdays between DATE and DATE", "help-fn-workdays"),
+	("tip 84.60 18% /3", "help-fn-tip"),
+	("ar 2560x1440 27\"", "help-fn-aspect"),
+	("120 -> 150", "help-fn-change"),
+	("150 as % of 600", "help-fn-share"),
+	("compound A at R% for Ny monthly", "help-fn-compound"),
+	("net 100, gross 120", "help-fn-tax"),
+	("1.5 TiB in GB", "help-fn-sizes"),
+	("4.7 GB at 35 Mbit/s", "help-fn-transfer"),
+];
+
+fn commands() -> Vec<(String, String)> {
+	let mut commands = vec![
+		(
+			format!("{} / q / exit / calc", QuicalcMode::KALK_COMMAND),
+			tr!("help-command-kalk"),
+		),
+		(
+			QuicalcMode::PAD_COMMAND.to_string(),
+			tr!("help-command-pad"),
+		),
+		(
+			QuicalcMode::SYS_COMMAND.to_string(),
+			tr!(
+				"help-command-sys",
+				commands = SystemCommand::ALL
+					.iter()
+					.map(|command| command.name())
+					.collect::<Vec<_>>()
+					.join(", "),
+			),
+		),
+		(
+			QuicalcMode::KILL_COMMAND.to_string(),
+			tr!("help-command-kill"),
+		),
+		(
+			QuicalcMode::WIN_COMMAND.to_string(),
+			tr!("help-command-win"),
+		),
+		(
+			format!("{} [NAME]", bookmarks::COMMAND),
+			tr!("help-command-go"),
+		),
+		(
+			format!("{} DURATION [LABEL]", timers::COMMAND),
+			tr!("help-command-timer"),
+		),
+		(
+			timers::STOPWATCH_COMMAND.to_string(),
+			tr!("help-command-sw"),
+		),
+		(
+			QuicalcMode::CLOCK_COMMAND.to_string(),
+			tr!("help-command-clock"),
+		),
+		(
+			QuicalcMode::CRON_COMMAND.to_string(),
+			tr!("help-command-cron"),
+		),
+		(
+			format!("{} A to B", QuicalcMode::GEO_COMMAND),
+			tr!("help-command-geo"),
+		),
+		(
+			format!("{} PLACE DATE", QuicalcMode::SUN_COMMAND),
+			tr!("help-command-sun"),
+		),
+		(
+			format!("{} A at R% over Ny", QuicalcMode::LOAN_COMMAND),
+			tr!("help-command-loan"),
+		),
+		#[cfg(feature = "weather")]
+		(QuicalcMode::WX_COMMAND.to_string(), tr!("help-command-wx")),
+		#[cfg(feature = "prices")]
+		(
+			format!("{} SYMBOL", QuicalcMode::PRICE_COMMAND),
+			tr!("help-command-price"),
+		),
+		(
+			Quicalc::CLEAR_COMMAND.to_string(),
+			tr!("help-command-clear"),
+		),
+		(
+			format!("{} save NAME", session::COMMAND),
+			tr!("help-command-session-save"),
+		),
+		(
+			format!("{} load NAME", session::COMMAND),
+			tr!("help-command-session-load"),
+		),
+		(COMMAND.to_string(), tr!("help-command-help")),
+		(about::COMMANDS.join(" / "), tr!("help-command-about")),
+	];
+
+	#[cfg(feature = "python")]
+	commands.splice(0..0, [
+		(
+			QuicalcMode::PYTHON_COMMAND.to_string(),
+			tr!("help-command-python"),
+		),
+		#[cfg(feature = "sym")]
+		(
+			QuicalcMode::SYM_COMMAND.to_string(),
+			tr!("help-command-sym"),
+		),
+		(python::RESET_COMMAND.to_string(), tr!("help-command-reset")),
+	]);
+
+	commands
+}
+
+pub fn section<'a>(
+	title: String,
+	entries: impl IntoIterator<Item = (String, String)>,
+) -> Column<'a, Message> {
+	entries.into_iter().fold(
+		column![text(title).size(20)].spacing(2),
+		|section, (name, description)| {
+			section.push(row![
+				text(name).size(14).width(Length::FillPortion(1)),
+				text(description).size(14).width(Length::FillPortion(1)),
+			])
+		},
+	)
+}
+
+pub fn view<'a>() -> Element<'a, Message> {
+	scrollable(
+		column![
+			section(tr!("help-commands"), commands()),
+			section(
+				tr!("help-calculator"),
+				KALK_FUNCTIONS
+					.iter()
+					.map(|&(name, description)| (name.to_string(), tr!(description)))
+			),
+			section(tr!("help-keys"), [
+				(hotkey::active().to_string(), tr!("help-key-open")),
+				("Escape".to_string(), tr!("help-key-hide")),
+				(
+					format!("F1 / {}", shortcuts::INPUT),
+					tr!("shortcut-shortcuts")
+				),
+			]),
+		]
+		.spacing(8)
+		.padding(4),
+	)
+	.height(Length::Fill)
+	.into()
+}
@@ -0,0 +1,724 @@
+use std::{
+	cell::RefCell,
+	iter,
+	sync::{LazyLock, Mutex, PoisonError},
+	thread,
+	time::{Duration, Instant},
+};
+
+use crossbeam_channel::{Receiver, Sender};
+use global_hotkey::{
+	GlobalHotKeyManager,
+	hotkey::{Code, HotKey, Modifiers},
+};
+use iced::keyboard::{
+	Modifiers as IcedModifiers,
+	key::{Code as IcedCode, Physical},
+};
+use rdev::{EventType, Key};
+use tracing::{debug, error, info, warn};
+
+use crate::{
+	config::{HotkeyConfig, TapKey},
+	locale::tr,
+	tray,
+};
+
+static ACTIVE: Mutex<Option<HotKey>> = Mutex::new(None);
+#[cfg(feature = "ocr")]
+static CAPTURE: Mutex<Option<HotKey>> = Mutex::new(None);
+static SELECTION: Mutex<Option<HotKey>> = Mutex::new(None);
+
+thread_local! {
+	static MANAGER: RefCell<Option<GlobalHotKeyManager>> = const { RefCell::new(None) };
+}
+
+static GESTURES: LazyLock<(Sender<Gesture>, Receiver<Gesture>)> =
+	LazyLock::new(crossbeam_channel::unbounded);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Gesture {
+	DoubleTap,
+	Chord,
+}
+
+pub fn active() -> HotKey {
+	ACTIVE
+		.lock()
+		.unwrap_or_else(PoisonError::into_inner)
+		.unwrap_or(HotkeyConfig::default().key)
+}
+
+pub fn matches(id: u32) -> bool {
+	let hotkey = active();
+	hotkey.id() == id || numpad_variant(hotkey).is_some_and(|numpad| numpad.id() == id)
+}
+
+fn numpad_variant(hotkey: HotKey) -> Option<HotKey> {
+	(hotkey.key == Code::Enter).then(|| HotKey::new(Some(hotkey.mods), Code::NumpadEnter))
+}
+
+fn register_numpad_variant(hotkeys: &GlobalHotKeyManager, hotkey: HotKey) {
+	if let Some(numpad) = numpad_variant(hotkey) {
+		hotkeys
+			.register(numpad)
+			.inspect_err(|err| debug!(?err, %numpad, "error registering numpad hotkey"))
+			.ok();
+	}
+}
+
+pub fn from_iced(modifiers: IcedModifiers, key: Physical) -> Option<(Modifiers, Code)> {
+	let Physical::Code(code) = key else {
+		return None;
+	};
+
+	let code = code_from_iced(code)?;
+
+	let mut mods = Modifiers::empty();
+	mods.set(Modifiers::SHIFT, modifiers.shift());
+	mods.set(Modifiers::CONTROL, modifiers.control());
+	mods.set(Modifiers::ALT, modifiers.alt());
+	mods.set(Modifiers::SUPER, modifiers.logo());
+
+	Some((mods, code))
+}
+
+/// The global-hotkey key code for an iced key code
+///
+/// They're mostly named the same, after the W3C UI Events spec, but are
+/// mapped one by one so that a renamed key doesn't silently stop working.
+const fn code_from_iced(code: IcedCode) -> Option<Code> {
+	Some(match code {
+		IcedCode::Backquote => Code::Backquote,
+		IcedCode::Backslash => Code::Backslash,
+		IcedCode::BracketLeft => Code::BracketLeft,
+		IcedCode::BracketRight => Code::BracketRight,
+		IcedCode::Comma => Code::Comma,
+		IcedCode::Digit0 => Code::Digit0,
+		IcedCode::Digit1 => Code::Digit1,
+		IcedCode::Digit2 => Code::Digit2,
+		IcedCode::Digit3 => Code::Digit3,
+		IcedCode::Digit4 => Code::Digit4,
+		IcedCode::Digit5 => Code::Digit5,
+		IcedCode::Digit6 => Code::Digit6,
+		IcedCode::Digit7 => Code::Digit7,
+		IcedCode::Digit8 => Code::Digit8,
+		IcedCode::Digit9 => Code::Digit9,
+		IcedCode::Equal => Code::Equal,
+		IcedCode::IntlBackslash => Code::IntlBackslash,
+		IcedCode::IntlRo => Code::IntlRo,
+		IcedCode::IntlYen => Code::IntlYen,
+		IcedCode::KeyA => Code::KeyA,
+		IcedCode::KeyB => Code::KeyB,
+		IcedCode::KeyC => Code::KeyC,
+		IcedCode::KeyD => Code::KeyD,
+		IcedCode::KeyE => Code::KeyE,
+		IcedCode::KeyF => Code::KeyF,
+		IcedCode::KeyG => Code::KeyG,
+		IcedCode::KeyH => Code::KeyH,
+		IcedCode::KeyI => Code::KeyI,
+		IcedCode::KeyJ => Code::KeyJ,
+		IcedCode::KeyK => Code::KeyK,
+		IcedCode::KeyL => Code::KeyL,
+		IcedCode::KeyM => Code::KeyM,
+		IcedCode::KeyN => Code::KeyN,
+		IcedCode::KeyO => Code::KeyO,
+		IcedCode::KeyP => Code::KeyP,
+		IcedCode::KeyQ => Code::KeyQ,
+		IcedCode::KeyR => Code::KeyR,
+		IcedCode::KeyS => Code::KeyS,
+		IcedCode::KeyT => Code::KeyT,
+		IcedCode::KeyU => Code::KeyU,
+		IcedCode::KeyV => Code::KeyV,
+		IcedCode::KeyW => Code::KeyW,
+		IcedCode::KeyX => Code::KeyX,
+		IcedCode::KeyY => Code::KeyY,
+		IcedCode::KeyZ => Code::KeyZ,
+		IcedCode::Minus => Code::Minus,
+		IcedCode::Period => Code::Period,
+		IcedCode::Quote => Code::Quote,
+		IcedCode::Semicolon => Code::Semicolon,
+		IcedCode::Slash => Code::Slash,
+		IcedCode::AltLeft => Code::AltLeft,
+		IcedCode::AltRight => Code::AltRight,
+		IcedCode::Backspace => Code::Backspace,
+		IcedCode::CapsLock => Code::CapsLock,
+		IcedCode::ContextMenu => Code::ContextMenu,
+		IcedCode::ControlLeft => Code::ControlLeft,
+		IcedCode::ControlRight => Code::ControlRight,
+		IcedCode::Enter => Code::Enter,
+		IcedCode::ShiftLeft => Code::ShiftLeft,
+		IcedCode::ShiftRight => Code::ShiftRight,
+		IcedCode::Space => Code::Space,
+		IcedCode::Tab => Code::Tab,
+		IcedCode::Convert => Code::Convert,
+		IcedCode::KanaMode => Code::KanaMode,
+		IcedCode::Lang1 => Code::Lang1,
+		IcedCode::Lang2 => Code::Lang2,
+		IcedCode::Lang3 => Code::Lang3,
+		IcedCode::Lang4 => Code::Lang4,
+		IcedCode::Lang5 => Code::Lang5,
+		IcedCode::NonConvert => Code::NonConvert,
+		IcedCode::Delete => Code::Delete,
+		IcedCode::End => Code::End,
+		IcedCode::Help => Code::Help,
+		IcedCode::Home => Code::Home,
+		IcedCode::Insert => Code::Insert,
+		IcedCode::PageDown => Code::PageDown,
+		IcedCode::PageUp => Code::PageUp,
+		IcedCode::ArrowDown => Code::ArrowDown,
+		IcedCode::ArrowLeft => Code::ArrowLeft,
+		IcedCode::ArrowRight => Code::ArrowRight,
+		IcedCode::ArrowUp => Code::ArrowUp,
+		IcedCode::NumLock => Code::NumLock,
+		IcedCode::Numpad0 => Code::Numpad0,
+		IcedCode::Numpad1 => Code::Numpad1,
+		IcedCode::Numpad2 => Code::Numpad2,
+		IcedCode::Numpad3 => Code::Numpad3,
+		IcedCode::Numpad4 => Code::Numpad4,
+		IcedCode::Numpad5 => Code::Numpad5,
+		IcedCode::Numpad6 => Code::Numpad6,
+		IcedCode::Numpad7 => Code::Numpad7,
+		IcedCode::Numpad8 => Code::Numpad8,
+		IcedCode::Numpad9 => Code::Numpad9,
+		IcedCode::NumpadAdd => Code::NumpadAdd,
+		IcedCode::NumpadBackspace => Code::NumpadBackspace,
+		IcedCode::NumpadClear => Code::NumpadClear,
+		IcedCode::NumpadClearEntry => Code::NumpadClearEntry,
+		IcedCode::NumpadComma => Code::NumpadComma,
+		IcedCode::NumpadDecimal => Code::NumpadDecimal,
+		IcedCode::NumpadDivide => Code::NumpadDivide,
+		IcedCode::NumpadEnter => Code::NumpadEnter,
+		IcedCode::NumpadEqual => Code::NumpadEqual,
+		IcedCode::NumpadHash => Code::NumpadHash,
+		IcedCode::NumpadMemoryAdd => Code::NumpadMemoryAdd,
+		IcedCode::NumpadMemoryClear => Code::NumpadMemoryClear,
+		IcedCode::NumpadMemoryRecall => Code::NumpadMemoryRecall,
+		IcedCode::NumpadMemoryStore => Code::NumpadMemoryStore,
+		IcedCode::NumpadMemorySubtract => Code::NumpadMemorySubtract,
+		IcedCode::NumpadMultiply => Code::NumpadMultiply,
+		IcedCode::NumpadParenLeft => Code::NumpadParenLeft,
+		IcedCode::NumpadParenRight => Code::NumpadParenRight,
+		IcedCode::NumpadStar => Code::NumpadStar,
+		IcedCode::NumpadSubtract => Code::NumpadSubtract,
+		IcedCode::Escape => Code::Escape,
+		IcedCode::Fn => Code::Fn,
+		IcedCode::FnLock => Code::FnLock,
+		IcedCode::PrintScreen => Code::PrintScreen,
+		IcedCode::ScrollLock => Code::ScrollLock,
+		IcedCode::Pause => Code::Pause,
+		IcedCode::BrowserBack => Code::BrowserBack,
+		IcedCode::BrowserFavorites => Code::BrowserFavorites,
+		IcedCode::BrowserForward => Code::BrowserForward,
+		IcedCode::BrowserHome => Code::BrowserHome,
+		IcedCode::BrowserRefresh => Code::BrowserRefresh,
+		IcedCode::BrowserSearch => Code::BrowserSearch,
+		IcedCode::BrowserStop => Code::BrowserStop,
+		IcedCode::Eject => Code::Eject,
+		IcedCode::LaunchApp1 => Code::LaunchApp1,
+		IcedCode::LaunchApp2 => Code::LaunchApp2,
+		IcedCode::LaunchMail => Code::LaunchMail,
+		IcedCode::MediaPlayPause => Code::MediaPlayPause,
+		IcedCode::MediaSelect => Code::MediaSelect,
+		IcedCode::MediaStop => Code::MediaStop,
+		IcedCode::MediaTrackNext => Code::MediaTrackNext,
+		IcedCode::MediaTrackPrevious => Code::MediaTrackPrevious,
+		IcedCode::Power => Code::Power,
+		IcedCode::Sleep => Code::Sleep,
+		IcedCode::AudioVolumeDown => Code::AudioVolumeDown,
+		IcedCode::AudioVolumeMute => Code::AudioVolumeMute,
+		IcedCode::AudioVolumeUp => Code::AudioVolumeUp,
+		IcedCode::WakeUp => Code::WakeUp,
+		IcedCode::Hyper => Code::Hyper,
+		IcedCode::Turbo => Code::Turbo,
+		IcedCode::Abort => Code::Abort,
+		IcedCode::Resume => Code::Resume,
+		IcedCode::Suspend => Code::Suspend,
+		IcedCode::Again => Code::Again,
+		IcedCode::Copy => Code::Copy,
+		IcedCode::Cut => Code::Cut,
+		IcedCode::Find => Code::Find,
+		IcedCode::Open => Code::Open,
+		IcedCode::Paste => Code::Paste,
+		IcedCode::Props => Code::Props,
+		IcedCode::Select => Code::Select,
+		IcedCode::Undo => Code::Undo,
+		IcedCode::Hiragana => Code::Hiragana,
+		IcedCode::Katakana => Code::Katakana,
+		IcedCode::F1 => Code::F1,
+		IcedCode::F2 => Code::F2,
+		IcedCode::F3 => Code::F3,
+		IcedCode::F4 => Code::F4,
+		IcedCode::F5 => Code::F5,
+		IcedCode::F6 => Code::F6,
+		IcedCode::F7 => Code::F7,
+		IcedCode::F8 => Code::F8,
+		IcedCode::F9 => Code::F9,
+		IcedCode::F10 => Code::F10,
+		IcedCode::F11 => Code::F11,
+		IcedCode::F12 => Code::F12,
+		IcedCode::F13 => Code::F13,
+		IcedCode::F14 => Code::F14,
+		IcedCode::F15 => Code::F15,
+		IcedCode::F16 => Code::F16,
+		IcedCode::F17 => Code::F17,
+		IcedCode::F18 => Code::F18,
+		IcedCode::F19 => Code::F19,
+		IcedCode::F20 => Code::F20,
+		IcedCode::F21 => Code::F21,
+		IcedCode::F22 => Code::F22,
+		IcedCode::F23 => Code::F23,
+		IcedCode::F24 => Code::F24,
+		IcedCode::F25 => Code::F25,
+		IcedCode::F26 => Code::F26,
+		IcedCode::F27 => Code::F27,
+		IcedCode::F28 => Code::F28,
+		IcedCode::F29 => Code::F29,
+		IcedCode::F30 => Code::F30,
+		IcedCode::F31 => Code::F31,
+		IcedCode::F32 => Code::F32,
+		IcedCode::F33 => Code::F33,
+		IcedCode::F34 => Code::F34,
+		IcedCode::F35 => Code::F35,
+		// iced names the keys that the spec calls meta after the super key
+		IcedCode::SuperLeft => Code::MetaLeft,
+		IcedCode::SuperRight => Code::MetaRight,
+		_ => return None,
+	})
+}
+
+pub fn register(config: &HotkeyConfig) -> Result<(), String> {
+	let hotkeys = GlobalHotKeyManager::new().map_err(|e| e.to_string())?;
+	let result = register_first(&hotkeys, config);
+	MANAGER.set(Some(hotkeys));
+	result
+}
+
+/// Register the configured hotkey, or the first fallback that's available
+fn register_first(hotkeys: &GlobalHotKeyManager, config: &HotkeyConfig) -> Result<(), String> {
+	let previous = *ACTIVE.lock().unwrap_or_else(PoisonError::into_inner);
+
+	for hotkey in iter::once(config.key).chain(config.fallbacks.iter().copied()) {
+		match hotkeys.register(hotkey) {
+			Ok(()) => {
+				if hotkey != config.key && previous != Some(hotkey) {
+					warn!(%hotkey, "using fallback hotkey");
+					tray::notify(
+						&tr!("hotkey-changed"),
+						&tr!(
+							"hotkey-changed-body",
+							default = config.key.to_string(),
+							hotkey = hotkey.to_string(),
+						),
+					);
+				}
+
+				register_numpad_variant(hotkeys, hotkey);
+				*ACTIVE.lock().unwrap_or_else(PoisonError::into_inner) = Some(hotkey);
+				return Ok(());
+			}
+			Err(err) => warn!(?err, %hotkey, "error registering hotkey"),
+		}
+	}
+
+	tray::notify(&tr!("hotkey-unavailable"), &tr!("hotkey-unavailable-body"));
+
+	Err("all configured hotkeys are already in use".to_string())
+}
+
+/// Register the configured or fallback hotkey again after they have changed
+pub fn reload(config: &HotkeyConfig) -> Result<(), String> {
+	MANAGER.with_borrow(|hotkeys| {
+		let Some(hotkeys) = hotkeys else {
+			return Ok(());
+		};
+
+		let hotkey = active();
+
+		for hotkey in iter::once(hotkey).chain(numpad_variant(hotkey)) {
+			hotkeys
+				.unregister(hotkey)
+				.inspect_err(|err| debug!(?err, %hotkey, "error unregistering hotkey"))
+				.ok();
+		}
+
+		register_first(hotkeys, config)
+	})
+}
+
+/// Register the hotkey for capturing an expression from the screen, after the
+/// main hotkey has been registered, replacing the previous one
+#[cfg(feature = "ocr")]
+pub fn register_capture(hotkey: Option<HotKey>) -> Result<(), String> {
+	register_extra(&CAPTURE, hotkey)
+}
+
+#[cfg(feature = "ocr")]
+pub fn is_capture(id: u32) -> bool {
+	is_extra(&CAPTURE, id)
+}
+
+/// Register the hotkey for evaluating the selected text, after the main hotkey
+/// has been registered, replacing the previous one
+pub fn register_selection(hotkey: Option<HotKey>) -> Result<(), String> {
+	register_extra(&SELECTION, hotkey)
+}
+
+pub fn is_selection(id: u32) -> bool {
+	is_extra(&SELECTION, id)
+}
+
+/// Register a hotkey for something other than showing the window, replacing
+/// the one previously registered in `slot`
+fn register_extra(slot: &Mutex<Option<HotKey>>, hotkey: Option<HotKey>) -> Result<(), String> {
+	MANAGER.with_borrow(|hotkeys| {
+		let hotkeys = hotkeys.as_ref().ok_or("no hotkey manager")?;
+		let mut slot = slot.lock().unwrap_or_else(PoisonError::into_inner);
+
+		if let Some(previous) = slot.take() {
+			hotkeys
+				.unregister(previous)
+				.inspect_err(|err| debug!(?err, %previous, "error unregistering hotkey"))
+				.ok();
+		}
+
+		if let Some(hotkey) = hotkey {
+			hotkeys.register(hotkey).map_err(|e| e.to_string())?;
+			*slot = Some(hotkey);
+		}
+
+		Ok(())
+	})
+}
+
+fn is_extra(slot: &Mutex<Option<HotKey>>, id: u32) -> bool {
+	slot.lock()
+		.unwrap_or_else(PoisonError::into_inner)
+		.is_some_and(|hotkey| hotkey.id() == id)
+}
+
+/// Register all hotkeys again, like after resuming from sleep, when their
+/// registrations may have been lost
+///
+/// The main hotkey is registered like when starting, so a fallback is used if
+/// the configured hotkey has been taken by another program in the meantime.
+pub fn reregister(config: &HotkeyConfig) -> Result<(), String> {
+	let main = reload(config);
+
+	#[cfg(feature = "ocr")]
+	reregister_extra(&CAPTURE)?;
+	reregister_extra(&SELECTION)?;
+
+	main
+}
+
+fn reregister_extra(slot: &Mutex<Option<HotKey>>) -> Result<(), String> {
+	let hotkey = *slot.lock().unwrap_or_else(PoisonError::into_inner);
+
+	match hotkey {
+		Some(hotkey) => register_extra(slot, Some(hotkey)),
+		None => Ok(()),
+	}
+}
+
+/// Register the hotkey again if its registration was lost, like when another
+/// app took it over, returning whether it was
+///
+/// Registering a hotkey that's still registered fails, so that's tried to find
+/// out whether it still is. This only works on Windows, where the registration
+/// isn't tracked by the hotkey manager itself.
+#[cfg(windows)]
+pub fn restore_lost() -> Result<bool, String> {
+	MANAGER.with_borrow(|hotkeys| {
+		let Some(hotkeys) = hotkeys else {
+			return Ok(false);
+		};
+
+		let hotkey = active();
+
+		match hotkeys.register(hotkey) {
+			Ok(()) => {
+				register_numpad_variant(hotkeys, hotkey);
+				Ok(true)
+			}
+			Err(global_hotkey::Error::AlreadyRegistered(_)) => Ok(false),
+			Err(err) => Err(err.to_string()),
+		}
+	})
+}
+
+/// Unregister all hotkeys, so that another process can register them
+pub fn release() {
+	MANAGER.take();
+}
+
+pub fn receiver() -> &'static Receiver<Gesture> {
+	&GESTURES.1
+}
+
+pub fn listen(config: &HotkeyConfig) {
+	if config.double_tap.is_none() && config.chord.is_empty() {
+		return;
+	}
+
+	let mut detector = Detector::new(config);
+
+	thread::spawn(move || {
+		info!("listening for hotkey gestures");
+
+		rdev::listen(move |event| {
+			let now = Instant::now();
+
+			let gesture = match event.event_type {
+				EventType::KeyPress(key) => detector.press(key, now),
+				EventType::KeyRelease(key) => detector.release(key, now),
+				_ => None,
+			};
+
+			if let Some(gesture) = gesture {
+				debug!(?gesture, "detected hotkey gesture");
+				GESTURES.0.send(gesture).ok();
+			}
+		})
+		.inspect_err(|err| error!(?err, "error listening for hotkey gestures"))
+		.ok();
+	});
+}
+
+struct Detector {
+	timeout: Duration,
+	modifiers: Modifiers,
+	double_tap: Option<DoubleTap>,
+	chord: Chord,
+}
+
+struct DoubleTap {
+	modifier: Modifiers,
+	clean: bool,
+	last_tap: Option<Instant>,
+}
+
+struct Chord {
+	steps: Vec<HotKey>,
+	progress: usize,
+	last_step: Option<Instant>,
+}
+
+impl Detector {
+	fn new(config: &HotkeyConfig) -> Self {
+		Self {
+			timeout: Duration::from_millis(config.gesture_timeout_ms),
+			modifiers: Modifiers::empty(),
+			double_tap: config.double_tap.map(|key| DoubleTap {
+				modifier: match key {
+					TapKey::Ctrl => Modifiers::CONTROL,
+					TapKey::Alt => Modifiers::ALT,
+					TapKey::Shift => Modifiers::SHIFT,
+					TapKey::Super => Modifiers::SUPER,
+				},
+				clean: false,
+				last_tap: None,
+			}),
+			chord: Chord {
+				steps: config.chord.clone(),
+				progress: 0,
+				last_step: None,
+			},
+		}
+	}
+
+	fn press(&mut self, key: Key, now: Instant) -> Option<Gesture> {
+		if let Some(modifier) = modifier(key) {
+			let repeat = self.modifiers.contains(modifier);
+			self.modifiers.insert(modifier);
+
+			if let Some(tap) = &mut self.double_tap
+				&& !repeat
+			{
+				if modifier != tap.modifier {
+					tap.clean = false;
+					tap.last_tap = None;
+				} else if self.modifiers == modifier
+					&& tap.last_tap.is_some_and(|last| now - last <= self.timeout)
+				{
+					tap.last_tap = None;
+					return Some(Gesture::DoubleTap);
+				} else {
+					tap.clean = self.modifiers == modifier;
+				}
+			}
+
+			return None;
+		}
+
+		if let Some(tap) = &mut self.double_tap {
+			tap.clean = false;
+			tap.last_tap = None;
+		}
+
+		let code = code(key)?;
+		self.chord.step(self.modifiers, code, now, self.timeout)
+	}
+
+	fn release(&mut self, key: Key, now: Instant) -> Option<Gesture> {
+		let modifier = modifier(key)?;
+		self.modifiers.remove(modifier);
+
+		if let Some(tap) = &mut self.double_tap
+			&& modifier == tap.modifier
+		{
+			tap.last_tap = tap.clean.then_some(now);
+			tap.clean = false;
+		}
+
+		None
+	}
+}
+
+impl Chord {
+	fn step(
+		&mut self,
+		modifiers: Modifiers,
+		code: Code,
+		now: Instant,
+		timeout: Duration,
+	) -> Option<Gesture> {
+		if self.steps.is_empty() {
+			return None;
+		}
+
+		if self.last_step.is_none_or(|last| now - last > timeout) {
+			self.progress = 0;
+		}
+
+		if self.steps[self.progress].matches(modifiers, code) {
+			self.progress += 1;
+		} else if self.steps[0].matches(modifiers, code) {
+			self.progress = 1;
+		} else {
+			self.progress = 0;
+		}
+
+		self.last_step = Some(now);
+
+		if self.progress == self.steps.len() {
+			self.progress = 0;
+			Some(Gesture::Chord)
+		} else {
+			None
+		}
+	}
+}
+
+fn modifier(key: Key) -> Option<Modifiers> {
+	match key {
+		Key::ControlLeft | Key::ControlRight => Some(Modifiers::CONTROL),
+		Key::Alt | Key::AltGr => Some(Modifiers::ALT),
+		Key::ShiftLeft | Key::ShiftRight => Some(Modifiers::SHIFT),
+		Key::MetaLeft | Key::MetaRight => Some(Modifiers::SUPER),
+		_ => None,
+	}
+}
+
+fn code(key: Key) -> Option<Code> {
+	Some(match key {
+		Key::Backspace => Code::Backspace,
+		Key::CapsLock => Code::CapsLock,
+		Key::Delete => Code::Delete,
+		Key::DownArrow => Code::ArrowDown,
+		Key::End => Code::End,
+		Key::Escape => Code::Escape,
+		Key::F1 => Code::F1,
+		Key::F2 => Code::F2,
+		Key::F3 => Code::F3,
+		Key::F4 => Code::F4,
+		Key::F5 => Code::F5,
+		Key::F6 => Code::F6,
+		Key::F7 => Code::F7,
+		Key::F8 => Code::F8,
+		Key::F9 => Code::F9,
+		Key::F10 => Code::F10,
+		Key::F11 => Code::F11,
+		Key::F12 => Code::F12,
+		Key::Home => Code::Home,
+		Key::LeftArrow => Code::ArrowLeft,
+		Key::PageDown => Code::PageDown,
+		Key::PageUp => Code::PageUp,
+		Key::Return => Code::Enter,
+		Key::RightArrow => Code::ArrowRight,
+		Key::Space => Code::Space,
+		Key::Tab => Code::Tab,
+		Key::UpArrow => Code::ArrowUp,
+		Key::PrintScreen => Code::PrintScreen,
+		Key::ScrollLock => Code::ScrollLock,
+		Key::Pause => Code::Pause,
+		Key::NumLock => Code::NumLock,
+		Key::BackQuote => Code::Backquote,
+		Key::Num1 => Code::Digit1,
+		Key::Num2 => Code::Digit2,
+		Key::Num3 => Code::Digit3,
+		Key::Num4 => Code::Digit4,
+		Key::Num5 => Code::Digit5,
+		Key::Num6 => Code::Digit6,
+		Key::Num7 => Code::Digit7,
+		Key::Num8 => Code::Digit8,
+		Key::Num9 => Code::Digit9,
+		Key::Num0 => Code::Digit0,
+		Key::Minus => Code::Minus,
+		Key::Equal => Code::Equal,
+		Key::KeyQ => Code::KeyQ,
+		Key::KeyW => Code::KeyW,
+		Key::KeyE => Code::KeyE,
+		Key::KeyR => Code::KeyR,
+		Key::KeyT => Code::KeyT,
+		Key::KeyY => Code::KeyY,
+		Key::KeyU => Code::KeyU,
+		Key::KeyI => Code::KeyI,
+		Key::KeyO => Code::KeyO,
+		Key::KeyP => Code::KeyP,
+		Key::LeftBracket => Code::BracketLeft,
+		Key::RightBracket => Code::BracketRight,
+		Key::KeyA => Code::KeyA,
+		Key::KeyS => Code::KeyS,
+		Key::KeyD => Code::KeyD,
+		Key::KeyF => Code::KeyF,
+		Key::KeyG => Code::KeyG,
+		Key::KeyH => Code::KeyH,
+		Key::KeyJ => Code::KeyJ,
+		Key::KeyK => Code::KeyK,
+		Key::KeyL => Code::KeyL,
+		Key::SemiColon => Code::Semicolon,
+		Key::Quote => Code::Quote,
+		Key::BackSlash => Code::Backslash,
+		Key::IntlBackslash => Code::IntlBackslash,
+		Key::KeyZ => Code::KeyZ,
+		Key::KeyX => Code::KeyX,
+		Key::KeyC => Code::KeyC,
+		Key::KeyV => Code::KeyV,
+		Key::KeyB => Code::KeyB,
+		Key::KeyN => Code::KeyN,
+		Key::KeyM => Code::KeyM,
+		Key::Comma => Code::Comma,
+		Key::Dot => Code::Period,
+		Key::Slash => Code::Slash,
+		Key::Insert => Code::Insert,
+		Key::KpReturn => Code::NumpadEnter,
+		Key::KpMinus => Code::NumpadSubtract,
+		Key::KpPlus => Code::NumpadAdd,
+		Key::KpMultiply => Code::NumpadMultiply,
+		Key::KpDivide => Code::NumpadDivide,
+		Key::Kp0 => Code::Numpad0,
+		Key::Kp1 => Code::Numpad1,
+		Key::Kp2 => Code::Numpad2,
+		Key::Kp3 => Code::Numpad3,
+		Key::Kp4 => Code::Numpad4,
+		Key::Kp5 => Code::Numpad5,
+		Key::Kp6 => Code::Numpad6,
+		Key::Kp7 => Code::Numpad7,
+		Key::Kp8 => Code::Numpad8,
+		Key::Kp9 => Code::Numpad9,
+		Key::KpDelete => Code::NumpadDecimal,
+		Key::Function => Code::Fn,
+		_ => return None,
+	})
+}
@@ -0,0 +1,17 @@
+//! The evaluation behind quicalc's modes, without the window around it, so it
+//! can also be benchmarked and fuzzed
+
+pub mod calendar;
+pub mod config;
+pub mod cron;
+pub mod finance;
+pub mod locale;
+pub mod math;
+pub mod pad;
+pub mod paths;
+pub mod percent;
+#[cfg(feature = "python")]
+pub mod python;
+pub mod sizes;
+pub mod tax;
+pub mod timers;
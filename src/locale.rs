@@ -0,0 +1,108 @@
+use std::sync::OnceLock;
+
+use fluent_bundle::{FluentArgs, FluentResource, concurrent::FluentBundle};
+use tracing::{error, info, warn};
+use unic_langid::LanguageIdentifier;
+
+const LOCALES: &[(&str, &str)] = &[
+	("en", include_str!("../locales/en.ftl")),
+	("de", include_str!("../locales/de.ftl")),
+	("es", include_str!("../locales/es.ftl")),
+	("fr", include_str!("../locales/fr.ftl")),
+	("sl", include_str!("../locales/sl.ftl")),
+];
+const FALLBACK: &str = "en";
+
+static BUNDLES: OnceLock<Vec<FluentBundle<FluentResource>>> = OnceLock::new();
+
+#[macro_export]
+macro_rules! tr {
+	($id:expr) => {
+		$crate::locale::text($id, None)
+	};
+	($id:expr, $($name:ident = $value:expr),+ $(,)?) => {{
+		let mut args = ::fluent_bundle::FluentArgs::new();
+		$(args.set(stringify!($name), $value);)+
+		$crate::locale::text($id, Some(&args))
+	}};
+}
+
+pub use tr;
+
+pub fn init(language: Option<&str>) {
+	BUNDLES.set(load(language)).ok();
+}
+
+fn negotiate(language: Option<&str>) -> &'static str {
+	let requested = language
+		.map(str::to_string)
+		.or_else(sys_locale::get_locale)
+		.and_then(|locale| {
+			locale
+				.parse::<LanguageIdentifier>()
+				.inspect_err(|err| warn!(?err, locale, "invalid language"))
+				.ok()
+		});
+
+	requested
+		.and_then(|requested| {
+			LOCALES
+				.iter()
+				.map(|&(language, _)| language)
+				.find(|&language| language == requested.language.as_str())
+		})
+		.unwrap_or(FALLBACK)
+}
+
+fn bundle(language: &str, source: &str) -> FluentBundle<FluentResource> {
+	let mut bundle = FluentBundle::new_concurrent(vec![language.parse().unwrap_or_default()]);
+	bundle.set_use_isolating(false);
+
+	let resource = FluentResource::try_new(source.to_string()).unwrap_or_else(|(resource, err)| {
+		error!(?err, language, "error parsing translations");
+		resource
+	});
+
+	bundle
+		.add_resource(resource)
+		.inspect_err(|err| error!(?err, language, "error loading translations"))
+		.ok();
+
+	bundle
+}
+
+fn load(language: Option<&str>) -> Vec<FluentBundle<FluentResource>> {
+	let language = negotiate(language);
+	info!(language, "using language");
+
+	let mut languages = vec![language];
+	if language != FALLBACK {
+		languages.push(FALLBACK);
+	}
+
+	languages
+		.into_iter()
+		.filter_map(|language| LOCALES.iter().find(|&&(locale, _)| locale == language))
+		.map(|&(locale, source)| bundle(locale, source))
+		.collect()
+}
+
+pub fn text(id: &str, args: Option<&FluentArgs>) -> String {
+	let bundles = BUNDLES.get_or_init(|| load(None));
+
+	for bundle in bundles {
+		if let Some(pattern) = bundle.get_message(id).and_then(|message| message.value()) {
+			let mut errors = Vec::new();
+			let text = bundle.format_pattern(pattern, args, &mut errors);
+
+			if !errors.is_empty() {
+				warn!(?errors, id, "error formatting text");
+			}
+
+			return text.into_owned();
+		}
+	}
+
+	error!(id, "missing text");
+	id.to_string()
+}
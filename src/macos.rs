@@ -0,0 +1,105 @@
+use iced::{
+	Task,
+	window::{
+		self, Window,
+		raw_window_handle::{HasWindowHandle, RawWindowHandle},
+	},
+};
+use objc2::{
+	ClassType, MainThreadMarker, MainThreadOnly, define_class, ffi,
+	rc::Retained,
+	runtime::{AnyObject, NSObject, NSObjectProtocol},
+};
+use objc2_app_kit::{
+	NSApplication, NSApplicationActivationPolicy, NSPanel, NSResponder, NSView, NSWindow,
+	NSWindowCollectionBehavior, NSWindowStyleMask,
+};
+use tracing::{error, trace};
+
+define_class!(
+	// SAFETY: NSPanel has no subclassing requirements, and `Panel` doesn't
+	// implement `Drop`
+	#[unsafe(super(NSPanel, NSWindow, NSResponder, NSObject))]
+	#[thread_kind = MainThreadOnly]
+	#[name = "QuicalcPanel"]
+	struct Panel;
+
+	impl Panel {
+		// borderless panels can't become the key window by default, so they
+		// couldn't be typed in
+		#[unsafe(method(canBecomeKeyWindow))]
+		fn can_become_key_window(&self) -> bool {
+			true
+		}
+	}
+);
+
+/// The AppKit window of an iced window
+fn ns_window(window: &dyn Window) -> Option<Retained<NSWindow>> {
+	let Ok(RawWindowHandle::AppKit(handle)) = window.window_handle().map(|handle| handle.as_raw())
+	else {
+		error!("not an AppKit window");
+		return None;
+	};
+
+	// SAFETY: the handle points to the window's content view, which is kept alive
+	// by winit for at least the duration of this callback
+	let view = unsafe { handle.ns_view.cast::<NSView>().as_ref() };
+
+	view.window()
+}
+
+fn configure(window: &dyn Window) {
+	let Some(mtm) = MainThreadMarker::new() else {
+		error!("not configuring panel outside of the main thread");
+		return;
+	};
+
+	let Some(ns_window) = ns_window(window) else {
+		error!("not configuring panel without a window");
+		return;
+	};
+
+	// only panels can become the key window without activating the app, so the
+	// window is turned into one
+	if !ns_window.isKindOfClass(Panel::class()) {
+		// SAFETY: winit's window class only overrides methods and has no instance
+		// variables, so its windows have the same layout as panels, which winit
+		// only uses through `NSWindow`'s methods
+		unsafe {
+			ffi::object_setClass(
+				Retained::as_ptr(&ns_window).cast_mut().cast::<AnyObject>(),
+				Panel::class(),
+			);
+		}
+	}
+
+	ns_window.setStyleMask(ns_window.styleMask() | NSWindowStyleMask::NonactivatingPanel);
+	ns_window.setCollectionBehavior(
+		NSWindowCollectionBehavior::CanJoinAllSpaces
+			| NSWindowCollectionBehavior::FullScreenAuxiliary
+			| NSWindowCollectionBehavior::Transient,
+	);
+	ns_window.setHidesOnDeactivate(false);
+
+	NSApplication::sharedApplication(mtm)
+		.setActivationPolicy(NSApplicationActivationPolicy::Accessory);
+
+	trace!("configured window as a non-activating panel");
+}
+
+pub fn configure_panel<T: Send + 'static>(id: window::Id) -> Task<T> {
+	window::run(id, configure).discard()
+}
+
+/// Make the panel the key window without activating the app (unlike
+/// `window::gain_focus`), so the previously active app keeps its focus once
+/// the panel is hidden
+pub fn focus_panel<T: Send + 'static>(id: window::Id) -> Task<T> {
+	window::run(id, |window| {
+		if let Some(ns_window) = ns_window(window) {
+			ns_window.makeKeyAndOrderFront(None);
+		}
+	})
+	.discard()
+}
@@ -1,48 +1,161 @@
 #![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
 
-#[cfg(feature = "python")]
-use std::ffi::CString;
+mod about;
+mod actions;
+mod aspect;
+mod assets;
+mod autostart;
+mod bookmarks;
+mod cache;
+#[cfg(feature = "ocr")]
+mod capture;
+mod cli;
+mod clock;
+mod editing;
+mod events;
+mod file_info;
+mod fuzzy;
+mod geo;
+mod help;
+mod hotkey;
+#[cfg(target_os = "macos")]
+mod macos;
+mod onboarding;
+mod paste;
+mod pinned;
+#[cfg(target_os = "linux")]
+mod portal;
+#[cfg(feature = "prices")]
+mod prices;
+mod processes;
+mod queries;
+mod selection;
+mod session;
+mod settings;
+mod shortcuts;
+mod startup;
+mod sun;
+mod switcher;
+mod system;
+#[cfg(windows)]
+mod taskbar;
+mod tip;
+mod tray;
+#[cfg(feature = "updater")]
+mod updater;
+mod watcher;
+#[cfg(feature = "weather")]
+mod weather;
+
+#[cfg(any(feature = "weather", feature = "prices"))]
+use std::collections::HashMap;
 use std::{
 	any,
+	collections::BTreeMap,
+	env,
 	fmt::{Debug, Formatter, Result as FmtResult},
+	mem,
 	ops::{Deref, DerefMut},
-	sync::LazyLock,
+	panic::{self, AssertUnwindSafe},
+	path::PathBuf,
+	process,
+	sync::{
+		Arc, LazyLock, Mutex, OnceLock, PoisonError,
+		atomic::{AtomicU64, Ordering},
+	},
+	time::{Duration, Instant, SystemTime},
 };
 
-use cfg_if::cfg_if;
-use global_hotkey::{
-	GlobalHotKeyEvent, GlobalHotKeyManager, HotKeyState,
-	hotkey::{Code, HotKey, Modifiers},
-};
 use iced::{
-	Alignment, Element, Event, Pixels, Settings, Size, Subscription, Task, Theme, event, exit,
-	futures::SinkExt,
-	keyboard::{Event as KeyboardEvent, Key, Modifiers as IcedModifiers, key::Named},
-	stream,
-	widget::{Id, Image, column, image::Handle, operation, row, text, text_input},
+	Alignment, Background, Border, Color, Element, Event, Font, Length, Padding, Pixels, Settings,
+	Size, Subscription, Task, Theme,
+	advanced::input_method::Event as InputMethodEvent,
+	alignment::Horizontal,
+	clipboard,
+	event::{self, Status},
+	exit,
+	keyboard::{
+		Event as KeyboardEvent, Key, Modifiers as IcedModifiers,
+		key::{Code as KeyCode, Physical},
+	},
+	mouse::Interaction,
+	task, time,
+	widget::{
+		Id, Image, TextEditor, button, column, container,
+		image::Handle,
+		mouse_area, operation, row, scrollable, text,
+		text::{LineHeight, Shaping, Wrapping},
+		text_editor::{
+			self, Action as EditorAction, Binding, Content, Motion, Status as EditorStatus,
+		},
+		text_input, tooltip,
+	},
 	window::{self, Event as WindowEvent, Level, Mode, Position, Settings as WindowSettings, icon},
 };
-use image::{DynamicImage, ImageFormat};
+use image::DynamicImage;
 use kalk::parser::{Context, eval};
 #[cfg(feature = "python")]
-use pyo3::{Python, PythonVersionInfo};
-#[cfg(feature = "python")]
-use tracing::warn;
-use tracing::{debug, error, info, trace};
+use quicalc::python;
+use quicalc::{calendar, config, cron, finance, locale, math, pad, paths, sizes, tax, timers};
+use tracing::{debug, error, info, trace, warn};
 use tracing_subscriber::{EnvFilter, fmt, prelude::*};
-use tray_icon::{
-	Icon, TrayIcon, TrayIconBuilder,
-	menu::{Menu, MenuEvent, MenuId, MenuItem},
-};
+use unicode_bidi::Direction;
 
-static KEYBIND: LazyLock<(IcedModifiers, Key)> =
-	LazyLock::new(|| (IcedModifiers::ALT, Key::Named(Named::Enter)));
-static CLOSE_KEYBIND: LazyLock<(IcedModifiers, Key)> =
-	LazyLock::new(|| (IcedModifiers::empty(), Key::Named(Named::Escape)));
-static HOTKEY: LazyLock<HotKey> = LazyLock::new(|| HotKey::new(Some(Modifiers::ALT), Code::Enter));
+#[cfg(feature = "updater")]
+use crate::updater::Release;
+#[cfg(feature = "weather")]
+use crate::weather::Weather;
+use crate::{
+	actions::Action,
+	bookmarks::Bookmark,
+	cache::Cache,
+	cli::Args,
+	clock::Zone,
+	config::{Config, EditingStyle, Renderer},
+	cron::Schedule,
+	editing::{LineEdit, Vi},
+	file_info::FileInfo,
+	fuzzy::{Search, Searchable, Selection},
+	locale::tr,
+	pinned::Pinned,
+	processes::ProcessInfo,
+	session::{Session, Snapshot},
+	switcher::WindowInfo,
+	system::SystemCommand,
+	timers::Timer,
+};
 
-static MENU_SHOW: LazyLock<MenuId> = LazyLock::new(|| MenuId::new("show"));
-static MENU_EXIT: LazyLock<MenuId> = LazyLock::new(|| MenuId::new("exit"));
+static KEYBIND: LazyLock<[(IcedModifiers, Physical); 2]> = LazyLock::new(|| {
+	[
+		(IcedModifiers::ALT, Physical::Code(KeyCode::Enter)),
+		(IcedModifiers::ALT, Physical::Code(KeyCode::NumpadEnter)),
+	]
+});
+static SUBMIT_KEYBIND: LazyLock<(IcedModifiers, Physical)> =
+	LazyLock::new(|| (IcedModifiers::empty(), Physical::Code(KeyCode::NumpadEnter)));
+static CLOSE_KEYBIND: LazyLock<(IcedModifiers, Physical)> =
+	LazyLock::new(|| (IcedModifiers::empty(), Physical::Code(KeyCode::Escape)));
+static TOGGLE_OUTPUT_KEYBIND: LazyLock<(IcedModifiers, Physical)> =
+	LazyLock::new(|| (IcedModifiers::CTRL, Physical::Code(KeyCode::KeyO)));
+static COPY_RESULT_KEYBIND: LazyLock<(IcedModifiers, Physical)> = LazyLock::new(|| {
+	(
+		IcedModifiers::CTRL | IcedModifiers::SHIFT,
+		Physical::Code(KeyCode::KeyC),
+	)
+});
+/// Undo and redo are matched by the letter the key types instead of where it
+/// is, so that they follow the keyboard layout
+static UNDO_KEYBIND: LazyLock<(IcedModifiers, char)> = LazyLock::new(|| (IcedModifiers::CTRL, 'z'));
+static REDO_KEYBIND: LazyLock<(IcedModifiers, char)> =
+	LazyLock::new(|| (IcedModifiers::CTRL | IcedModifiers::SHIFT, 'z'));
+static SHORTCUTS_KEYBIND: LazyLock<(IcedModifiers, Physical)> =
+	LazyLock::new(|| (IcedModifiers::empty(), Physical::Code(KeyCode::F1)));
+static PIN_KEYBIND: LazyLock<(IcedModifiers, Physical)> =
+	LazyLock::new(|| (IcedModifiers::CTRL, Physical::Code(KeyCode::KeyN)));
+static ON_TOP_KEYBIND: LazyLock<(IcedModifiers, Physical)> =
+	LazyLock::new(|| (IcedModifiers::CTRL, Physical::Code(KeyCode::KeyT)));
+/// The window shown with the hotkey, as opposed to pinned calculations
+static MAIN_WINDOW: OnceLock<window::Id> = OnceLock::new();
 
 #[derive(Default, Clone, Copy)]
 struct ImplDebug<T: ?Sized>(pub T);
@@ -70,347 +183,4296 @@ impl<T: ?Sized> DerefMut for ImplDebug<T> {
 #[derive(Debug, Clone)]
 enum Message {
 	InputChanged(String),
+	InputPasted(String),
 	InputSubmitted,
-	ShowWindow,
+	EvalCompleted {
+		generation: u64,
+		result: Option<String>,
+	},
+	PadEvaluated {
+		generation: u64,
+		results: Vec<Option<String>>,
+	},
+	/// A key was pressed, with the key it types last
+	KeyPressed(IcedModifiers, Physical, Key),
+	Composing(bool),
+	#[cfg(feature = "python")]
+	ModifiersChanged(IcedModifiers),
+	EditorAction(EditorAction),
+	/// Selecting or scrolling the result
+	ResultAction(EditorAction),
+	/// Show all of a result that's too long for the window
+	ExpandResult,
+	/// Copy the result's value to the clipboard
+	CopyResult,
+	/// Show or hide the list of keyboard shortcuts
+	ToggleShortcuts,
+	/// Go back to the input from before the last edit
+	Undo,
+	/// Go forward to the input from before the last undo
+	Redo,
+	/// Switch to another vi mode or wait for more keys of a command
+	Vi(Vi),
+	/// Escape was pressed, hiding the window or leaving vi's insert mode
+	Escape,
+	/// Show the calculation in a separate window
+	Pin,
+	/// Close the window of a pinned calculation
+	Unpin(window::Id),
+	/// Keep a window above other windows, or stop doing that
+	ToggleOnTop(window::Id),
+	/// The window lost focus, which hides it unless that's turned off
+	Unfocused,
+	/// Stop showing that the result was copied
+	HideCopied,
+	#[cfg(feature = "python")]
+	PythonEvaluated(u64, python::Evaluation),
+	#[cfg(feature = "python")]
+	PythonReady,
+	Evaluate,
+	Complete,
+	CompletionSelected(usize),
+	ProcessesListed {
+		generation: u64,
+		processes: Vec<ProcessInfo>,
+	},
+	WindowsListed {
+		generation: u64,
+		windows: Result<Vec<WindowInfo>, String>,
+	},
+	BookmarksListed {
+		generation: u64,
+		bookmarks: Vec<Bookmark>,
+	},
+	ZonesListed {
+		generation: u64,
+		zones: Vec<Zone>,
+		clocks: Vec<Zone>,
+	},
+	#[cfg(feature = "weather")]
+	WeatherFetched {
+		generation: u64,
+		location: String,
+		weather: Result<Weather, String>,
+	},
+	#[cfg(feature = "prices")]
+	PriceFetched {
+		generation: u64,
+		symbol: String,
+		price: Result<f64, String>,
+	},
+	/// Select one of the processes, windows, bookmarks, or time zones found in
+	/// the kill, win, go, or clock mode
+	MatchSelected(usize),
+	ProcessKilled(ProcessInfo, Result<(), String>),
+	WindowFocused(Result<(), String>),
+	ToggleOutput,
+	ShowWindow(Option<QuicalcMode>),
+	ClipboardRead(Option<String>),
+	FileDropped(PathBuf),
+	FileInspected(Result<FileInfo, String>),
+	/// Clipboard contents, if they can be evaluated in the given mode
+	ClipboardParsed(QuicalcMode, Option<String>),
+	ShowAbout,
 	HideWindow,
+	/// Recognize an expression in a region of the screen
+	#[cfg(feature = "ocr")]
+	Capture,
+	#[cfg(feature = "ocr")]
+	Captured(Result<Option<String>, String>),
+	/// Evaluate the text selected in another program
+	EvaluateSelection,
+	SelectionRead(Option<String>),
+	SystemCommandRan(Result<(), String>),
+	/// An action was started, or couldn't be
+	ActionStarted(Action, Result<(), String>),
+	TimerDone(u64),
+	/// Update the running timers and stopwatch shown in the window
+	Tick,
+	RefreshTray,
+	ToggleAutostart,
+	OpenConfig,
+	/// The config file was changed while running
+	ConfigChanged,
+	/// Set up what isn't needed to respond to the hotkey
+	FinishStartup,
+	#[cfg(feature = "updater")]
+	CheckForUpdates,
+	#[cfg(feature = "updater")]
+	UpdateChecked(Result<Option<Release>, String>),
+	#[cfg(feature = "updater")]
+	InstallUpdate,
+	#[cfg(feature = "updater")]
+	UpdateInstalled(Result<(), String>),
+	Watchdog,
+	/// The taskbar was created again, so the tray icon is gone
+	#[cfg(windows)]
+	TaskbarCreated,
+	RecreateTray,
 	Exit,
 }
 
-#[derive(Debug, Default, PartialEq, Eq)]
+#[cfg(feature = "python")]
+impl From<python::Event> for Message {
+	fn from(event: python::Event) -> Self {
+		match event {
+			python::Event::Ready => Self::PythonReady,
+			python::Event::Evaluated(id, evaluation) => Self::PythonEvaluated(id, evaluation),
+		}
+	}
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
 enum QuicalcMode {
 	#[default]
 	Kalk,
+	Pad,
 	#[cfg(feature = "python")]
 	Python,
+	#[cfg(feature = "sym")]
+	Sym,
+	Sys,
+	Kill,
+	Win,
+	Go,
+	Timer,
+	Clock,
+	Cron,
+	Geo,
+	Sun,
+	Loan,
+	#[cfg(feature = "weather")]
+	Wx,
+	#[cfg(feature = "prices")]
+	Price,
 }
 
 impl QuicalcMode {
+	const ALL: &[Self] = &[
+		Self::Kalk,
+		Self::Pad,
+		#[cfg(feature = "python")]
+		Self::Python,
+		#[cfg(feature = "sym")]
+		Self::Sym,
+		Self::Sys,
+		Self::Kill,
+		Self::Win,
+		Self::Go,
+		Self::Timer,
+		Self::Clock,
+		Self::Cron,
+		Self::Geo,
+		Self::Sun,
+		Self::Loan,
+		#[cfg(feature = "weather")]
+		Self::Wx,
+		#[cfg(feature = "prices")]
+		Self::Price,
+	];
+	const CLOCK_COMMAND: &str = "clock";
+	const CRON_COMMAND: &str = "cron";
+	const GEO_COMMAND: &str = "geo";
 	const KALK_COMMAND: &str = "kalk";
+	const KILL_COMMAND: &str = "kill";
+	const LOAN_COMMAND: &str = "loan";
+	const PAD_COMMAND: &str = "pad";
+	const PRICE_COMMAND: &str = "price";
 	const PYTHON_COMMAND: &str = "py";
+	const SUN_COMMAND: &str = "sun";
+	const SYM_COMMAND: &str = "sym";
+	const SYS_COMMAND: &str = "sys";
+	const WIN_COMMAND: &str = "win";
+	const WX_COMMAND: &str = "wx";
 
-	fn prompt(&self) -> &'static str {
-		#[cfg(feature = "python")]
-		static PY_VERSION: LazyLock<String> = LazyLock::new(|| {
-			Python::attach(|py| {
-				let PythonVersionInfo { major, minor, .. } = py.version_info();
-				format!("Python {major}.{minor}")
-			})
-		});
+	fn name(&self) -> String {
+		match self {
+			Self::Kalk => tr!("mode-calculator"),
+			Self::Pad => tr!("mode-pad"),
+			#[cfg(feature = "python")]
+			Self::Python => tr!("mode-python"),
+			#[cfg(feature = "sym")]
+			Self::Sym => tr!("mode-sym"),
+			Self::Sys => tr!("mode-sys"),
+			Self::Kill => tr!("mode-kill"),
+			Self::Win => tr!("mode-win"),
+			Self::Go => tr!("mode-go"),
+			Self::Timer => tr!("mode-timer"),
+			Self::Clock => tr!("mode-clock"),
+			Self::Cron => tr!("mode-cron"),
+			Self::Geo => tr!("mode-geo"),
+			Self::Sun => tr!("mode-sun"),
+			Self::Loan => tr!("mode-loan"),
+			#[cfg(feature = "weather")]
+			Self::Wx => tr!("mode-wx"),
+			#[cfg(feature = "prices")]
+			Self::Price => tr!("mode-price"),
+		}
+	}
 
+	fn command(&self) -> &'static str {
 		match self {
-			Self::Kalk => "Calculator",
+			Self::Kalk => Self::KALK_COMMAND,
+			Self::Pad => Self::PAD_COMMAND,
 			#[cfg(feature = "python")]
-			Self::Python => &PY_VERSION,
+			Self::Python => Self::PYTHON_COMMAND,
+			#[cfg(feature = "sym")]
+			Self::Sym => Self::SYM_COMMAND,
+			Self::Sys => Self::SYS_COMMAND,
+			Self::Kill => Self::KILL_COMMAND,
+			Self::Win => Self::WIN_COMMAND,
+			Self::Go => bookmarks::COMMAND,
+			Self::Timer => timers::COMMAND,
+			Self::Clock => Self::CLOCK_COMMAND,
+			Self::Cron => Self::CRON_COMMAND,
+			Self::Geo => Self::GEO_COMMAND,
+			Self::Sun => Self::SUN_COMMAND,
+			Self::Loan => Self::LOAN_COMMAND,
+			#[cfg(feature = "weather")]
+			Self::Wx => Self::WX_COMMAND,
+			#[cfg(feature = "prices")]
+			Self::Price => Self::PRICE_COMMAND,
 		}
 	}
 
-	fn indicator(&self) -> &'static Handle {
-		static KALK_IMAGE: LazyLock<Handle> = LazyLock::new(|| {
-			let icon = image::load_from_memory_with_format(
-				include_bytes!("../assets/indicators/kalk.png"),
-				ImageFormat::Png,
-			)
-			.inspect_err(|err| error!(?err, "error loading kalk icon"))
-			.unwrap_or_default();
+	fn prompt(&self) -> String {
+		match self {
+			Self::Kalk => tr!("mode-calculator"),
+			Self::Pad => tr!("mode-pad"),
+			#[cfg(feature = "python")]
+			Self::Python => python::version()
+				.map_or_else(|| tr!("mode-python"), |version| format!("Python {version}")),
+			#[cfg(feature = "sym")]
+			Self::Sym => tr!("mode-sym"),
+			Self::Sys => tr!("mode-sys"),
+			Self::Kill => tr!("mode-kill-prompt"),
+			Self::Win => tr!("mode-win-prompt"),
+			Self::Go => tr!("mode-go-prompt"),
+			Self::Timer => tr!("mode-timer-prompt"),
+			Self::Clock => tr!("mode-clock-prompt"),
+			Self::Cron => tr!("mode-cron-prompt"),
+			Self::Geo => tr!("mode-geo-prompt"),
+			Self::Sun => tr!("mode-sun-prompt"),
+			Self::Loan => tr!("mode-loan-prompt"),
+			#[cfg(feature = "weather")]
+			Self::Wx => tr!("mode-wx-prompt"),
+			#[cfg(feature = "prices")]
+			Self::Price => tr!("mode-price-prompt"),
+		}
+	}
+
+	/// The color of the input's border and the result, to tell modes apart
+	fn accent(&self) -> Color {
+		match self {
+			Self::Kalk => Color::from_rgb8(0x5e, 0x9c, 0xff),
+			Self::Pad => Color::from_rgb8(0xc7, 0x92, 0xea),
+			#[cfg(feature = "python")]
+			Self::Python => Color::from_rgb8(0xff, 0xd4, 0x3b),
+			#[cfg(feature = "sym")]
+			Self::Sym => Color::from_rgb8(0x4c, 0xc3, 0x8a),
+			Self::Sys => Color::from_rgb8(0xff, 0x8a, 0x5c),
+			Self::Kill => Color::from_rgb8(0xff, 0x5c, 0x6c),
+			Self::Win => Color::from_rgb8(0x4c, 0xc9, 0xd6),
+			Self::Go => Color::from_rgb8(0x9a, 0xd8, 0x4a),
+			Self::Timer => Color::from_rgb8(0xe0, 0x6c, 0x9f),
+			Self::Clock => Color::from_rgb8(0xb3, 0x9d, 0xf5),
+			Self::Cron => Color::from_rgb8(0x6f, 0xc2, 0x8b),
+			Self::Geo => Color::from_rgb8(0x3c, 0xb3, 0xa8),
+			Self::Sun => Color::from_rgb8(0xff, 0xb3, 0x47),
+			Self::Loan => Color::from_rgb8(0x8f, 0xbf, 0x5a),
+			#[cfg(feature = "weather")]
+			Self::Wx => Color::from_rgb8(0x5c, 0xb8, 0xf0),
+			#[cfg(feature = "prices")]
+			Self::Price => Color::from_rgb8(0xf2, 0xb7, 0x3c),
+		}
+	}
+
+	/// Whether results can be reused when the same input is evaluated again
+	fn is_cacheable(&self) -> bool {
+		match self {
+			Self::Kalk => true,
+			// every line of a pad is evaluated again anyway
+			Self::Pad => false,
+			// python expressions can have side effects
+			#[cfg(feature = "python")]
+			Self::Python => false,
+			#[cfg(feature = "sym")]
+			Self::Sym => true,
+			// system commands are only described, which doesn't take long
+			Self::Sys => false,
+			// processes, windows, and bookmarks come and go
+			Self::Kill | Self::Win | Self::Go => false,
+			// the running timers, the time, and the next runs change all the time
+			Self::Timer | Self::Clock | Self::Cron => false,
+			// distances, sunrises, and loans are calculated right away
+			Self::Geo | Self::Sun | Self::Loan => false,
+			// the weather has its own cache, which expires
+			#[cfg(feature = "weather")]
+			Self::Wx => false,
+			// prices have their own cache, which expires
+			#[cfg(feature = "prices")]
+			Self::Price => false,
+		}
+	}
+
+	/// Whether the text is a single input that can be evaluated in this mode
+	///
+	/// Python's syntax is only checked once the input is evaluated.
+	fn parses(&self, text: &str) -> bool {
+		if text.contains('\n') {
+			return false;
+		}
+
+		match self {
+			Self::Kalk | Self::Pad => {
+				eval(&mut Context::default(), text).is_ok_and(|res| res.is_some())
+			}
+			#[cfg(feature = "python")]
+			Self::Python => true,
+			#[cfg(feature = "sym")]
+			Self::Sym => true,
+			Self::Sys => SystemCommand::parse(text).is_some(),
+			Self::Kill | Self::Win | Self::Go | Self::Timer | Self::Clock => false,
+			Self::Cron => Schedule::parse(text).is_ok(),
+			Self::Geo => geo::eval(text).is_some_and(|res| res.is_ok()),
+			Self::Sun => sun::eval(text).is_some_and(|res| res.is_ok()),
+			Self::Loan => finance::eval(text).is_some_and(|res| res.is_ok()),
+			#[cfg(feature = "weather")]
+			Self::Wx => false,
+			#[cfg(feature = "prices")]
+			Self::Price => false,
+		}
+	}
+
+	/// Whether inputs are evaluated by the python worker
+	#[cfg(feature = "python")]
+	fn uses_python(&self) -> bool {
+		match self {
+			Self::Kalk
+			| Self::Pad
+			| Self::Sys
+			| Self::Kill
+			| Self::Win
+			| Self::Go
+			| Self::Timer
+			| Self::Clock
+			| Self::Cron
+			| Self::Geo
+			| Self::Sun
+			| Self::Loan => false,
+			#[cfg(feature = "weather")]
+			Self::Wx => false,
+			#[cfg(feature = "prices")]
+			Self::Price => false,
+			Self::Python => true,
+			#[cfg(feature = "sym")]
+			Self::Sym => true,
+		}
+	}
 
-			Handle::from_rgba(icon.width(), icon.height(), icon.into_rgba8().into_vec())
+	fn indicator_image(&self) -> &'static DynamicImage {
+		static KALK_IMAGE: LazyLock<DynamicImage> = LazyLock::new(|| {
+			assets::indicator("kalk", include_bytes!("../assets/indicators/kalk.png"))
 		});
 
 		#[cfg(feature = "python")]
-		static PYTHON_IMAGE: LazyLock<Handle> = LazyLock::new(|| {
-			let icon = image::load_from_memory_with_format(
-				include_bytes!("../assets/indicators/python.png"),
-				ImageFormat::Png,
-			)
-			.inspect_err(|err| error!(?err, "error loading python icon"))
-			.unwrap_or_default();
-
-			Handle::from_rgba(icon.width(), icon.height(), icon.into_rgba8().into_vec())
+		static PYTHON_IMAGE: LazyLock<DynamicImage> = LazyLock::new(|| {
+			assets::indicator("python", include_bytes!("../assets/indicators/python.png"))
 		});
 
 		match self {
-			Self::Kalk => &KALK_IMAGE,
+			Self::Kalk
+			| Self::Pad
+			| Self::Sys
+			| Self::Kill
+			| Self::Win
+			| Self::Go
+			| Self::Timer
+			| Self::Clock
+			| Self::Cron
+			| Self::Geo
+			| Self::Sun
+			| Self::Loan => &KALK_IMAGE,
+			#[cfg(feature = "weather")]
+			Self::Wx => &KALK_IMAGE,
+			#[cfg(feature = "prices")]
+			Self::Price => &KALK_IMAGE,
 			#[cfg(feature = "python")]
 			Self::Python => &PYTHON_IMAGE,
+			#[cfg(feature = "sym")]
+			Self::Sym => &PYTHON_IMAGE,
+		}
+	}
+
+	fn indicator(&self) -> &'static Handle {
+		fn handle(icon: &DynamicImage) -> Handle {
+			Handle::from_rgba(icon.width(), icon.height(), icon.to_rgba8().into_vec())
+		}
+
+		static KALK_HANDLE: LazyLock<Handle> =
+			LazyLock::new(|| handle(QuicalcMode::Kalk.indicator_image()));
+		#[cfg(feature = "python")]
+		static PYTHON_HANDLE: LazyLock<Handle> =
+			LazyLock::new(|| handle(QuicalcMode::Python.indicator_image()));
+
+		match self {
+			Self::Kalk
+			| Self::Pad
+			| Self::Sys
+			| Self::Kill
+			| Self::Win
+			| Self::Go
+			| Self::Timer
+			| Self::Clock
+			| Self::Cron
+			| Self::Geo
+			| Self::Sun
+			| Self::Loan => &KALK_HANDLE,
+			#[cfg(feature = "weather")]
+			Self::Wx => &KALK_HANDLE,
+			#[cfg(feature = "prices")]
+			Self::Price => &KALK_HANDLE,
+			#[cfg(feature = "python")]
+			Self::Python => &PYTHON_HANDLE,
+			#[cfg(feature = "sym")]
+			Self::Sym => &PYTHON_HANDLE,
+		}
+	}
+}
+
+/// What an evaluation depends on: the mode, the generation of the context, and
+/// the input
+type EvalKey = (QuicalcMode, u64, String);
+
+#[derive(Debug, Clone)]
+struct CachedResult {
+	result: Option<String>,
+	output: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Panel {
+	Help,
+	About,
+	Shortcuts,
+}
+
+impl Panel {
+	fn window_size(self) -> Size {
+		match self {
+			Self::Help => Size::new(640.0, 420.0),
+			Self::About => Size::new(640.0, 200.0),
+			Self::Shortcuts => Size::new(640.0, 360.0),
+		}
+	}
+
+	fn view<'a>(self, config: &Config) -> Element<'a, Message> {
+		match self {
+			Self::Help => help::view(),
+			Self::About => about::view(),
+			Self::Shortcuts => shortcuts::view(config),
+		}
+	}
+}
+
+/// What submitting the input does, besides evaluating it
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Command {
+	SetMode(QuicalcMode),
+	/// A mode that this build doesn't include, by the id of its name's
+	/// translation
+	Unsupported(&'static str),
+	ShowPanel(Panel),
+	ClearTotal,
+	/// Forget the current mode's variables and clear the result
+	Clear,
+	/// Save the session under a name
+	SaveSession(String),
+	/// Replace the session with the one saved under a name
+	LoadSession(String),
+	#[cfg(feature = "python")]
+	ResetPython,
+	#[cfg(feature = "python")]
+	RunPython,
+	/// Confirm or run the system command in the input
+	RunSystem,
+	/// Confirm terminating the selected process, or terminate it
+	KillProcess,
+	FocusWindow,
+	/// Open the bookmark best matching a search, from any mode
+	OpenBookmark(String),
+	OpenSelectedBookmark,
+	/// Open the URL of a web query command, from any mode
+	WebQuery(String),
+	/// Run an action command, from any mode
+	RunAction(Action),
+	/// Start a countdown for a duration, with a label
+	StartTimer(Duration, String),
+	ToggleStopwatch,
+	/// Fetch the weather at the location in the input
+	#[cfg(feature = "weather")]
+	LookUpWeather,
+}
+
+impl Command {
+	/// Find the command for a submitted input, which has to match the whole
+	/// input
+	fn parse(input: &str, mode: QuicalcMode, config: &Config) -> Option<Self> {
+		if let Some((bookmarks::COMMAND, search)) = input.split_once(char::is_whitespace)
+			&& !search.trim().is_empty()
+		{
+			return Some(Self::OpenBookmark(search.trim().to_string()));
+		}
+
+		if let Some((timers::COMMAND, timer)) = input.split_once(char::is_whitespace)
+			&& let Some((duration, label)) = timers::parse(timer)
+		{
+			return Some(Self::StartTimer(duration, label));
+		}
+
+		if let Some(url) = queries::url(&config.queries, input) {
+			return Some(Self::WebQuery(url));
+		}
+
+		if let Some(action) = Action::parse(&config.actions, input) {
+			return Some(Self::RunAction(action));
+		}
+
+		let mut words = input.split_whitespace();
+
+		if words.next() == Some(session::COMMAND)
+			&& let (Some(action), Some(name), None) = (words.next(), words.next(), words.next())
+			&& session::is_valid_name(name)
+		{
+			match action {
+				"save" => return Some(Self::SaveSession(name.to_string())),
+				"load" => return Some(Self::LoadSession(name.to_string())),
+				_ => (),
+			}
+		}
+
+		match input {
+			#[cfg(feature = "python")]
+			QuicalcMode::PYTHON_COMMAND => Some(Self::SetMode(QuicalcMode::Python)),
+			#[cfg(not(feature = "python"))]
+			QuicalcMode::PYTHON_COMMAND => Some(Self::Unsupported("mode-python")),
+			#[cfg(feature = "sym")]
+			QuicalcMode::SYM_COMMAND => Some(Self::SetMode(QuicalcMode::Sym)),
+			#[cfg(not(feature = "sym"))]
+			QuicalcMode::SYM_COMMAND => Some(Self::Unsupported("mode-sym")),
+			QuicalcMode::PAD_COMMAND => Some(Self::SetMode(QuicalcMode::Pad)),
+			QuicalcMode::SYS_COMMAND => Some(Self::SetMode(QuicalcMode::Sys)),
+			QuicalcMode::KILL_COMMAND => Some(Self::SetMode(QuicalcMode::Kill)),
+			QuicalcMode::WIN_COMMAND => Some(Self::SetMode(QuicalcMode::Win)),
+			bookmarks::COMMAND => Some(Self::SetMode(QuicalcMode::Go)),
+			timers::COMMAND => Some(Self::SetMode(QuicalcMode::Timer)),
+			QuicalcMode::CLOCK_COMMAND => Some(Self::SetMode(QuicalcMode::Clock)),
+			QuicalcMode::CRON_COMMAND => Some(Self::SetMode(QuicalcMode::Cron)),
+			QuicalcMode::GEO_COMMAND => Some(Self::SetMode(QuicalcMode::Geo)),
+			QuicalcMode::SUN_COMMAND => Some(Self::SetMode(QuicalcMode::Sun)),
+			QuicalcMode::LOAN_COMMAND => Some(Self::SetMode(QuicalcMode::Loan)),
+			#[cfg(feature = "weather")]
+			QuicalcMode::WX_COMMAND => Some(Self::SetMode(QuicalcMode::Wx)),
+			#[cfg(not(feature = "weather"))]
+			QuicalcMode::WX_COMMAND => Some(Self::Unsupported("mode-wx")),
+			#[cfg(feature = "prices")]
+			QuicalcMode::PRICE_COMMAND => Some(Self::SetMode(QuicalcMode::Price)),
+			#[cfg(not(feature = "prices"))]
+			QuicalcMode::PRICE_COMMAND => Some(Self::Unsupported("mode-price")),
+			timers::STOPWATCH_COMMAND => Some(Self::ToggleStopwatch),
+			"" | "q" | "exit" | "quit" | "calc" | QuicalcMode::KALK_COMMAND => {
+				Some(Self::SetMode(QuicalcMode::default()))
+			}
+			command if about::COMMANDS.contains(&command) => Some(Self::ShowPanel(Panel::About)),
+			help::COMMAND => Some(Self::ShowPanel(Panel::Help)),
+			Quicalc::CLEAR_TOTAL_COMMAND => Some(Self::ClearTotal),
+			Quicalc::CLEAR_COMMAND => Some(Self::Clear),
+			#[cfg(feature = "python")]
+			python::RESET_COMMAND if mode == QuicalcMode::Python => Some(Self::ResetPython),
+			#[cfg(feature = "python")]
+			_ if mode == QuicalcMode::Python => Some(Self::RunPython),
+			_ if mode == QuicalcMode::Sys => Some(Self::RunSystem),
+			_ if mode == QuicalcMode::Kill => Some(Self::KillProcess),
+			_ if mode == QuicalcMode::Win => Some(Self::FocusWindow),
+			_ if mode == QuicalcMode::Go => Some(Self::OpenSelectedBookmark),
+			_ if mode == QuicalcMode::Timer => {
+				timers::parse(input).map(|(duration, label)| Self::StartTimer(duration, label))
+			}
+			#[cfg(feature = "weather")]
+			_ if mode == QuicalcMode::Wx => Some(Self::LookUpWeather),
+			_ => None,
 		}
 	}
 }
 
 #[derive(Debug, Default)]
 struct Quicalc {
+	config: Config,
 	mode: QuicalcMode,
-	ctx: ImplDebug<Context>,
+	ctx: ImplDebug<Arc<Mutex<Context>>>,
+	/// Incremented for every evaluation, so that outdated results are ignored
+	generation: u64,
+	/// The evaluation currently running in the background
+	running_eval: Option<task::Handle>,
+	/// The generation of the latest kalk evaluation, so that outdated ones
+	/// still waiting for the context can be skipped
+	latest_math: Arc<AtomicU64>,
+	/// Incremented whenever kalk's variables and functions may have changed,
+	/// also by evaluations running in the background
+	context: Arc<AtomicU64>,
+	/// The code defining the variables and functions in the current context
+	session: Session,
+	/// What the evaluation that's currently running depends on
+	eval_key: Option<EvalKey>,
+	cache: Cache<EvalKey, CachedResult>,
+	/// Whether the window is hidden, waiting to be opened with the hotkey
+	hidden: bool,
 	input: String,
 	result: Option<String>,
+	output: Option<String>,
+	/// The full traceback of an error, shown along with the output once
+	/// expanded
+	traceback: Option<String>,
+	output_expanded: bool,
+	#[cfg(feature = "python")]
+	figure: Option<python::Figure>,
+	#[cfg(feature = "updater")]
+	update: Option<Release>,
+	onboarding: bool,
+	panel: Option<Panel>,
+	last_watchdog: Option<SystemTime>,
+	composing: bool,
+	/// Whether the input was just pasted, and hasn't been evaluated yet
+	pasted: bool,
+	/// A file dropped onto the window, shown instead of the input's result
+	file: Option<FileInfo>,
+	/// Reading a dropped file, which is cancelled when the input changes
+	reading_file: Option<task::Handle>,
+	#[cfg(feature = "python")]
+	modifiers: IcedModifiers,
+	editor: Option<ImplDebug<Content>>,
+	/// The result of each line in pad mode
+	pad_results: Vec<Option<String>>,
+	/// A copy of the result that can be selected, with the result it was made
+	/// from
+	result_selection: Option<(String, ImplDebug<Content>)>,
+	/// Whether a result too long for the window is shown in full
+	result_expanded: bool,
+	/// Whether the result is an error message
+	failed: bool,
+	/// Earlier inputs, most recent last
+	undo: Vec<String>,
+	/// Inputs that were undone, most recent last
+	redo: Vec<String>,
+	/// The input's mode in the vi editing style
+	vi: Vi,
+	/// Calculations shown in their own windows, by window
+	pinned: BTreeMap<window::Id, Pinned>,
+	/// Whether the main window can go behind other windows, instead of
+	/// staying on top of them
+	behind: bool,
+	/// Shows that the result was copied until it's dropped
+	copied: Option<task::Handle>,
+	/// When the evaluation that's still running started
+	eval_started: Option<Instant>,
+	/// How long the last evaluation took
+	eval_duration: Option<Duration>,
+	/// The sum of the results submitted in the calculator
+	total: Option<f64>,
+	/// Whether the result of the evaluation that's still running should be
+	/// added to the total
+	add_to_total: bool,
+	/// The evaluation whose result is copied once it's done, for a selection
+	/// evaluated with its hotkey
+	copy_result: Option<u64>,
+	/// The system command that runs if it's submitted again
+	confirming: Option<SystemCommand>,
+	/// The running processes searched in the kill mode
+	processes: Search<ProcessInfo>,
+	/// The process that's terminated if it's submitted again
+	killing: Option<u32>,
+	/// The open windows searched in the win mode
+	windows: Search<WindowInfo>,
+	/// The bookmarks searched in the go mode
+	bookmarks: Search<Bookmark>,
+	/// The countdowns that aren't done yet, ending soonest first
+	timers: Vec<Timer>,
+	next_timer_id: u64,
+	/// When the stopwatch was started, if it's running
+	stopwatch: Option<Instant>,
+	/// The time zones searched in the clock mode
+	zones: Search<Zone>,
+	/// The time zones shown in the clock mode before searching
+	clocks: Vec<Zone>,
+	/// The weather fetched in the wx mode, with when it was fetched, by
+	/// lowercase location (empty for the configured one)
+	#[cfg(feature = "weather")]
+	weather: HashMap<String, (Instant, Weather)>,
+	/// The prices fetched for kalk and the price mode, with when they were
+	/// fetched, by uppercase symbol
+	#[cfg(feature = "prices")]
+	prices: HashMap<String, (Instant, f64)>,
+	/// A debounced evaluation that's waiting for typing to pause
+	pending_eval: Option<task::Handle>,
+	size: Size,
+	#[cfg(feature = "python")]
+	python: python::Worker,
+	/// The Python job whose result is shown once it's done
+	#[cfg(feature = "python")]
+	python_job: Option<u64>,
+	/// The Python job running submitted code, with that code, which is added
+	/// to the session if it runs without errors
+	#[cfg(feature = "python")]
+	running_code: Option<(u64, String)>,
+	completions: Vec<String>,
+	/// The completion currently inserted into the input
+	completion: Option<usize>,
+	/// Where the completed word starts in the input
+	completion_start: usize,
+	#[cfg(feature = "python")]
+	completion_job: Option<u64>,
 }
 
 impl Quicalc {
+	/// The approximate width of a character, relative to the text size
+	const CHAR_WIDTH: f32 = 0.6;
+	const CLEAR_COMMAND: &'static str = "clear";
+	const CLEAR_TOTAL_COMMAND: &'static str = "total clear";
+	const COPIED_DURATION: Duration = Duration::from_millis(1500);
+	const EDITOR_LINE_HEIGHT: f32 = 42.0;
+	const MAX_COMPLETIONS: usize = 8;
+	const MAX_EDITOR_LINES: usize = 10;
+	const MAX_FIGURE_HEIGHT: f32 = 360.0;
+	const MAX_OUTPUT_LINES: usize = 15;
+	const MAX_RESULT_LINES: usize = 8;
+	const MAX_UNDO_STEPS: usize = 100;
+	const MIN_RESULT_TEXT_SIZE: f32 = 16.0;
+	const OUTPUT_LINE_HEIGHT: f32 = 19.0;
+	const OUTPUT_PREVIEW_LINES: usize = 3;
+	const OUTPUT_TEXT_SIZE: f32 = 14.0;
+	const RESULT_TEXT_SIZE: f32 = 32.0;
+	/// How long to wait for the taskbar to be ready after it's been created
+	#[cfg(windows)]
+	const TASKBAR_DELAY: Duration = Duration::from_secs(2);
 	const TEXT_INPUT_ID: &'static str = "quicalc-input";
+	const WATCHDOG_INTERVAL: Duration = Duration::from_secs(15);
+	const WINDOW_SIZE: Size = Size::new(640.0, 100.0);
+
+	#[cfg_attr(not(feature = "ocr"), expect(unused_variables))]
+	fn new(config: Config, capture: bool) -> (Self, Task<Message>) {
+		let onboarding = onboarding::is_first_run();
+
+		startup::phase("event loop started");
 
-	fn new() -> (Self, Task<Message>) {
-		(Self::default(), Task::none())
+		let (window, open) = window::open(Self::window_settings());
+		MAIN_WINDOW.set(window).ok();
+
+		// everything else needs the main window to exist
+		let task = open.discard().chain(Task::batch([
+			Task::done(Message::FinishStartup),
+			#[cfg(feature = "ocr")]
+			if capture {
+				Task::done(Message::Capture)
+			} else {
+				Task::none()
+			},
+			#[cfg(feature = "updater")]
+			if config.updates.check {
+				Task::done(Message::CheckForUpdates)
+			} else {
+				Task::none()
+			},
+			if onboarding {
+				Task::done(Message::ShowWindow(None))
+			} else {
+				Task::none()
+			},
+		]));
+
+		let session = if config.session.persist {
+			Session::load()
+		} else {
+			Session::default()
+		};
+
+		let mut ctx = Context::default();
+		session.restore_kalk(&mut ctx);
+
+		#[cfg(feature = "python")]
+		let mut python = python::Worker::new(config.python.clone());
+		#[cfg(feature = "python")]
+		python.restore(session.python.clone());
+
+		(
+			Self {
+				#[cfg(feature = "python")]
+				python,
+				ctx: ImplDebug(Arc::new(Mutex::new(ctx))),
+				session,
+				config,
+				onboarding,
+				hidden: true,
+				size: Self::WINDOW_SIZE,
+				..Self::default()
+			},
+			task,
+		)
+	}
+
+	fn window_settings() -> WindowSettings {
+		WindowSettings {
+			decorations: false,
+			size: Self::WINDOW_SIZE,
+			position: Position::Centered,
+			visible: false,
+			resizable: false,
+			transparent: true,
+			level: Level::AlwaysOnTop,
+			exit_on_close_request: false,
+			..Default::default()
+		}
 	}
 
-	fn title(&self) -> String {
-		"Quicalc".to_string()
+	fn title(&self, window: window::Id) -> String {
+		if let Some(pinned) = self.pinned.get(&window) {
+			return pinned.title();
+		}
+
+		match paths::profile() {
+			Some(profile) => format!("Quicalc ({profile})"),
+			None => "Quicalc".to_string(),
+		}
 	}
 
-	fn theme(&self) -> Theme {
-		Theme::Dark
+	fn theme(&self, _window: window::Id) -> Theme {
+		match self.config.theme {
+			config::Theme::Dark => Theme::Dark,
+			config::Theme::Light => Theme::Light,
+		}
 	}
 
 	fn subscription(&self) -> Subscription<Message> {
 		trace!("subscription");
 
 		Subscription::batch([
-			Subscription::run(|| {
-				stream::channel(0, async move |mut sender| {
-					loop {
-						let message = crossbeam_channel::select! {
-							recv(GlobalHotKeyEvent::receiver()) -> msg => {
-								if let Ok(event) = msg {
-									debug!(?event, "new hotkey event");
-
-									if event.state() == HotKeyState::Pressed && event.id() == HOTKEY.id() {
-										Some(Message::ShowWindow)
-									} else {
-										None
-									}
-								} else {
-									error!("error receiving global hotkey event: {msg:?}");
-									None
-								}
-							},
-							recv(MenuEvent::receiver()) -> msg => {
-								if let Ok(event) = msg {
-									debug!(?event, "new tray icon menu event");
-
-									if event.id() == &*MENU_SHOW {
-										Some(Message::ShowWindow)
-									} else if event.id() == &*MENU_EXIT {
-										Some(Message::Exit)
-									} else {
-										error!("unknown menu item event id: {:?}", event.id());
-										None
-									}
-								} else {
-									error!("error receiving global hotkey event: {msg:?}");
-									None
-								}
-							},
-						};
+			Subscription::run(events::stream),
+			Subscription::run(watcher::stream),
+			// pinned calculations stay open while the main window is hidden, and can only be
+			// closed or moved behind other windows
+			if self.pinned.is_empty() {
+				Subscription::none()
+			} else {
+				event::listen_with(|event, _, window| match event {
+					_ if MAIN_WINDOW.get() == Some(&window) => None,
+					Event::Keyboard(KeyboardEvent::KeyPressed {
+						physical_key,
+						modifiers,
+						..
+					}) => {
+						let keypress = (modifiers, physical_key);
 
-						if let Some(message) = message
-							&& let Err(err) = sender.send(message).await
-						{
-							error!("error processing event: {err:?}")
+						if keypress == *CLOSE_KEYBIND {
+							Some(Message::Unpin(window))
+						} else if keypress == *ON_TOP_KEYBIND {
+							Some(Message::ToggleOnTop(window))
+						} else {
+							None
 						}
 					}
+					Event::Window(WindowEvent::CloseRequested) => Some(Message::Unpin(window)),
+					_ => None,
 				})
-			}),
-			event::listen_with(|event, _, _| match event {
-				Event::Keyboard(KeyboardEvent::KeyPressed { key, modifiers, .. }) => {
-					let keypress = (modifiers, key);
-
-					if keypress == *KEYBIND {
-						Some(Message::ShowWindow)
-					} else if keypress == *CLOSE_KEYBIND {
-						Some(Message::HideWindow)
-					} else {
-						None
+			},
+			// while hidden, only the hotkey and tray listeners are needed to show the window again
+			if self.hidden {
+				Subscription::none()
+			} else {
+				event::listen_with(|event, status, window| match event {
+					_ if MAIN_WINDOW.get() != Some(&window) => None,
+					Event::Keyboard(KeyboardEvent::KeyPressed {
+						key,
+						physical_key,
+						modifiers,
+						..
+					}) => {
+						let keypress = (modifiers, physical_key);
+						let letter = key.to_latin(physical_key).map(|letter| (modifiers, letter));
+
+						if KEYBIND.contains(&keypress) {
+							Some(Message::ShowWindow(None))
+						} else if keypress == *CLOSE_KEYBIND {
+							Some(Message::Escape)
+						} else if keypress == *TOGGLE_OUTPUT_KEYBIND {
+							Some(Message::ToggleOutput)
+						} else if keypress == *COPY_RESULT_KEYBIND {
+							Some(Message::CopyResult)
+						} else if keypress == *PIN_KEYBIND {
+							Some(Message::Pin)
+						} else if keypress == *ON_TOP_KEYBIND {
+							Some(Message::ToggleOnTop(window))
+						} else if keypress == *SHORTCUTS_KEYBIND {
+							Some(Message::ToggleShortcuts)
+						} else if letter == Some(*UNDO_KEYBIND) {
+							Some(Message::Undo)
+						} else if letter == Some(*REDO_KEYBIND) {
+							Some(Message::Redo)
+						} else if LineEdit::from_key(modifiers, &key, physical_key).is_some() {
+							// the input handles some of these itself, but they're replaced in the
+							// emacs editing style
+							Some(Message::KeyPressed(modifiers, physical_key, key))
+						} else if status == Status::Captured {
+							None
+						} else if keypress == *SUBMIT_KEYBIND {
+							Some(Message::InputSubmitted)
+						} else {
+							Some(Message::KeyPressed(modifiers, physical_key, key))
+						}
 					}
-				}
-				Event::Window(event) => match event {
-					WindowEvent::CloseRequested => Some(Message::HideWindow),
-					WindowEvent::Unfocused => Some(Message::HideWindow),
+					#[cfg(feature = "python")]
+					Event::Keyboard(KeyboardEvent::ModifiersChanged(modifiers)) => {
+						Some(Message::ModifiersChanged(modifiers))
+					}
+					// a composition ends when it's committed, or when its preedit is cleared
+					// without committing it
+					Event::InputMethod(event) => match event {
+						InputMethodEvent::Preedit(content, _) => {
+							Some(Message::Composing(!content.is_empty()))
+						}
+						InputMethodEvent::Commit(_) | InputMethodEvent::Closed => {
+							Some(Message::Composing(false))
+						}
+						InputMethodEvent::Opened => None,
+					},
+					Event::Window(event) => match event {
+						WindowEvent::CloseRequested => Some(Message::HideWindow),
+						WindowEvent::Unfocused => Some(Message::Unfocused),
+						WindowEvent::FileDropped(path) => Some(Message::FileDropped(path)),
+						_ => None,
+					},
 					_ => None,
-				},
-				_ => None,
-			}),
+				})
+			},
+			#[cfg(target_os = "linux")]
+			if portal::is_wayland() {
+				Subscription::run(portal::shortcuts)
+			} else {
+				Subscription::none()
+			},
+			#[cfg(feature = "updater")]
+			if self.config.updates.check {
+				time::every(Duration::from_secs(
+					self.config.updates.interval_hours.max(1) * 60 * 60,
+				))
+				.map(|_| Message::CheckForUpdates)
+			} else {
+				Subscription::none()
+			},
+			time::every(Self::WATCHDOG_INTERVAL).map(|_| Message::Watchdog),
+			#[cfg(windows)]
+			Subscription::run(taskbar::stream),
+			// running timers, the stopwatch, and clocks count the seconds while they're shown
+			if !self.hidden
+				&& (!self.timers.is_empty()
+					|| self.stopwatch.is_some()
+					|| self.mode == QuicalcMode::Clock)
+			{
+				time::every(Duration::from_secs(1)).map(|_| Message::Tick)
+			} else {
+				Subscription::none()
+			},
+			// nothing needs to wait for python until it's been started
+			#[cfg(feature = "python")]
+			if self.python.is_started() {
+				Subscription::run(python::evaluations).map(Message::from)
+			} else {
+				Subscription::none()
+			},
 		])
 	}
 
 	fn update(&mut self, msg: Message) -> Task<Message> {
+		let task = self.handle(msg);
+		self.sync_result_selection();
+		task
+	}
+
+	fn handle(&mut self, msg: Message) -> Task<Message> {
 		debug!(?msg, "update");
 
 		match msg {
-			Message::ShowWindow => Task::batch(vec![
-				window::oldest().and_then(|id| window::set_mode(id, Mode::Windowed)),
-				window::oldest().and_then(window::gain_focus),
-				operation::focus(Id::new(Self::TEXT_INPUT_ID)),
-				operation::select_all(Id::new(Self::TEXT_INPUT_ID)),
-			]),
-			Message::HideWindow => {
-				if self.input.is_empty() {
-					self.mode = QuicalcMode::default();
+			Message::ShowWindow(mode) => {
+				if let Some(mode) = mode {
+					self.set_mode(mode);
 				}
 
-				self.ctx.0 = Context::new();
-				self.eval();
+				self.hidden = false;
 
-				window::oldest().and_then(|id| window::set_mode(id, Mode::Hidden))
+				// on macOS, focusing the panel mustn't activate the app
+				#[cfg(not(target_os = "macos"))]
+				let focus = window::oldest().and_then(window::gain_focus);
+				#[cfg(target_os = "macos")]
+				let focus = window::oldest().and_then(macos::focus_panel::<Message>);
+
+				#[cfg_attr(not(target_os = "macos"), expect(unused_mut))]
+				let mut tasks = vec![
+					window::oldest().and_then(|id| window::set_mode(id, Mode::Windowed)),
+					focus,
+					operation::focus(Id::new(Self::TEXT_INPUT_ID)),
+					operation::select_all(Id::new(Self::TEXT_INPUT_ID)),
+				];
+
+				#[cfg(target_os = "macos")]
+				tasks.insert(
+					0,
+					window::oldest().and_then(macos::configure_panel::<Message>),
+				);
+
+				if self.config.eval.clipboard && self.editor.is_none() {
+					tasks.push(clipboard::read().map(Message::ClipboardRead));
+				}
+
+				Task::batch(tasks)
 			}
-			Message::InputChanged(input) => {
-				self.input = input;
-				self.eval();
-				Task::none()
+			Message::ClipboardRead(contents) => {
+				let Some(contents) = contents
+					.map(|contents| contents.trim().to_string())
+					.filter(|contents| !contents.is_empty() && *contents != self.input)
+				else {
+					return Task::none();
+				};
+
+				let mode = self.mode;
+
+				Task::perform(
+					tokio::task::spawn_blocking(move || mode.parses(&contents).then_some(contents)),
+					move |contents| {
+						Message::ClipboardParsed(
+							mode,
+							contents
+								.inspect_err(|err| {
+									error!(?err, "error checking clipboard contents")
+								})
+								.ok()
+								.flatten(),
+						)
+					},
+				)
 			}
-			Message::InputSubmitted => {
-				match self.input.as_str() {
-					QuicalcMode::PYTHON_COMMAND => {
-						cfg_if! {
-							if #[cfg(feature = "python")] {
-								self.mode = QuicalcMode::Python;
-								self.input.clear();
-								self.result = None;
-							} else {
-								self.input.clear();
-								self.result = Some("Python mode is not supported.".to_string());
-							}
-						};
-					}
-					"" | "q" | "exit" | "quit" | "calc" | QuicalcMode::KALK_COMMAND => {
-						self.mode = QuicalcMode::default();
-						self.input.clear();
-						self.result = None;
-					}
-					_ => (),
+			Message::ClipboardParsed(mode, contents) => {
+				let Some(contents) = contents else {
+					return Task::none();
 				};
 
+				// the window may have been hidden or changed since showing it
+				if self.hidden || mode != self.mode || self.editor.is_some() {
+					return Task::none();
+				}
+
+				self.input = contents;
+
+				self.clear_completions();
+
 				Task::batch(vec![
+					self.eval(),
 					operation::focus(Id::new(Self::TEXT_INPUT_ID)),
 					operation::select_all(Id::new(Self::TEXT_INPUT_ID)),
 				])
 			}
-			Message::Exit => exit(),
-		}
-	}
-
-	fn view(&self) -> Element<'_, Message, Theme> {
-		trace!("view");
-
-		column![
-			text_input(self.mode.prompt(), &self.input)
-				.on_input(Message::InputChanged)
-				.on_submit(Message::InputSubmitted)
-				.id(Id::new(Self::TEXT_INPUT_ID)),
-			row![
-				Image::new(self.mode.indicator()),
-				text(self.result.as_deref().unwrap_or_default())
-			],
-		]
-		.padding(0)
-		.align_x(Alignment::Start)
-		.into()
-	}
+			Message::FileDropped(path) => {
+				debug!(?path, "file dropped");
 
-	fn eval(&mut self) {
-		trace!("eval");
+				self.result = Some(tr!("file-reading"));
+				self.output = None;
+				self.traceback = None;
+				self.cancel_eval();
 
-		match self.mode {
-			QuicalcMode::Kalk => {
-				self.result = eval(&mut self.ctx, &self.input)
-					.inspect_err(|err| debug!(?err, "error evaluating math"))
-					.ok()
-					.flatten()
-					.map(|res| format!("≈ {res}"));
+				let (task, handle) =
+					Task::perform(file_info::inspect(path), Message::FileInspected).abortable();
+				self.reading_file = Some(handle.abort_on_drop());
+				task
 			}
-			#[cfg(feature = "python")]
-			QuicalcMode::Python => {
-				self.result = Python::attach(|py| {
-					py.eval(
-						CString::new(self.input.clone())
-							.inspect_err(|err| warn!(?err, "invalid python expression entered"))
-							.unwrap_or_default()
-							.as_c_str(),
-						None,
-						None,
-					)
-					.inspect_err(|err| debug!(?err, "error evaluating python expression"))
-					.ok()
-					.map(|res| format!("→ {res}"))
-				})
+			Message::FileInspected(Ok(file)) => {
+				self.reading_file = None;
+				self.result = Some(format!(
+					"{}: {}",
+					file.name,
+					file_info::format_size(file.size)
+				));
+				self.output = Some(match file.lines {
+					Some(lines) => format!(
+						"SHA-256: {}\n{}",
+						file.sha256,
+						tr!("file-lines", lines = lines)
+					),
+					None => format!("SHA-256: {}", file.sha256),
+				});
+				self.file = Some(file);
+				self.resize()
 			}
-		}
-	}
-}
-
-fn set_up_hotkey() -> Result<GlobalHotKeyManager, String> {
-	let hotkeys = GlobalHotKeyManager::new().map_err(|e| e.to_string())?;
-	hotkeys.register(*HOTKEY).map_err(|e| e.to_string())?;
+			Message::FileInspected(Err(err)) => {
+				error!(?err, "error reading dropped file");
+				self.reading_file = None;
+				self.result = Some(tr!("file-error"));
+				self.file = None;
+				self.resize()
+			}
+			#[cfg(feature = "ocr")]
+			Message::Capture => {
+				let capture =
+					Task::perform(capture::capture(self.config.ocr.clone()), Message::Captured);
 
-	Ok(hotkeys)
-}
+				// the window shouldn't cover what's being captured
+				if self.hidden {
+					capture
+				} else {
+					Task::done(Message::HideWindow).chain(capture)
+				}
+			}
+			#[cfg(feature = "ocr")]
+			Message::Captured(Ok(Some(text))) if text.is_empty() => {
+				tray::notify(&tr!("capture-failed"), &tr!("capture-empty"));
+				Task::none()
+			}
+			#[cfg(feature = "ocr")]
+			Message::Captured(Ok(Some(text))) => {
+				self.input = text;
+				self.editor = None;
+				self.vi = Vi::INSERT;
+				self.file = None;
 
-fn set_up_tray_icon(icon: &DynamicImage) -> Result<TrayIcon, String> {
-	let tray_menu = Menu::with_items(&[
-		&MenuItem::with_id(&*MENU_SHOW.0, "Show", true, None),
-		&MenuItem::with_id(&*MENU_EXIT.0, "Exit", true, None),
-	])
-	.map_err(|e| e.to_string())?;
+				Task::batch(vec![
+					self.set_panel(None),
+					self.eval(),
+					Task::done(Message::ShowWindow(None)),
+				])
+			}
+			#[cfg(feature = "ocr")]
+			Message::Captured(Ok(None)) => Task::none(),
+			#[cfg(feature = "ocr")]
+			Message::Captured(Err(err)) => {
+				error!(?err, "error capturing expression");
+				tray::notify(&tr!("capture-failed"), &err);
+				Task::none()
+			}
+			Message::ProcessesListed {
+				generation,
+				processes,
+			} => {
+				if generation != self.generation || self.mode != QuicalcMode::Kill {
+					return Task::none();
+				}
 
-	let (width, height, pixels) = (icon.width(), icon.height(), icon.to_rgba8().into_vec());
+				self.running_eval = None;
+				self.processes.set_items(processes);
+				self.show_matches()
+			}
+			Message::WindowsListed {
+				generation,
+				windows,
+			} => {
+				if generation != self.generation || self.mode != QuicalcMode::Win {
+					return Task::none();
+				}
 
-	let tray_icon = TrayIconBuilder::new()
-		.with_tooltip("Quicalc")
-		.with_icon(Icon::from_rgba(pixels, width, height).map_err(|e| e.to_string())?)
-		.with_menu(Box::new(tray_menu))
-		.build()
-		.map_err(|e| e.to_string())?;
+				self.running_eval = None;
 
-	Ok(tray_icon)
-}
+				match windows {
+					Ok(windows) => {
+						self.windows.set_items(windows);
+						self.show_matches()
+					}
+					Err(err) => {
+						error!(?err, "error listing windows");
+						self.result = Some(tr!("win-list-failed"));
+						self.failed = true;
+						self.resize()
+					}
+				}
+			}
+			Message::BookmarksListed {
+				generation,
+				bookmarks,
+			} => {
+				if generation != self.generation || self.mode != QuicalcMode::Go {
+					return Task::none();
+				}
 
-fn main() {
-	tracing_subscriber::registry()
-		.with(fmt::layer())
-		.with(EnvFilter::from_env("QUICALC_LOG"))
-		.init();
+				self.running_eval = None;
+				self.bookmarks.set_items(bookmarks);
+				self.show_matches()
+			}
+			Message::ZonesListed {
+				generation,
+				zones,
+				clocks,
+			} => {
+				if generation != self.generation || self.mode != QuicalcMode::Clock {
+					return Task::none();
+				}
 
-	let _hotkeys = set_up_hotkey()
-		.inspect(|_| info!("set up global hotkey"))
-		.inspect_err(|err| error!(?err, "error setting up global hotkey"))
-		.ok();
+				self.running_eval = None;
+				self.zones.set_items(zones);
+				self.clocks = clocks;
+				self.show_matches()
+			}
+			#[cfg(feature = "weather")]
+			Message::WeatherFetched {
+				generation,
+				location,
+				weather,
+			} => {
+				if generation != self.generation || self.mode != QuicalcMode::Wx {
+					return Task::none();
+				}
 
-	let icon =
-		image::load_from_memory_with_format(include_bytes!("../assets/icon.png"), ImageFormat::Png)
-			.inspect_err(|err| error!(?err, "error loading program icon"))
-			.unwrap_or_default();
+				self.running_eval = None;
+				self.eval_duration = self.eval_started.take().map(|started| started.elapsed());
 
-	info!("loaded icon");
+				match weather {
+					Ok(weather) => {
+						self.result = Some(weather.summary());
+						self.failed = false;
+						self.weather
+							.insert(location.to_lowercase(), (Instant::now(), weather));
+					}
+					Err(err) => {
+						error!(?err, %location, "error fetching weather");
+						self.result = Some(tr!("wx-failed", error = err));
+						self.failed = true;
+					}
+				}
 
-	let _tray_icon = set_up_tray_icon(&icon)
-		.inspect(|_| info!("set up tray icon"))
-		.inspect_err(|err| error!(?err, "error setting up tray icon"))
-		.ok();
+				self.resize()
+			}
+			#[cfg(feature = "prices")]
+			Message::PriceFetched {
+				generation,
+				symbol,
+				price,
+			} => {
+				if generation != self.generation || !self.evaluates_math() {
+					return Task::none();
+				}
 
-	let (width, height, pixels) = (icon.width(), icon.height(), icon.into_rgba8().into_vec());
-	let window_icon = icon::from_rgba(pixels, width, height)
-		.inspect_err(|err| error!(?err, "error setting window icon"))
-		.ok();
+				self.running_eval = None;
 
-	iced::application(Quicalc::new, Quicalc::update, Quicalc::view)
-		.subscription(Quicalc::subscription)
-		.theme(Quicalc::theme)
-		.title(Quicalc::title)
-		.settings(Settings {
-			antialiasing: true,
-			default_text_size: Pixels(32.0),
-			..Default::default()
-		})
-		.window(WindowSettings {
-			decorations: false,
-			size: Size::new(640.0, 100.0),
-			position: Position::Centered,
-			visible: false,
-			resizable: false,
-			transparent: true,
-			level: Level::AlwaysOnTop,
-			icon: window_icon,
-			exit_on_close_request: false,
+				match price {
+					Ok(price) => {
+						self.prices.insert(symbol, (Instant::now(), price));
+						// the math can be evaluated with the price now
+						self.eval()
+					}
+					Err(err) => {
+						error!(?err, %symbol, "error fetching price");
+						self.eval_started = None;
+						self.result = Some(err);
+						self.failed = true;
+						self.resize()
+					}
+				}
+			}
+			Message::MatchSelected(index) => {
+				self.select_match(|search| search.select(index));
+				operation::focus(Id::new(Self::TEXT_INPUT_ID))
+			}
+			Message::ProcessKilled(process, Ok(())) => {
+				self.processes.remove(&process.pid);
+				self.result = Some(tr!("kill-done", name = process.name));
+				self.resize()
+			}
+			Message::ProcessKilled(process, Err(err)) => {
+				error!(?err, pid = process.pid, "error terminating process");
+				self.result = Some(tr!("kill-failed", name = process.name, error = err));
+				self.failed = true;
+				self.resize()
+			}
+			Message::WindowFocused(Ok(())) => Task::none(),
+			Message::WindowFocused(Err(err)) => {
+				error!(?err, "error focusing window");
+				tray::notify(&tr!("win-focus-failed"), &err);
+				Task::none()
+			}
+			Message::SystemCommandRan(Ok(())) => Task::none(),
+			Message::SystemCommandRan(Err(err)) => {
+				error!(?err, "error running system command");
+				tray::notify(&tr!("sys-failed"), &err);
+				Task::none()
+			}
+			Message::ActionStarted(action, Ok(())) => {
+				debug!(name = %action.name, "action started");
+				tray::notify(
+					&tr!("action-started", name = action.name.as_str()),
+					&action.command_line(),
+				);
+				Task::none()
+			}
+			Message::ActionStarted(action, Err(err)) => {
+				error!(?err, name = %action.name, "error starting action");
+				tray::notify(&tr!("action-failed", name = action.name.as_str()), &err);
+				Task::none()
+			}
+			Message::TimerDone(id) => {
+				let Some(index) = self.timers.iter().position(|timer| timer.id == id) else {
+					return Task::none();
+				};
+
+				let timer = self.timers.remove(index);
+				info!(label = %timer.label, "timer done");
+				tray::notify(&tr!("timer-done"), &timer.label());
+
+				let sound = if self.config.timer.sound {
+					Task::future(timers::play_sound()).discard()
+				} else {
+					Task::none()
+				};
+
+				// the list of timers shown in the timer mode changed
+				if self.mode == QuicalcMode::Timer {
+					Task::batch([sound, self.eval()])
+				} else {
+					sound
+				}
+			}
+			Message::Tick if self.mode == QuicalcMode::Timer && self.input.trim().is_empty() => {
+				self.eval()
+			}
+			Message::Tick if self.mode == QuicalcMode::Clock => self.show_matches(),
+			Message::Tick => Task::none(),
+			Message::EvaluateSelection => selection::read().map(Message::SelectionRead),
+			Message::SelectionRead(text) => {
+				let Some(text) = text
+					.map(|text| text.trim().to_string())
+					.filter(|text| !text.is_empty())
+				else {
+					tray::notify(&tr!("selection-failed"), &tr!("selection-empty"));
+					return Task::none();
+				};
+
+				self.set_mode(QuicalcMode::Kalk);
+				self.input = text;
+				self.file = None;
+				// selections often have words around the math, like pasted text
+				self.pasted = true;
+
+				Task::batch(vec![
+					self.set_panel(None),
+					self.eval_and_copy(),
+					Task::done(Message::ShowWindow(None)),
+				])
+			}
+			Message::ShowAbout => Task::batch(vec![
+				self.set_panel(Some(Panel::About)),
+				Task::done(Message::ShowWindow(None)),
+			]),
+			Message::Escape => {
+				if self.config.keys.editing != EditingStyle::Vi
+					|| self.panel.is_some()
+					|| (self.vi.normal && !self.vi.is_pending())
+				{
+					return self.update(Message::HideWindow);
+				}
+
+				self.set_vi(Vi::NORMAL)
+			}
+			Message::Vi(vi) => self.set_vi(vi),
+			Message::Pin => {
+				let Some(result) = self.result.clone().filter(|_| !self.failed) else {
+					return Task::none();
+				};
+
+				let (window, open) = window::open(Pinned::settings());
+				self.pinned.insert(window, Pinned {
+					input: self.input.clone(),
+					result,
+					accent: self.accent(),
+					on_top: true,
+				});
+
+				open.discard()
+			}
+			Message::Unpin(window) => {
+				self.pinned.remove(&window);
+				window::close(window)
+			}
+			Message::ToggleOnTop(window) => {
+				let on_top = if let Some(pinned) = self.pinned.get_mut(&window) {
+					pinned.on_top = !pinned.on_top;
+					pinned.on_top
+				} else {
+					self.behind = !self.behind;
+					!self.behind
+				};
+
+				debug!(?window, on_top, "changing window level");
+
+				window::set_level(
+					window,
+					if on_top {
+						Level::AlwaysOnTop
+					} else {
+						Level::Normal
+					},
+				)
+			}
+			Message::Unfocused if self.config.hide.on_unfocus => self.update(Message::HideWindow),
+			Message::Unfocused => Task::none(),
+			Message::HideWindow => {
+				self.finish_onboarding();
+				let vi = self.set_vi(Vi::INSERT);
+				self.hidden = true;
+				self.pending_eval = None;
+				self.file = None;
+				self.reading_file = None;
+				self.set_tooltip();
+
+				if self.config.hide.clear_input || self.input.is_empty() {
+					self.set_mode(QuicalcMode::default());
+				}
+
+				if self.config.hide.reset_kalk {
+					self.ctx = ImplDebug::default();
+					self.session.kalk.clear();
+				}
+
+				#[cfg(feature = "python")]
+				if self.config.hide.reset_python {
+					self.python.reset();
+					self.session.python.clear();
+				}
+
+				self.save_session();
+
+				let eval = if self.config.hide.reset_kalk || self.config.hide.reset_python {
+					self.context.fetch_add(1, Ordering::SeqCst);
+					self.eval()
+				} else {
+					Task::none()
+				};
+
+				Task::batch(vec![
+					eval,
+					vi,
+					self.set_panel(None),
+					window::oldest().and_then(|id| window::set_mode(id, Mode::Hidden)),
+				])
+			}
+			Message::InputChanged(input) if input == shortcuts::INPUT && self.input.is_empty() => {
+				Task::done(Message::ToggleShortcuts)
+			}
+			Message::InputChanged(input) => {
+				self.finish_onboarding();
+				self.clear_error();
+				self.record_edit(&input);
+				self.input = input;
+				self.pasted = false;
+				self.confirming = None;
+				self.killing = None;
+				self.file = None;
+				self.reading_file = None;
+
+				self.clear_completions();
+
+				let task = if self.composing {
+					Task::none()
+				} else {
+					self.debounce_eval()
+				};
+
+				Task::batch(vec![task, self.set_panel(None)])
+			}
+			Message::InputPasted(input) => {
+				let task = self.update(Message::InputChanged(input));
+				self.pasted = true;
+				task
+			}
+			Message::Evaluate => {
+				self.pending_eval = None;
+				self.eval()
+			}
+			Message::EvalCompleted { generation, result } => {
+				if generation != self.generation || !self.evaluates_math() {
+					return Task::none();
+				}
+
+				self.running_eval = None;
+				self.eval_duration = self.eval_started.take().map(|started| started.elapsed());
+
+				// pasted text often has math surrounded by words, which can't be evaluated
+				// as a whole
+				if mem::take(&mut self.pasted)
+					&& result.is_none()
+					&& !self.hidden && let Some(math) = paste::extract_math(&self.input)
+				{
+					debug!(math, "evaluating math from pasted text");
+					self.input = math;
+					let eval = if self.copy_result.take().is_some() {
+						self.eval_and_copy()
+					} else {
+						self.eval()
+					};
+
+					return Task::batch(vec![
+						eval,
+						operation::move_cursor_to_end(Id::new(Self::TEXT_INPUT_ID)),
+					]);
+				}
+
+				self.result = result.map(|res| format!("≈ {res}"));
+				self.failed = false;
+				self.cache_result();
+
+				if mem::take(&mut self.add_to_total) {
+					self.add_result_to_total();
+				}
+
+				let copy = if self
+					.copy_result
+					.take_if(|copied| *copied == generation)
+					.is_some()
+				{
+					Task::done(Message::CopyResult)
+				} else {
+					Task::none()
+				};
+
+				// the result of re-evaluating after hiding the window is the most recent one
+				if self.hidden {
+					self.set_tooltip();
+				}
+
+				Task::batch(vec![self.resize(), copy])
+			}
+			Message::PadEvaluated {
+				generation,
+				results,
+			} => {
+				if generation != self.generation || self.mode != QuicalcMode::Pad {
+					return Task::none();
+				}
+
+				self.running_eval = None;
+				self.eval_duration = self.eval_started.take().map(|started| started.elapsed());
+				self.pad_results = results;
+				Task::none()
+			}
+			Message::InputSubmitted if self.composing => Task::none(),
+			#[cfg(feature = "python")]
+			Message::InputSubmitted
+				if self.mode == QuicalcMode::Python
+					&& self.editor.is_none()
+					&& self.modifiers.shift() =>
+			{
+				let mut editor = Content::with_text(&format!("{}\n", self.input));
+				editor.perform(EditorAction::Move(Motion::DocumentEnd));
+				self.input = editor.text();
+				self.editor = Some(ImplDebug(editor));
+
+				Task::batch(vec![
+					self.resize(),
+					operation::focus(Id::new(Self::TEXT_INPUT_ID)),
+				])
+			}
+			Message::InputSubmitted if self.file.is_some() => {
+				let hash = self.file.take().map(|file| file.sha256).unwrap_or_default();
+				self.result = Some(tr!("file-hash-copied"));
+				clipboard::write(hash)
+			}
+			Message::InputSubmitted => {
+				let mut task = Task::none();
+				let eval = self.flush_eval();
+
+				self.clear_completions();
+
+				match Command::parse(&self.input, self.mode, &self.config) {
+					Some(Command::SetMode(mode)) => self.set_mode(mode),
+					Some(Command::Unsupported(mode)) => {
+						self.input.clear();
+						self.result = Some(tr!("mode-unsupported", mode = tr!(mode)));
+					}
+					Some(Command::ShowPanel(panel)) => {
+						self.input.clear();
+						self.result = None;
+						task = self.set_panel(Some(panel));
+					}
+					Some(Command::ClearTotal) => {
+						self.input.clear();
+						self.result = Some(tr!("total-cleared"));
+						self.total = None;
+						// results using the total are outdated now
+						self.context.fetch_add(1, Ordering::SeqCst);
+					}
+					Some(Command::Clear) => {
+						self.reset_context();
+						self.set_mode(self.mode);
+					}
+					Some(Command::SaveSession(name)) => {
+						let snapshot = Snapshot {
+							session: self.session.clone(),
+							history: self.undo.clone(),
+							total: self.total,
+						};
+
+						self.input.clear();
+
+						match session::save_named(&name, &snapshot) {
+							Ok(()) => self.result = Some(tr!("session-saved", name = name)),
+							Err(err) => {
+								error!(?err, %name, "error saving named session");
+								self.result = Some(tr!("session-save-failed", name = name));
+								self.failed = true;
+							}
+						}
+					}
+					Some(Command::LoadSession(name)) => {
+						self.input.clear();
+
+						match session::load_named(&name) {
+							Ok(Some(snapshot)) => {
+								self.load_snapshot(snapshot);
+								self.result = Some(tr!("session-loaded", name = name));
+							}
+							Ok(None) => {
+								self.result = Some(tr!("session-not-found", name = name));
+								self.failed = true;
+							}
+							Err(err) => {
+								error!(?err, %name, "error loading named session");
+								self.result = Some(tr!("session-load-failed", name = name));
+								self.failed = true;
+							}
+						}
+					}
+					#[cfg(feature = "python")]
+					Some(Command::ResetPython) => {
+						// results of evaluating the command as an expression are outdated now
+						self.python_job = self.python.reset();
+						self.session.python.clear();
+						self.save_session();
+
+						self.input.clear();
+						self.result = Some(tr!("python-reset"));
+						self.output = None;
+						self.traceback = None;
+						self.figure = None;
+					}
+					#[cfg(feature = "python")]
+					Some(Command::RunPython) => {
+						self.eval_started = Some(Instant::now());
+						let id = self.python.run(&self.input);
+						self.python_job = Some(id);
+						self.running_code = Some((id, self.input.clone()));
+					}
+					Some(Command::RunSystem) => match SystemCommand::parse(&self.input) {
+						Some(command) if self.confirming == Some(command) => {
+							info!(?command, "running system command");
+							self.confirming = None;
+							self.input.clear();
+							self.result = None;
+							task = Task::done(Message::HideWindow)
+								.chain(Task::perform(command.run(), Message::SystemCommandRan));
+						}
+						Some(command) => {
+							self.confirming = Some(command);
+							self.result = Some(tr!("sys-confirm", action = command.description()));
+						}
+						None => {
+							self.result = Some(tr!("sys-unknown"));
+							self.failed = true;
+						}
+					},
+					Some(Command::KillProcess) => match self.processes.selected().cloned() {
+						Some(process) if self.killing == Some(process.pid) => {
+							info!(pid = process.pid, name = %process.name, "terminating process");
+							self.killing = None;
+							let pid = process.pid;
+							task = Task::perform(
+								tokio::task::spawn_blocking(move || processes::kill(pid)),
+								move |res| {
+									Message::ProcessKilled(
+										process,
+										res.unwrap_or_else(|err| Err(err.to_string())),
+									)
+								},
+							);
+						}
+						Some(process) => {
+							self.killing = Some(process.pid);
+							self.result = Some(tr!("kill-confirm", name = process.name));
+						}
+						None => {
+							self.result = Some(tr!("kill-no-match"));
+							self.failed = true;
+						}
+					},
+					Some(Command::FocusWindow) => match self.windows.selected().cloned() {
+						Some(window) => {
+							info!(title = %window.title, app = %window.app, "focusing window");
+							self.input.clear();
+							self.result = None;
+							self.windows = Search::default();
+							task = Task::done(Message::HideWindow).chain(Task::perform(
+								tokio::task::spawn_blocking(move || switcher::focus(&window)),
+								|res| {
+									Message::WindowFocused(
+										res.unwrap_or_else(|err| Err(err.to_string())),
+									)
+								},
+							));
+						}
+						None => {
+							self.result = Some(tr!("win-no-match"));
+							self.failed = true;
+						}
+					},
+					Some(Command::OpenBookmark(search)) => {
+						let mut bookmarks = Search::default();
+						bookmarks.set_items(bookmarks::load(&self.config.go));
+						bookmarks.search(&search);
+						task = self.open_bookmark(bookmarks.selected().cloned());
+					}
+					Some(Command::OpenSelectedBookmark) => {
+						task = self.open_bookmark(self.bookmarks.selected().cloned());
+					}
+					Some(Command::WebQuery(url)) => {
+						info!(%url, "opening web query");
+
+						if let Err(err) = open::that(&url) {
+							error!(?err, %url, "error opening web query");
+							self.result = Some(tr!("query-failed"));
+							self.failed = true;
+						} else {
+							self.input.clear();
+							self.result = None;
+							task = Task::done(Message::HideWindow);
+						}
+					}
+					Some(Command::StartTimer(duration, label)) => {
+						task = self.start_timer(duration, label);
+					}
+					#[cfg(feature = "weather")]
+					Some(Command::LookUpWeather) => {
+						task = self.fetch_weather(self.input.trim().to_string());
+					}
+					Some(Command::ToggleStopwatch) => {
+						self.input.clear();
+
+						self.result = Some(match self.stopwatch.take() {
+							Some(started) => tr!(
+								"sw-stopped",
+								elapsed = timers::format_clock(started.elapsed())
+							),
+							None => {
+								self.stopwatch = Some(Instant::now());
+								tr!("sw-started")
+							}
+						});
+					}
+					Some(Command::RunAction(action)) => {
+						info!(name = %action.name, args = ?action.args, "running action");
+						self.input.clear();
+						self.result = None;
+						let res = action.run();
+						task = Task::done(Message::HideWindow)
+							.chain(Task::done(Message::ActionStarted(action, res)));
+					}
+					None if self.evaluates_math() => {
+						if self.mode == QuicalcMode::Kalk && self.input.contains('=') {
+							self.session.push_kalk(&self.input);
+							self.save_session();
+						}
+
+						// the result is only known once the evaluation is done
+						if self.running_eval.is_some() {
+							self.add_to_total = true;
+						} else {
+							self.add_result_to_total();
+						}
+					}
+					None => (),
+				};
+
+				Task::batch(vec![
+					task,
+					eval,
+					self.resize(),
+					operation::focus(Id::new(Self::TEXT_INPUT_ID)),
+					operation::select_all(Id::new(Self::TEXT_INPUT_ID)),
+				])
+			}
+			#[cfg(feature = "python")]
+			Message::ModifiersChanged(modifiers) => {
+				self.modifiers = modifiers;
+				Task::none()
+			}
+			Message::ResultAction(action) => {
+				// the result can only be selected, not edited
+				if !action.is_edit()
+					&& let Some((_, content)) = &mut self.result_selection
+				{
+					content.perform(action);
+				}
+
+				Task::none()
+			}
+			Message::Undo => {
+				let Some(input) = self.undo.pop() else {
+					return Task::none();
+				};
+
+				let input = mem::replace(&mut self.input, input);
+				self.redo.push(input);
+				self.restore_input()
+			}
+			Message::Redo => {
+				let Some(input) = self.redo.pop() else {
+					return Task::none();
+				};
+
+				let input = mem::replace(&mut self.input, input);
+				self.undo.push(input);
+				self.restore_input()
+			}
+			Message::ToggleShortcuts => {
+				if self.panel == Some(Panel::Shortcuts) {
+					self.set_panel(None)
+				} else {
+					self.set_panel(Some(Panel::Shortcuts))
+				}
+			}
+			Message::CopyResult => {
+				let Some(value) = self.result_value() else {
+					return Task::none();
+				};
+
+				let (hide, handle) =
+					Task::perform(tokio::time::sleep(Self::COPIED_DURATION), |()| {
+						Message::HideCopied
+					})
+					.abortable();
+				self.copied = Some(handle.abort_on_drop());
+
+				Task::batch(vec![clipboard::write(value.to_string()), hide])
+			}
+			Message::HideCopied => {
+				self.copied = None;
+				Task::none()
+			}
+			Message::ExpandResult => {
+				self.result_expanded = true;
+				self.resize()
+			}
+			Message::EditorAction(action) => {
+				let Some(editor) = &mut self.editor else {
+					return Task::none();
+				};
+
+				let edit = action.is_edit();
+				editor.perform(action);
+
+				if !edit {
+					return Task::none();
+				}
+
+				let input = editor.text();
+				self.finish_onboarding();
+				self.record_edit(&input);
+				self.input = input;
+				self.clear_error();
+
+				// vi's normal mode needs the editor's cursor position
+				let keep_editor =
+					self.input.contains('\n') || self.mode == QuicalcMode::Pad || self.vi.normal;
+
+				let task = if keep_editor {
+					Task::none()
+				} else {
+					self.editor = None;
+
+					Task::batch(vec![
+						operation::focus(Id::new(Self::TEXT_INPUT_ID)),
+						operation::move_cursor_to_end(Id::new(Self::TEXT_INPUT_ID)),
+					])
+				};
+
+				Task::batch(vec![task, self.debounce_eval(), self.resize()])
+			}
+			#[cfg(feature = "python")]
+			Message::PythonEvaluated(id, python::Evaluation::Completions(completions)) => {
+				if self.completion_job != Some(id) || self.mode != QuicalcMode::Python {
+					return Task::none();
+				}
+
+				self.completion_job = None;
+				self.show_completions(completions)
+			}
+			Message::Complete => {
+				if self.editor.is_some() {
+					return Task::none();
+				}
+
+				// pressing tab again cycles through the completions
+				if !self.completions.is_empty() {
+					let index = self
+						.completion
+						.map_or(0, |index| (index + 1) % self.completions.len());
+					self.completion = Some(index);
+
+					let completion = self.completions[index].clone();
+					return self.insert_completion(&completion);
+				}
+
+				#[cfg(feature = "python")]
+				if self.mode == QuicalcMode::Python {
+					let word = python::completion_word(&self.input);
+
+					if !word.is_empty() {
+						self.completion_start = self.input.len() - word.len();
+						self.completion_job = Some(self.python.complete(word));
+					}
+
+					return Task::none();
+				}
+
+				// web queries and actions are completed while they're the only word
+				let word = self.input.trim_start();
+
+				if self.mode != QuicalcMode::Kalk
+					|| word.is_empty()
+					|| word.contains(char::is_whitespace)
+				{
+					return Task::none();
+				}
+
+				self.completion_start = self.input.len() - word.len();
+				let mut completions = queries::complete(&self.config.queries, word);
+				completions.extend(actions::complete(&self.config.actions, word));
+				completions.sort();
+				completions.dedup();
+				self.show_completions(completions)
+			}
+			Message::CompletionSelected(index) => {
+				let Some(completion) = self.completions.get(index).cloned() else {
+					return Task::none();
+				};
+
+				self.clear_completions();
+				Task::batch(vec![
+					self.insert_completion(&completion),
+					operation::focus(Id::new(Self::TEXT_INPUT_ID)),
+				])
+			}
+			#[cfg(feature = "python")]
+			Message::PythonEvaluated(id, evaluation) => {
+				if Some(id) != self.python_job || !self.mode.uses_python() {
+					return Task::none();
+				}
+
+				self.eval_duration = self.eval_started.take().map(|started| started.elapsed());
+
+				match evaluation {
+					// pretty-printed math spanning multiple lines needs a monospace font
+					#[cfg(feature = "sym")]
+					python::Evaluation::Expression { result, output, .. }
+						if self.mode == QuicalcMode::Sym =>
+					{
+						match result {
+							Some(result) if result.contains('\n') => {
+								self.result = None;
+								self.output = Some(if output.is_empty() {
+									result
+								} else {
+									format!("{result}\n{output}")
+								});
+							}
+							result => {
+								self.result = result.map(|res| format!("= {res}"));
+								self.output = Some(output);
+							}
+						}
+						self.traceback = None;
+						self.figure = None;
+						self.failed = false;
+					}
+					python::Evaluation::Expression {
+						result,
+						output,
+						figure,
+						error,
+					} => {
+						self.result = match &error {
+							Some(error) => Some(error.summary.clone()),
+							None => result.map(|res| format!("→ {res}")),
+						};
+						self.failed = error.is_some();
+						self.output = Some(output);
+						self.traceback = error.map(|error| error.traceback);
+						self.figure = figure;
+					}
+					python::Evaluation::Statements {
+						result,
+						output,
+						figure,
+						error,
+					} => {
+						if error.is_none()
+							&& let Some((_, code)) =
+								self.running_code.take_if(|(running, _)| *running == id)
+						{
+							self.session.push_python(&code);
+							self.save_session();
+						}
+
+						self.result = match &error {
+							Some(error) => Some(error.summary.clone()),
+							None => result.map(|res| format!("→ {res}")),
+						};
+						self.failed = error.is_some();
+						self.output = Some(output);
+						self.traceback = error.map(|error| error.traceback);
+						self.figure = figure;
+					}
+					python::Evaluation::Completions(_) => return Task::none(),
+					python::Evaluation::TimedOut => {
+						self.result = Some(tr!("python-timeout"));
+						self.failed = true;
+						self.output = None;
+						self.traceback = None;
+						self.figure = None;
+					}
+				}
+
+				self.output = self.output.take().filter(|output| !output.is_empty());
+
+				// unlike python, sympy doesn't keep any state between evaluations
+				#[cfg(feature = "sym")]
+				if self.mode == QuicalcMode::Sym {
+					self.cache_result();
+				}
+
+				self.resize()
+			}
+			// the prompt shows the interpreter's version, which is known once it's ready
+			#[cfg(feature = "python")]
+			Message::PythonReady => Task::none(),
+			Message::ToggleOutput => {
+				self.output_expanded = !self.output_expanded;
+				self.resize()
+			}
+			Message::Composing(composing) => {
+				let finished = self.composing && !composing;
+				self.composing = composing;
+
+				if finished { self.eval() } else { Task::none() }
+			}
+			Message::KeyPressed(modifiers, Physical::Code(KeyCode::Tab), _)
+				if modifiers.is_empty()
+					&& matches!(self.mode, QuicalcMode::Kalk | QuicalcMode::Python) =>
+			{
+				Task::done(Message::Complete)
+			}
+			Message::KeyPressed(
+				modifiers,
+				Physical::Code(code @ (KeyCode::ArrowUp | KeyCode::ArrowDown)),
+				_,
+			) if modifiers.is_empty()
+				&& matches!(
+					self.mode,
+					QuicalcMode::Kill | QuicalcMode::Win | QuicalcMode::Go | QuicalcMode::Clock
+				) =>
+			{
+				let forward = code == KeyCode::ArrowDown;
+				self.select_match(|search| search.select_next(forward));
+				self.resize()
+			}
+			Message::KeyPressed(modifiers, physical_key, key) => {
+				// the multi-line editor has its own bindings for these
+				if self.config.keys.editing == EditingStyle::Emacs
+					&& self.editor.is_none()
+					&& let Some(edit) = LineEdit::from_key(modifiers, &key, physical_key)
+				{
+					self.line_edit(edit)
+				} else if let Some((modifiers, code)) = hotkey::from_iced(modifiers, physical_key)
+					&& self
+						.config
+						.keys
+						.confirm
+						.iter()
+						.any(|confirm| confirm.matches(modifiers, code))
+				{
+					Task::done(Message::InputSubmitted)
+				} else {
+					Task::none()
+				}
+			}
+			Message::FinishStartup => {
+				tray::set_up(&self.config.tray)
+					.inspect(|_| info!("set up tray icon"))
+					.inspect_err(|err| error!(?err, "error setting up tray icon"))
+					.ok();
+
+				startup::phase("tray set up");
+
+				let icon = &*assets::ICON;
+				let (width, height, pixels) =
+					(icon.width(), icon.height(), icon.to_rgba8().into_vec());
+
+				icon::from_rgba(pixels, width, height)
+					.inspect_err(|err| error!(?err, "error setting window icon"))
+					.map_or_else(
+						|_| Task::none(),
+						|icon| {
+							window::oldest().and_then(move |id| window::set_icon(id, icon.clone()))
+						},
+					)
+			}
+			Message::RefreshTray => {
+				tray::refresh();
+				Task::none()
+			}
+			Message::ToggleAutostart => {
+				if autostart::is_enabled() {
+					autostart::disable()
+						.inspect(|_| info!("disabled start at login"))
+						.inspect_err(|err| error!(?err, "error disabling start at login"))
+						.ok();
+				} else {
+					autostart::enable()
+						.inspect(|_| info!("enabled start at login"))
+						.inspect_err(|err| error!(?err, "error enabling start at login"))
+						.ok();
+				}
+
+				tray::refresh();
+				Task::none()
+			}
+			Message::OpenConfig => {
+				match Config::create_if_missing() {
+					Ok(path) => {
+						for path in [path.parent(), Some(path.as_path())].into_iter().flatten() {
+							open::that(path)
+								.inspect_err(|err| error!(?err, ?path, "error opening config"))
+								.ok();
+						}
+					}
+					Err(err) => error!(?err, "error creating config file"),
+				}
+
+				Task::none()
+			}
+			Message::ConfigChanged => {
+				match Config::reload() {
+					Ok(config) => self.apply_config(config),
+					Err(err) => {
+						error!(?err, "error reloading config");
+						tray::notify(&tr!("config-invalid"), &err);
+					}
+				}
+
+				Task::none()
+			}
+			#[cfg(feature = "updater")]
+			Message::CheckForUpdates => Task::perform(updater::check(), Message::UpdateChecked),
+			#[cfg(feature = "updater")]
+			Message::UpdateChecked(Ok(Some(release))) => {
+				if self.update.as_ref().map(|update| &update.tag_name) != Some(&release.tag_name) {
+					info!(version = %release.tag_name, "update available");
+
+					tray::notify(
+						&tr!("update-available"),
+						&tr!("update-available-body", version = release.tag_name.clone()),
+					);
+
+					tray::show_update(&if release.can_self_update() {
+						tr!("tray-update-install", version = release.tag_name.clone())
+					} else {
+						tr!("tray-update-download", version = release.tag_name.clone())
+					});
+				}
+
+				self.update = Some(release);
+				Task::none()
+			}
+			#[cfg(feature = "updater")]
+			Message::UpdateChecked(Ok(None)) => Task::none(),
+			#[cfg(feature = "updater")]
+			Message::UpdateChecked(Err(err)) => {
+				error!(?err, "error checking for updates");
+				Task::none()
+			}
+			#[cfg(feature = "updater")]
+			Message::InstallUpdate => match &self.update {
+				Some(release) if release.can_self_update() => {
+					Task::perform(updater::install(release.clone()), Message::UpdateInstalled)
+				}
+				Some(release) => {
+					open::that(&release.html_url)
+						.inspect_err(|err| error!(?err, "error opening download page"))
+						.ok();
+					Task::none()
+				}
+				None => Task::none(),
+			},
+			#[cfg(feature = "updater")]
+			Message::UpdateInstalled(Ok(())) => {
+				info!("update installed, restarting");
+
+				env::current_exe()
+					.and_then(|exe| process::Command::new(exe).spawn())
+					.inspect_err(|err| error!(?err, "error restarting after update"))
+					.ok();
+
+				exit()
+			}
+			#[cfg(feature = "updater")]
+			Message::UpdateInstalled(Err(err)) => {
+				error!(?err, "error installing update");
+				tray::notify(&tr!("update-failed"), &err);
+				Task::none()
+			}
+			Message::Watchdog => {
+				let now = SystemTime::now();
+				let resumed = self
+					.last_watchdog
+					.and_then(|last| now.duration_since(last).ok())
+					.is_some_and(|elapsed| elapsed > Self::WATCHDOG_INTERVAL * 4);
+				self.last_watchdog = Some(now);
+
+				if resumed {
+					info!("resumed from sleep, re-registering global hotkeys and tray icon");
+
+					hotkey::reregister(&self.config.hotkey)
+						.inspect_err(|err| error!(?err, "error re-registering global hotkeys"))
+						.ok();
+
+					return Task::done(Message::RecreateTray);
+				}
+
+				#[cfg(windows)]
+				match hotkey::restore_lost() {
+					Ok(true) => {
+						info!("global hotkey was no longer registered, registered it again")
+					}
+					Ok(false) => (),
+					Err(err) => error!(?err, "error checking global hotkey registration"),
+				}
+
+				Task::none()
+			}
+			// the tray icon library adds the icon again by itself too, but that can
+			// happen before the taskbar is ready for it
+			#[cfg(windows)]
+			Message::TaskbarCreated => Task::perform(tokio::time::sleep(Self::TASKBAR_DELAY), |()| {
+				Message::RecreateTray
+			}),
+			Message::RecreateTray => {
+				tray::recreate()
+					.inspect_err(|err| error!(?err, "error re-creating tray icon"))
+					.ok();
+
+				Task::none()
+			}
+			Message::Exit => exit(),
+		}
+	}
+
+	fn view(&self, window: window::Id) -> Element<'_, Message, Theme> {
+		trace!("view");
+
+		if let Some(pinned) = self.pinned.get(&window) {
+			return pinned.view();
+		}
+
+		// nothing is shown while hidden, so there's no need to lay anything out
+		if self.hidden {
+			return column![].into();
+		}
+
+		let pad = self.mode == QuicalcMode::Pad;
+		let accent = self.accent();
+		let editing = self.config.keys.editing;
+		let vi = self.vi;
+
+		column![
+			if let Some(editor) = &self.editor {
+				let editor = TextEditor::new(editor)
+					.placeholder(self.prompt())
+					.style(move |theme, status| {
+						let style = text_editor::default(theme, status);
+						// a thicker border shows that keys run vi commands instead of typing
+						let border = if vi.normal {
+							style.border.width(2.0)
+						} else {
+							style.border
+						};
+
+						text_editor::Style {
+							border: border.color(accent),
+							..style
+						}
+					})
+					.on_action(Message::EditorAction)
+					.key_binding(move |key_press| {
+						let focused = matches!(key_press.status, EditorStatus::Focused { .. });
+
+						if editing == EditingStyle::Emacs
+							&& focused && let Some(edit) = LineEdit::from_key(
+							key_press.modifiers,
+							&key_press.key,
+							key_press.physical_key,
+						) {
+							return Some(edit.binding());
+						}
+
+						if editing == EditingStyle::Vi && focused {
+							// escape switches vi's modes instead of unfocusing the editor
+							if key_press.physical_key == Physical::Code(KeyCode::Escape) {
+								return None;
+							}
+
+							if vi.normal
+								&& let Some(key) = Vi::command_key(&key_press)
+							{
+								return vi.binding(key);
+							}
+						}
+
+						let enter = matches!(
+							key_press.physical_key,
+							Physical::Code(KeyCode::Enter | KeyCode::NumpadEnter)
+						);
+
+						// pads are for writing multiple lines, so a new line is the default there
+						let new_line = if pad {
+							!key_press.modifiers.control()
+						} else {
+							key_press.modifiers.shift()
+						};
+
+						if enter && focused {
+							Some(if new_line {
+								Binding::Enter
+							} else {
+								Binding::Custom(Message::InputSubmitted)
+							})
+						} else {
+							Binding::from_key_press(key_press)
+						}
+					})
+					.id(Id::new(Self::TEXT_INPUT_ID));
+
+				if pad {
+					row![editor.wrapping(Wrapping::None), self.pad_results_view()].into()
+				} else {
+					editor.into()
+				}
+			} else {
+				Element::from(
+					text_input(&self.prompt(), &self.input)
+						.on_input(Message::InputChanged)
+						.on_paste(Message::InputPasted)
+						.on_submit(Message::InputSubmitted)
+						.style(move |theme, status| {
+							let style = text_input::default(theme, status);
+							text_input::Style {
+								border: style.border.color(accent),
+								..style
+							}
+						})
+						.align_x(if is_rtl(&self.input) {
+							Horizontal::Right
+						} else {
+							Horizontal::Left
+						})
+						.id(Id::new(Self::TEXT_INPUT_ID)),
+				)
+			},
+			if self.onboarding {
+				onboarding::view()
+			} else if let Some(panel) = self.panel {
+				panel.view(&self.config)
+			} else {
+				column![
+					row![
+						mouse_area(if self.failed {
+							Element::from(
+								text("⚠")
+									.size(Self::RESULT_TEXT_SIZE)
+									.shaping(Shaping::Advanced)
+									.style(text::danger),
+							)
+						} else {
+							Image::new(self.mode.indicator()).into()
+						})
+						.on_press(Message::CopyResult)
+						.interaction(Interaction::Pointer)
+					]
+					.extend(self.copied.is_some().then(|| {
+						text(format!("✓ {}", tr!("result-copied")))
+							.size(Self::OUTPUT_TEXT_SIZE)
+							.style(text::success)
+							.into()
+					}))
+					.extend([
+						self.result_view(if self.failed {
+							self.theme(window).palette().danger
+						} else {
+							accent
+						}),
+						text(self.result_details())
+							.size(Self::OUTPUT_TEXT_SIZE)
+							.style(text::secondary)
+							.into(),
+					])
+					.align_y(Alignment::Center)
+				]
+				.extend(self.completions_view())
+				.extend(self.matches_view())
+				.extend(self.figure_view())
+				.extend(self.output_view())
+				.into()
+			},
+		]
+		.padding(0)
+		.align_x(Alignment::Start)
+		.into()
+	}
+
+	fn set_mode(&mut self, mode: QuicalcMode) {
+		if self.mode != mode {
+			tray::set_mode(mode);
+		}
+
+		self.mode = mode;
+		self.input.clear();
+		self.result = None;
+		self.failed = false;
+		self.output = None;
+		self.traceback = None;
+		self.editor = (mode == QuicalcMode::Pad).then(|| ImplDebug(Content::new()));
+		self.vi = Vi::INSERT;
+		self.confirming = None;
+		self.processes = Search::default();
+		self.killing = None;
+		self.windows = Search::default();
+		self.bookmarks = Search::default();
+		self.zones = Search::default();
+		self.pad_results.clear();
+		self.pending_eval = None;
+		self.running_eval = None;
+
+		#[cfg(feature = "python")]
+		self.figure = None;
+
+		self.clear_completions();
+
+		#[cfg(feature = "python")]
+		if mode.uses_python() {
+			self.python.start();
+		}
+	}
+
+	fn set_panel(&mut self, panel: Option<Panel>) -> Task<Message> {
+		self.panel = panel;
+		self.resize()
+	}
+
+	fn window_size(&self) -> Size {
+		if let Some(panel) = self.panel {
+			return panel.window_size();
+		}
+
+		let lines = self
+			.editor
+			.as_ref()
+			.map_or(1, |editor| editor.line_count())
+			.clamp(1, Self::MAX_EDITOR_LINES);
+
+		Size::new(
+			Self::WINDOW_SIZE.width,
+			Self::WINDOW_SIZE.height
+				+ (lines - 1) as f32 * Self::EDITOR_LINE_HEIGHT
+				+ self.completion_lines() as f32 * Self::OUTPUT_LINE_HEIGHT
+				+ self.match_lines() as f32 * Self::OUTPUT_LINE_HEIGHT
+				+ self.figure_height()
+				+ self
+					.result_area_height()
+					.map_or(0.0, |height| (height - Self::EDITOR_LINE_HEIGHT).max(0.0))
+				+ self.output_lines() as f32 * Self::OUTPUT_LINE_HEIGHT,
+		)
+	}
+
+	/// Add the result of the submitted input to the running total, unless the
+	/// input uses the total itself
+	fn add_result_to_total(&mut self) {
+		let (_, uses_total) = pad::replace_totals(&self.input, "0");
+
+		if let Some(value) = self
+			.result
+			.as_deref()
+			.and_then(|result| result.strip_prefix("≈ "))
+			.and_then(|result| result.parse::<f64>().ok())
+			&& !uses_total
+		{
+			self.total = Some(self.total.unwrap_or_default() + value);
+			// results using the total are outdated now
+			self.context.fetch_add(1, Ordering::SeqCst);
+		}
+	}
+
+	/// The result, shrunk to fit into the window down to a minimum size, after
+	/// which it's shortened and shown in full in a tooltip
+	///
+	/// Results spanning multiple lines, or shortened ones that were clicked,
+	/// are wrapped and can be scrolled instead.
+	fn result_view(&self, accent: Color) -> Element<'_, Message> {
+		let result = self.result.as_deref().unwrap_or_default();
+
+		if let Some(height) = self.result_area_height() {
+			return self.selectable_result(
+				accent,
+				Self::OUTPUT_TEXT_SIZE,
+				Wrapping::WordOrGlyph,
+				Length::Fixed(height),
+			);
+		}
+
+		// glyphs have different widths, so this is only an estimate
+		let width = self.result_width();
+		let chars = result.chars().count().max(1) as f32;
+
+		let size = (width / (chars * Self::CHAR_WIDTH))
+			.clamp(Self::MIN_RESULT_TEXT_SIZE, Self::RESULT_TEXT_SIZE);
+		let max_chars = (width / (size * Self::CHAR_WIDTH)) as usize;
+
+		if chars as usize <= max_chars {
+			return self.selectable_result(accent, size, Wrapping::None, Length::Shrink);
+		}
+
+		let mut elided = result
+			.chars()
+			.take(max_chars.saturating_sub(1))
+			.collect::<String>();
+		elided.push('…');
+
+		tooltip(
+			button(
+				text(elided)
+					.shaping(Shaping::Advanced)
+					.size(size)
+					.color(accent),
+			)
+			.on_press(Message::ExpandResult)
+			.padding(0)
+			.width(Length::Fill)
+			.style(button::text),
+			container(
+				text(result)
+					.shaping(Shaping::Advanced)
+					.size(Self::OUTPUT_TEXT_SIZE),
+			)
+			.padding(5)
+			.style(container::rounded_box),
+			tooltip::Position::Bottom,
+		)
+		.into()
+	}
+
+	/// The result as read-only text that can be selected and copied
+	fn selectable_result(
+		&self,
+		accent: Color,
+		size: f32,
+		wrapping: Wrapping,
+		height: Length,
+	) -> Element<'_, Message> {
+		let Some((_, content)) = &self.result_selection else {
+			return text("").width(Length::Fill).into();
+		};
+
+		let editor = TextEditor::new(content)
+			.on_action(Message::ResultAction)
+			.size(size)
+			.wrapping(wrapping)
+			.height(height)
+			.padding(0)
+			.style(move |theme, status| text_editor::Style {
+				background: Background::Color(Color::TRANSPARENT),
+				border: Border::default(),
+				value: accent,
+				..text_editor::default(theme, status)
+			});
+
+		if wrapping == Wrapping::None {
+			editor.into()
+		} else {
+			editor.font(Font::MONOSPACE).into()
+		}
+	}
+
+	/// Remember the input from before an edit, so it can be undone
+	fn record_edit(&mut self, input: &str) {
+		if input == self.input {
+			return;
+		}
+
+		self.undo.push(self.input.clone());
+		self.redo.clear();
+
+		if self.undo.len() > Self::MAX_UNDO_STEPS {
+			self.undo.remove(0);
+		}
+	}
+
+	/// Edit the single-line input with a readline-style shortcut
+	fn line_edit(&mut self, edit: LineEdit) -> Task<Message> {
+		let id = Id::new(Self::TEXT_INPUT_ID);
+
+		if !edit.is_edit() {
+			return if edit == LineEdit::Home {
+				operation::move_cursor_to_front(id)
+			} else {
+				operation::move_cursor_to_end(id)
+			};
+		}
+
+		let input = edit.apply(&self.input);
+		self.record_edit(&input);
+		self.input = input;
+		self.restore_input()
+	}
+
+	/// Forget the variables and functions defined in the current mode
+	fn reset_context(&mut self) {
+		match self.mode {
+			QuicalcMode::Kalk => {
+				self.ctx = ImplDebug::default();
+				self.session.kalk.clear();
+			}
+			#[cfg(feature = "python")]
+			QuicalcMode::Python => {
+				// results of evaluating the command as an expression are outdated now
+				self.python_job = self.python.reset();
+				self.session.python.clear();
+			}
+			// pads and symbolic math don't keep anything between evaluations
+			_ => return,
+		}
+
+		self.context.fetch_add(1, Ordering::SeqCst);
+		self.save_session();
+	}
+
+	/// Replace the current session with a saved one
+	fn load_snapshot(&mut self, snapshot: Snapshot) {
+		let mut ctx = Context::default();
+		snapshot.session.restore_kalk(&mut ctx);
+		self.ctx = ImplDebug(Arc::new(Mutex::new(ctx)));
+
+		#[cfg(feature = "python")]
+		{
+			// results of the code from the previous session are outdated now
+			self.python_job = self.python.reset();
+			self.python.restore(snapshot.session.python.clone());
+		}
+
+		self.session = snapshot.session;
+		self.undo = snapshot.history;
+		self.redo.clear();
+		self.total = snapshot.total;
+		// results from the previous session's context are outdated now
+		self.context.fetch_add(1, Ordering::SeqCst);
+		self.save_session();
+	}
+
+	/// Save the session, if it's restored after restarting
+	fn save_session(&self) {
+		if self.config.session.persist {
+			self.session
+				.save()
+				.inspect_err(|err| error!(?err, "error saving session"))
+				.ok();
+		}
+	}
+
+	/// Switch between vi's modes
+	///
+	/// Normal mode always uses the multi-line editor, because the single-line
+	/// input doesn't tell where its cursor is.
+	fn set_vi(&mut self, vi: Vi) -> Task<Message> {
+		let was_normal = mem::replace(&mut self.vi, vi).normal;
+		let id = Id::new(Self::TEXT_INPUT_ID);
+
+		if vi.normal && self.editor.is_none() {
+			// normal mode starts on the last character, like after typing
+			let mut content = Content::with_text(&self.input);
+			content.perform(EditorAction::Move(Motion::DocumentEnd));
+			content.perform(EditorAction::Move(Motion::Left));
+			self.editor = Some(ImplDebug(content));
+
+			Task::batch(vec![self.resize(), operation::focus(id)])
+		} else if !vi.normal
+			&& was_normal
+			&& !self.input.contains('\n')
+			&& self.mode != QuicalcMode::Pad
+			&& let Some(editor) = self.editor.take()
+		{
+			let column = editor.cursor().position.column;
+			let position = self
+				.input
+				.get(..column)
+				.map_or(0, |before| before.chars().count());
+
+			Task::batch(vec![
+				self.resize(),
+				operation::focus(id.clone()),
+				operation::move_cursor_to(id, position),
+			])
+		} else {
+			Task::none()
+		}
+	}
+
+	/// Show a changed input and evaluate it
+	fn restore_input(&mut self) -> Task<Message> {
+		self.clear_error();
+
+		let cursor = if let Some(editor) = &mut self.editor {
+			let mut content = Content::with_text(&self.input);
+			content.perform(EditorAction::Move(Motion::DocumentEnd));
+			*editor = ImplDebug(content);
+			Task::none()
+		} else {
+			operation::move_cursor_to_end(Id::new(Self::TEXT_INPUT_ID))
+		};
+
+		Task::batch(vec![cursor, self.debounce_eval(), self.resize()])
+	}
+
+	/// Remove an error from the result as soon as the input changes, instead of
+	/// once it's evaluated again
+	fn clear_error(&mut self) {
+		if mem::take(&mut self.failed) {
+			self.result = None;
+			self.traceback = None;
+		}
+	}
+
+	/// The result without the symbol in front of it
+	fn result_value(&self) -> Option<&str> {
+		let result = self.result.as_deref()?;
+
+		Some(
+			["≈ ", "→ ", "= "]
+				.into_iter()
+				.find_map(|prefix| result.strip_prefix(prefix))
+				.unwrap_or(result),
+		)
+	}
+
+	/// The width left for the result next to the indicator and running total
+	fn result_width(&self) -> f32 {
+		let details_width = self.result_details().chars().count() as f32
+			* Self::OUTPUT_TEXT_SIZE
+			* Self::CHAR_WIDTH;
+
+		self.size.width - Self::EDITOR_LINE_HEIGHT - details_width
+	}
+
+	/// How long the evaluation took (if enabled), the running total, and the
+	/// next timer and stopwatch if they're running, shown dimly next to the
+	/// result
+	fn result_details(&self) -> String {
+		let duration = self
+			.eval_duration
+			.filter(|_| self.config.eval.show_duration)
+			.filter(|_| self.result.is_some() || self.mode == QuicalcMode::Pad)
+			.map(|duration| format!("· {}", format_duration(duration)));
+		let total = self
+			.total
+			.map(|total| format!("Σ {}", format_number(total)));
+		let timer = self
+			.timers
+			.first()
+			.map(|timer| format!("⏲ {}", timers::format_clock(timer.remaining())));
+		let stopwatch = self
+			.stopwatch
+			.map(|started| format!("⏱ {}", timers::format_clock(started.elapsed())));
+
+		[duration, total, timer, stopwatch]
+			.into_iter()
+			.flatten()
+			.collect::<Vec<_>>()
+			.join("  ")
+	}
+
+	/// The height of the area showing a result that spans multiple lines, or a
+	/// long one that was expanded
+	fn result_area_height(&self) -> Option<f32> {
+		let result = self.result.as_deref()?;
+
+		if !result.contains('\n') && !self.result_expanded {
+			return None;
+		}
+
+		let line_chars =
+			((self.result_width() / (Self::OUTPUT_TEXT_SIZE * Self::CHAR_WIDTH)) as usize).max(1);
+		let lines = result
+			.lines()
+			.map(|line| line.chars().count().div_ceil(line_chars).max(1))
+			.sum::<usize>();
+
+		Some(lines.min(Self::MAX_RESULT_LINES) as f32 * Self::OUTPUT_LINE_HEIGHT)
+	}
+
+	/// Keep the selectable copy of the result up to date with it
+	fn sync_result_selection(&mut self) {
+		let source = self.result_selection.as_ref().map(|(source, _)| source);
+
+		if source != self.result.as_ref() {
+			self.result_selection = self
+				.result
+				.clone()
+				.map(|result| (result.clone(), ImplDebug(Content::with_text(&result))));
+			self.result_expanded = false;
+		}
+	}
+
+	/// The results of a pad's lines, lined up with them
+	fn pad_results_view(&self) -> Element<'_, Message> {
+		column(self.pad_results.iter().map(|result| {
+			text(
+				result
+					.as_deref()
+					.map_or_else(String::new, |res| format!("= {res}")),
+			)
+			.line_height(LineHeight::Absolute(Pixels(Self::EDITOR_LINE_HEIGHT)))
+			.shaping(Shaping::Advanced)
+			.wrapping(Wrapping::None)
+			.into()
+		}))
+		.padding(Padding::ZERO.top(5).right(5))
+		.align_x(Alignment::End)
+		.into()
+	}
+
+	/// Start a countdown that notifies once it's done, showing that it was
+	/// started
+	fn start_timer(&mut self, duration: Duration, label: String) -> Task<Message> {
+		let Some(ends) = Instant::now().checked_add(duration) else {
+			self.result = Some(tr!("timer-invalid"));
+			self.failed = true;
+			return Task::none();
+		};
+
+		let id = self.next_timer_id;
+		self.next_timer_id += 1;
+
+		info!(?duration, %label, "starting timer");
+
+		let timer = Timer { id, label, ends };
+		self.result = Some(tr!(
+			"timer-started",
+			label = timer.label(),
+			duration = timers::format_clock(duration)
+		));
+		self.input.clear();
+
+		let index = self.timers.partition_point(|other| other.ends <= ends);
+		self.timers.insert(index, timer);
+
+		Task::perform(tokio::time::sleep(duration), move |()| {
+			Message::TimerDone(id)
+		})
+	}
+
+	/// What the web query or action in the input does, or the result of the
+	/// calendar helper in the input, if it's one of them
+	fn command_result(&self) -> Option<Result<String, String>> {
+		if let Some(url) = queries::url(&self.config.queries, &self.input) {
+			return Some(Ok(tr!("query-hint", url = url)));
+		}
+
+		if let Some(action) = Action::parse(&self.config.actions, &self.input) {
+			return Some(Ok(tr!("action-hint", command = action.command_line())));
+		}
+
+		match self.input.split_once(char::is_whitespace) {
+			Some((QuicalcMode::GEO_COMMAND, places)) => return geo::eval(places),
+			Some((QuicalcMode::SUN_COMMAND, place)) => return sun::eval(place),
+			Some((tip::COMMAND, bill)) => return tip::eval(bill),
+			Some((aspect::COMMAND, size)) => return aspect::eval(size),
+			Some((QuicalcMode::LOAN_COMMAND, loan)) => return Some(finance::loan(loan)),
+			Some((finance::COMPOUND_COMMAND, investment)) => {
+				return Some(finance::compound(investment));
+			}
+			_ => (),
+		}
+
+		if let Some(result) = tax::eval(&self.input, &self.config.tax) {
+			return Some(result);
+		}
+
+		if let Some(result) = sizes::eval(&self.input) {
+			return Some(result);
+		}
+
+		calendar::eval(&self.input, &self.config.calendar)
+	}
+
+	/// Complete the word being completed in the input as far as all of the
+	/// completions agree, showing them if there's more than one
+	fn show_completions(&mut self, completions: Vec<String>) -> Task<Message> {
+		match completions.as_slice() {
+			[] => Task::none(),
+			[completion] => {
+				let completion = completion.clone();
+				self.insert_completion(&completion)
+			}
+			_ => {
+				let prefix = common_prefix(&completions).to_string();
+				self.completions = completions;
+				self.insert_completion(&prefix)
+			}
+		}
+	}
+
+	/// Replace the word being completed in the input
+	fn insert_completion(&mut self, completion: &str) -> Task<Message> {
+		self.input.truncate(self.completion_start);
+		self.input.push_str(completion);
+
+		Task::batch(vec![
+			self.eval(),
+			self.resize(),
+			operation::move_cursor_to_end(Id::new(Self::TEXT_INPUT_ID)),
+		])
+	}
+
+	fn clear_completions(&mut self) {
+		self.completions.clear();
+		self.completion = None;
+
+		#[cfg(feature = "python")]
+		{
+			self.completion_job = None;
+		}
+	}
+
+	/// The first of the completions shown, so that the selected one is visible
+	fn completions_page(&self) -> usize {
+		self.completion.unwrap_or_default() / Self::MAX_COMPLETIONS * Self::MAX_COMPLETIONS
+	}
+
+	fn completion_lines(&self) -> usize {
+		self.completions
+			.len()
+			.saturating_sub(self.completions_page())
+			.min(Self::MAX_COMPLETIONS)
+	}
+
+	fn completions_view(&self) -> Vec<Element<'_, Message>> {
+		self.completions
+			.iter()
+			.enumerate()
+			.skip(self.completions_page())
+			.take(Self::MAX_COMPLETIONS)
+			.map(|(index, completion)| {
+				button(
+					text(completion)
+						.size(Self::OUTPUT_TEXT_SIZE)
+						.font(Font::MONOSPACE),
+				)
+				.on_press(Message::CompletionSelected(index))
+				.padding(0)
+				.width(Length::Fill)
+				.style(if self.completion == Some(index) {
+					button::primary
+				} else {
+					button::text
+				})
+				.into()
+			})
+			.collect()
+	}
+
+	/// Search the processes, windows, bookmarks, or time zones for the input,
+	/// and show the matches
+	fn show_matches(&mut self) -> Task<Message> {
+		let (found, hint, no_match) = match self.mode {
+			QuicalcMode::Kill => {
+				self.processes.search(&self.input);
+				(
+					!self.processes.matches.is_empty(),
+					"kill-hint",
+					"kill-no-match",
+				)
+			}
+			QuicalcMode::Win => {
+				self.windows.search(&self.input);
+				(!self.windows.matches.is_empty(), "win-hint", "win-no-match")
+			}
+			QuicalcMode::Go => {
+				self.bookmarks.search(&self.input);
+				(!self.bookmarks.matches.is_empty(), "go-hint", "go-no-match")
+			}
+			// the configured clocks are shown until something is searched
+			QuicalcMode::Clock if self.input.trim().is_empty() => {
+				self.zones.search("");
+				self.failed = false;
+				self.result = (!self.clocks.is_empty()).then(|| {
+					self.clocks
+						.iter()
+						.map(Searchable::summary)
+						.collect::<Vec<_>>()
+						.join("\n")
+				});
+
+				return self.resize();
+			}
+			QuicalcMode::Clock => {
+				self.zones.search(&self.input);
+				(
+					!self.zones.matches.is_empty(),
+					"clock-hint",
+					"clock-no-match",
+				)
+			}
+			_ => return Task::none(),
+		};
+
+		self.failed = false;
+		self.result = if self.input.trim().is_empty() {
+			None
+		} else if found {
+			Some(tr!(hint))
+		} else {
+			// nothing can match before the list is ready
+			self.running_eval.is_none().then(|| tr!(no_match))
+		};
+
+		self.resize()
+	}
+
+	/// Open a bookmark in the browser and hide the window, or show that there
+	/// was no matching one
+	fn open_bookmark(&mut self, bookmark: Option<Bookmark>) -> Task<Message> {
+		let Some(bookmark) = bookmark else {
+			self.result = Some(tr!("go-no-match"));
+			self.failed = true;
+			return Task::none();
+		};
+
+		info!(name = %bookmark.name, "opening bookmark");
+
+		if let Err(err) = bookmarks::open(&bookmark) {
+			error!(?err, url = %bookmark.url, "error opening bookmark");
+			self.result = Some(tr!("go-open-failed", name = bookmark.name));
+			self.failed = true;
+			return Task::none();
+		}
+
+		self.input.clear();
+		self.result = None;
+		Task::done(Message::HideWindow)
+	}
+
+	/// Change which of the processes, windows, bookmarks, or time zones is
+	/// selected
+	fn select_match(&mut self, select: impl FnOnce(&mut dyn Selection)) {
+		match self.mode {
+			QuicalcMode::Kill => {
+				select(&mut self.processes);
+				self.killing = None;
+				self.result = Some(tr!("kill-hint"));
+			}
+			QuicalcMode::Win => {
+				select(&mut self.windows);
+				self.result = Some(tr!("win-hint"));
+			}
+			QuicalcMode::Go => {
+				select(&mut self.bookmarks);
+				self.result = Some(tr!("go-hint"));
+			}
+			QuicalcMode::Clock => {
+				select(&mut self.zones);
+				self.result = Some(tr!("clock-hint"));
+			}
+			_ => return,
+		}
+
+		self.failed = false;
+	}
+
+	/// The lines showing the processes, windows, bookmarks, or time zones
+	/// found, with the selected one
+	fn matches(&self) -> (Vec<String>, usize) {
+		match self.mode {
+			QuicalcMode::Kill => (self.processes.summaries(), self.processes.selected),
+			QuicalcMode::Win => (self.windows.summaries(), self.windows.selected),
+			QuicalcMode::Go => (self.bookmarks.summaries(), self.bookmarks.selected),
+			QuicalcMode::Clock => (self.zones.summaries(), self.zones.selected),
+			_ => (Vec::new(), 0),
+		}
+	}
+
+	fn match_lines(&self) -> usize {
+		let (matches, selected) = self.matches();
+		let page = selected / Self::MAX_COMPLETIONS * Self::MAX_COMPLETIONS;
+
+		(matches.len() - page).min(Self::MAX_COMPLETIONS)
+	}
+
+	fn matches_view(&self) -> Vec<Element<'_, Message>> {
+		let (matches, selected) = self.matches();
+		// the page with the selected match is shown
+		let page = selected / Self::MAX_COMPLETIONS * Self::MAX_COMPLETIONS;
+
+		matches
+			.into_iter()
+			.enumerate()
+			.skip(page)
+			.take(Self::MAX_COMPLETIONS)
+			.map(|(index, summary)| {
+				button(
+					text(summary)
+						.size(Self::OUTPUT_TEXT_SIZE)
+						.font(Font::MONOSPACE),
+				)
+				.on_press(Message::MatchSelected(index))
+				.padding(0)
+				.width(Length::Fill)
+				.style(if selected == index {
+					button::primary
+				} else {
+					button::text
+				})
+				.into()
+			})
+			.collect()
+	}
+
+	/// The height of the figure drawn by Python, scaled to fit the window
+	fn figure_height(&self) -> f32 {
+		#[cfg(feature = "python")]
+		if let Some(figure) = &self.figure {
+			let scale = (Self::WINDOW_SIZE.width / figure.width as f32).min(1.0);
+			return (figure.height as f32 * scale).min(Self::MAX_FIGURE_HEIGHT);
+		}
+
+		0.0
+	}
+
+	fn figure_view(&self) -> Option<Element<'_, Message>> {
+		#[cfg(feature = "python")]
+		if let Some(figure) = &self.figure {
+			return Some(
+				Image::new(figure.handle.clone())
+					.width(Length::Fill)
+					.height(self.figure_height())
+					.into(),
+			);
+		}
+
+		None
+	}
+
+	/// The printed output, followed by the traceback of an error
+	fn full_output(&self) -> String {
+		[self.output.as_deref(), self.traceback.as_deref()]
+			.into_iter()
+			.flatten()
+			.map(str::trim_end)
+			.collect::<Vec<_>>()
+			.join("\n")
+	}
+
+	/// The number of lines of output shown before expanding it
+	fn output_preview_lines(&self) -> Option<usize> {
+		let lines = self
+			.output
+			.as_deref()
+			.map_or(0, |output| output.lines().count());
+
+		// tracebacks are only shown once expanded
+		if self.traceback.is_some() || lines > Self::OUTPUT_PREVIEW_LINES {
+			Some(lines.min(Self::OUTPUT_PREVIEW_LINES))
+		} else {
+			None
+		}
+	}
+
+	/// The number of lines taken up by printed output, including the expander
+	fn output_lines(&self) -> usize {
+		let lines = self.full_output().lines().count();
+
+		match self.output_preview_lines() {
+			None => lines,
+			Some(_) if self.output_expanded => lines.min(Self::MAX_OUTPUT_LINES) + 1,
+			Some(preview) => preview + 1,
+		}
+	}
+
+	fn output_view(&self) -> Vec<Element<'_, Message>> {
+		if self.output.is_none() && self.traceback.is_none() {
+			return Vec::new();
+		}
+
+		let output = self.full_output();
+		let lines = output.lines().count();
+		let output_text = |content| {
+			text(content)
+				.size(Self::OUTPUT_TEXT_SIZE)
+				.font(Font::MONOSPACE)
+				.shaping(Shaping::Advanced)
+				.wrapping(Wrapping::None)
+		};
+
+		let Some(preview) = self.output_preview_lines() else {
+			return vec![output_text(output).into()];
+		};
+
+		let (view, toggle): (Option<Element<'_, Message>>, String) = if self.output_expanded {
+			(
+				Some(
+					scrollable(output_text(output))
+						.height(lines.min(Self::MAX_OUTPUT_LINES) as f32 * Self::OUTPUT_LINE_HEIGHT)
+						.width(Length::Fill)
+						.into(),
+				),
+				tr!("output-less"),
+			)
+		} else {
+			(
+				(preview > 0).then(|| {
+					output_text(output.lines().take(preview).collect::<Vec<_>>().join("\n")).into()
+				}),
+				if self.traceback.is_some() {
+					tr!("output-traceback")
+				} else {
+					tr!("output-more", lines = lines - preview)
+				},
+			)
+		};
+
+		view.into_iter()
+			.chain([button(text(toggle).size(Self::OUTPUT_TEXT_SIZE))
+				.on_press(Message::ToggleOutput)
+				.padding(0)
+				.style(button::text)
+				.into()])
+			.collect()
+	}
+
+	fn resize(&mut self) -> Task<Message> {
+		let size = self.window_size();
+
+		if self.size == size {
+			return Task::none();
+		}
+
+		self.size = size;
+		window::oldest().and_then(move |id| window::resize(id, size))
+	}
+
+	fn finish_onboarding(&mut self) {
+		if self.onboarding {
+			self.onboarding = false;
+			onboarding::complete();
+		}
+	}
+
+	/// Evaluate the input once typing pauses, replacing any evaluation that's
+	/// still waiting
+	fn debounce_eval(&mut self) -> Task<Message> {
+		let debounce = Duration::from_millis(self.config.eval.debounce_ms);
+
+		if debounce.is_zero() {
+			return self.eval();
+		}
+
+		let (task, handle) =
+			Task::perform(tokio::time::sleep(debounce), |()| Message::Evaluate).abortable();
+
+		// dropping the previous handle aborts its evaluation
+		self.pending_eval = Some(handle.abort_on_drop());
+		task
+	}
+
+	/// Immediately start an evaluation that's waiting for typing to pause
+	fn flush_eval(&mut self) -> Task<Message> {
+		if self.pending_eval.take().is_some() {
+			self.eval()
+		} else {
+			Task::none()
+		}
+	}
+
+	/// Stop waiting for the results of evaluations that are pending or still
+	/// running
+	fn cancel_eval(&mut self) {
+		self.pending_eval = None;
+		self.running_eval = None;
+		self.generation += 1;
+
+		#[cfg(feature = "python")]
+		{
+			self.python_job = None;
+		}
+	}
+
+	/// Start evaluating the input, replacing any evaluation that's still
+	/// running
+	/// Evaluate the input, and copy the result once it's done
+	fn eval_and_copy(&mut self) -> Task<Message> {
+		let eval = self.eval();
+
+		// cached results are shown right away
+		if self.running_eval.is_none() {
+			return Task::batch(vec![eval, Task::done(Message::CopyResult)]);
+		}
+
+		self.copy_result = Some(self.generation);
+		eval
+	}
+
+	fn eval(&mut self) -> Task<Message> {
+		trace!("eval");
+
+		// assignments and definitions change the context, so results from before
+		// them may no longer apply
+		if self.mode == QuicalcMode::Kalk && self.input.contains('=') {
+			self.context.fetch_add(1, Ordering::SeqCst);
+		}
+
+		// web queries, actions, and calendar helpers aren't math, so they're
+		// handled here instead
+		if self.mode == QuicalcMode::Kalk
+			&& let Some(result) = self.command_result()
+		{
+			self.failed = result.is_err();
+			self.result = Some(result.unwrap_or_else(|err| err));
+			self.output = None;
+			self.eval_key = None;
+			self.eval_started = None;
+			self.generation += 1;
+			self.running_eval = None;
+			return self.resize();
+		}
+
+		// prices can be used in math in kalk mode too, like `price btc * 0.05`
+		#[cfg(feature = "prices")]
+		if self.mode == QuicalcMode::Kalk
+			&& let Some((QuicalcMode::PRICE_COMMAND, input)) =
+				self.input.split_once(char::is_whitespace)
+		{
+			return self.eval_price(input.to_string());
+		}
+
+		let context = self.context.load(Ordering::SeqCst);
+		let key = self
+			.mode
+			.is_cacheable()
+			.then(|| (self.mode, context, self.input.clone()));
+
+		if let Some(cached) = key.as_ref().and_then(|key| self.cache.get(key)) {
+			trace!("using cached result");
+
+			self.result = cached.result.clone();
+			self.output = cached.output.clone();
+			self.eval_key = None;
+			self.eval_duration = None;
+			self.failed = false;
+			self.generation += 1;
+			self.running_eval = None;
+
+			#[cfg(feature = "python")]
+			{
+				self.python_job = None;
+			}
+
+			return self.resize();
+		}
+
+		self.eval_key = key;
+		self.eval_started = Some(Instant::now());
+
+		match self.mode {
+			QuicalcMode::Kalk => self.eval_math(self.input.clone()),
+			QuicalcMode::Pad => {
+				self.generation += 1;
+				let generation = self.generation;
+				let input = self.input.clone();
+
+				let (task, handle) = Task::perform(
+					tokio::task::spawn_blocking(move || pad::eval_lines(&input)),
+					move |results| Message::PadEvaluated {
+						generation,
+						results: results
+							.inspect_err(|err| error!(?err, "error running evaluation"))
+							.unwrap_or_default(),
+					},
+				)
+				.abortable();
+
+				self.running_eval = Some(handle.abort_on_drop());
+				task
+			}
+			#[cfg(feature = "python")]
+			QuicalcMode::Python => {
+				self.python_job = Some(self.python.eval(&self.input));
+				Task::none()
+			}
+			#[cfg(feature = "sym")]
+			QuicalcMode::Sym => {
+				self.python_job = Some(self.python.symbolic(&self.input));
+				Task::none()
+			}
+			QuicalcMode::Timer => {
+				self.eval_started = None;
+				self.failed = false;
+
+				self.result = if self.input.trim().is_empty() {
+					Some(if self.timers.is_empty() {
+						tr!("timer-hint")
+					} else {
+						self.timers
+							.iter()
+							.map(Timer::summary)
+							.collect::<Vec<_>>()
+							.join("\n")
+					})
+				} else if let Some((duration, label)) = timers::parse(&self.input) {
+					Some(tr!(
+						"timer-start",
+						duration = timers::format_clock(duration),
+						label = timers::label(&label)
+					))
+				} else {
+					self.failed = true;
+					Some(tr!("timer-invalid"))
+				};
+
+				self.resize()
+			}
+			QuicalcMode::Cron => {
+				self.eval_started = None;
+
+				match Schedule::parse(&self.input) {
+					_ if self.input.trim().is_empty() => {
+						self.result = None;
+						self.failed = false;
+					}
+					Ok(schedule) => {
+						self.result = Some(schedule.explain());
+						self.failed = false;
+					}
+					Err(err) => {
+						self.result = Some(err);
+						self.failed = true;
+					}
+				}
+
+				self.resize()
+			}
+			QuicalcMode::Geo | QuicalcMode::Sun | QuicalcMode::Loan => {
+				self.eval_started = None;
+
+				let helper = match self.mode {
+					QuicalcMode::Geo => geo::eval,
+					QuicalcMode::Sun => sun::eval,
+					_ => finance::eval,
+				};
+
+				match helper(&self.input) {
+					None => {
+						self.result = None;
+						self.failed = false;
+					}
+					Some(Ok(result)) => {
+						self.result = Some(result);
+						self.failed = false;
+					}
+					Some(Err(err)) => {
+						self.result = Some(err);
+						self.failed = true;
+					}
+				}
+
+				self.resize()
+			}
+			QuicalcMode::Sys => {
+				self.eval_started = None;
+				self.result = SystemCommand::parse(&self.input).map(SystemCommand::description);
+				self.failed = false;
+				self.resize()
+			}
+			QuicalcMode::Kill | QuicalcMode::Win | QuicalcMode::Go => {
+				self.eval_started = None;
+
+				let stale = match self.mode {
+					QuicalcMode::Kill => self.processes.is_stale(),
+					QuicalcMode::Win => self.windows.is_stale(),
+					_ => self.bookmarks.is_stale(),
+				};
+
+				if self.input.trim().is_empty() || !stale || self.running_eval.is_some() {
+					return self.show_matches();
+				}
+
+				self.generation += 1;
+				let generation = self.generation;
+
+				let list = match self.mode {
+					QuicalcMode::Kill => Task::perform(
+						tokio::task::spawn_blocking(processes::list),
+						move |processes| Message::ProcessesListed {
+							generation,
+							processes: processes
+								.inspect_err(|err| error!(?err, "error listing processes"))
+								.unwrap_or_default(),
+						},
+					),
+					QuicalcMode::Win => Task::perform(
+						tokio::task::spawn_blocking(switcher::list),
+						move |windows| Message::WindowsListed {
+							generation,
+							windows: windows.unwrap_or_else(|err| Err(err.to_string())),
+						},
+					),
+					_ => {
+						let config = self.config.go.clone();
+
+						Task::perform(
+							tokio::task::spawn_blocking(move || bookmarks::load(&config)),
+							move |bookmarks| Message::BookmarksListed {
+								generation,
+								bookmarks: bookmarks
+									.inspect_err(|err| error!(?err, "error loading bookmarks"))
+									.unwrap_or_default(),
+							},
+						)
+					}
+				};
+
+				let (task, handle) = list.abortable();
+				self.running_eval = Some(handle.abort_on_drop());
+
+				// the previous list is searched until the new one is ready
+				Task::batch(vec![self.show_matches(), task])
+			}
+			QuicalcMode::Clock => {
+				self.eval_started = None;
+
+				// the time zones don't change while quicalc is running
+				if self.zones.is_listed() || self.running_eval.is_some() {
+					return self.show_matches();
+				}
+
+				self.generation += 1;
+				let generation = self.generation;
+				let config = self.config.clock.clone();
+
+				let (task, handle) = Task::perform(
+					tokio::task::spawn_blocking(move || clock::list(&config)),
+					move |zones| {
+						let (zones, clocks) = zones
+							.inspect_err(|err| error!(?err, "error listing time zones"))
+							.unwrap_or_default();
+
+						Message::ZonesListed {
+							generation,
+							zones,
+							clocks,
+						}
+					},
+				)
+				.abortable();
+
+				self.running_eval = Some(handle.abort_on_drop());
+				task
+			}
+			#[cfg(feature = "weather")]
+			QuicalcMode::Wx => {
+				self.eval_started = None;
+				let location = self.input.trim().to_lowercase();
+
+				if let Some((fetched, weather)) = self.weather.get(&location)
+					&& fetched.elapsed() < weather::MAX_AGE
+				{
+					self.result = Some(weather.summary());
+					self.failed = false;
+					self.generation += 1;
+					self.running_eval = None;
+					return self.resize();
+				}
+
+				// the configured location is shown right away, others once they're
+				// submitted, instead of looking up every prefix while typing
+				if location.is_empty() {
+					return self.fetch_weather(location);
+				}
+
+				self.result = Some(tr!("wx-hint"));
+				self.failed = false;
+				self.generation += 1;
+				self.running_eval = None;
+				self.resize()
+			}
+			#[cfg(feature = "prices")]
+			QuicalcMode::Price => self.eval_price(self.input.clone()),
+		}
+	}
+
+	/// Evaluate math with kalk in the background, including phrasings about
+	/// percentages like `120 -> 150`
+	fn eval_math(&mut self, input: String) -> Task<Message> {
+		self.generation += 1;
+		let generation = self.generation;
+		self.latest_math.store(generation, Ordering::SeqCst);
+		let latest = Arc::clone(&self.latest_math);
+		let ctx = Arc::clone(&self.ctx);
+		let context = Arc::clone(&self.context);
+		let (input, _) =
+			pad::replace_totals(&input, &format_number(self.total.unwrap_or_default()));
+
+		let (task, handle) = Task::perform(
+			tokio::task::spawn_blocking(move || {
+				let defines = input.contains('=');
+				let mut ctx = ctx.lock().unwrap_or_else(PoisonError::into_inner);
+
+				// evaluations replaced while they were waiting for the context would
+				// only hold up the newer ones, but definitions may still be used later
+				if !defines && latest.load(Ordering::SeqCst) != generation {
+					return None;
+				}
+
+				let result = math::evaluate(&mut ctx, &input);
+
+				// other inputs may have been evaluated before this definition, while
+				// it was waiting for the context, so their results are outdated too
+				if defines {
+					context.fetch_add(1, Ordering::SeqCst);
+				}
+
+				result
+			}),
+			move |result| Message::EvalCompleted {
+				generation,
+				result: result
+					.inspect_err(|err| error!(?err, "error running evaluation"))
+					.ok()
+					.flatten(),
+			},
+		)
+		.abortable();
+
+		// dropping the previous handle only ignores its result, since kalk can't be
+		// interrupted, so an evaluation that already started keeps the context
+		// locked until it's done
+		self.running_eval = Some(handle.abort_on_drop());
+		task
+	}
+
+	/// Whether the current mode's results are from kalk, and can be added to
+	/// the total
+	fn evaluates_math(&self) -> bool {
+		match self.mode {
+			QuicalcMode::Kalk => true,
+			#[cfg(feature = "prices")]
+			QuicalcMode::Price => true,
+			_ => false,
+		}
+	}
+
+	/// Evaluate a price with math after it, like `btc * 0.05`, fetching the
+	/// price first unless it was fetched recently
+	#[cfg(feature = "prices")]
+	fn eval_price(&mut self, input: String) -> Task<Message> {
+		self.eval_key = None;
+
+		let Some((symbol, math)) = prices::split(&input) else {
+			self.eval_started = None;
+			self.result = Some(tr!("price-hint"));
+			self.failed = false;
+			self.generation += 1;
+			self.running_eval = None;
+			return self.resize();
+		};
+
+		if let Some(&(fetched, price)) = self.prices.get(&symbol)
+			&& fetched.elapsed() < prices::MAX_AGE
+		{
+			self.eval_started = Some(Instant::now());
+			return self.eval_math(format!("{price}{math}"));
+		}
+
+		self.generation += 1;
+		let generation = self.generation;
+
+		self.eval_started = Some(Instant::now());
+		self.result = Some(tr!("price-loading", symbol = symbol.as_str()));
+		self.failed = false;
+
+		let (task, handle) = Task::perform(
+			prices::fetch(self.config.price.clone(), symbol.clone()),
+			move |price| Message::PriceFetched {
+				generation,
+				symbol,
+				price,
+			},
+		)
+		.abortable();
+
+		self.running_eval = Some(handle.abort_on_drop());
+		Task::batch(vec![self.resize(), task])
+	}
+
+	/// Fetch the weather at a location, or at the configured location if it's
+	/// empty, showing it once it's ready
+	#[cfg(feature = "weather")]
+	fn fetch_weather(&mut self, location: String) -> Task<Message> {
+		self.generation += 1;
+		let generation = self.generation;
+
+		self.eval_started = Some(Instant::now());
+		self.result = Some(tr!("wx-loading"));
+		self.failed = false;
+
+		let (task, handle) = Task::perform(
+			weather::fetch(self.config.weather.clone(), location.clone()),
+			move |weather| Message::WeatherFetched {
+				generation,
+				location,
+				weather,
+			},
+		)
+		.abortable();
+
+		self.running_eval = Some(handle.abort_on_drop());
+		Task::batch(vec![self.resize(), task])
+	}
+
+	/// Remember the result of the evaluation that just completed
+	fn cache_result(&mut self) {
+		if let Some(key) = self.eval_key.take() {
+			self.cache.insert(key, CachedResult {
+				result: self.result.clone(),
+				output: self.output.clone(),
+			});
+		}
+	}
+
+	/// The text shown in the empty input, which can be changed for each mode
+	fn prompt(&self) -> String {
+		self.config
+			.prompts
+			.get(self.mode.command())
+			.cloned()
+			.unwrap_or_else(|| self.mode.prompt())
+	}
+
+	/// The accent color of the current mode, which can be changed for each mode
+	fn accent(&self) -> Color {
+		self.config
+			.accents
+			.get(self.mode.command())
+			.and_then(|color| color.parse().ok())
+			.unwrap_or_else(|| self.mode.accent())
+	}
+
+	/// Apply the settings from a changed config file, leaving the ones that
+	/// can only be set at startup for the next launch
+	fn apply_config(&mut self, config: Config) {
+		if config == self.config {
+			return;
+		}
+
+		info!("applying changed config");
+
+		if config.hotkey.key != self.config.hotkey.key
+			|| config.hotkey.fallbacks != self.config.hotkey.fallbacks
+		{
+			hotkey::reload(&config.hotkey)
+				.inspect_err(|err| error!(?err, "error registering changed hotkey"))
+				.ok();
+		}
+
+		#[cfg(feature = "ocr")]
+		if config.ocr.hotkey != self.config.ocr.hotkey {
+			hotkey::register_capture(config.ocr.hotkey)
+				.inspect_err(|err| {
+					error!(?err, "error registering changed capture hotkey");
+					tray::notify(&tr!("config-invalid"), err);
+				})
+				.ok();
+		}
+
+		if config.hotkey.selection != self.config.hotkey.selection {
+			hotkey::register_selection(config.hotkey.selection)
+				.inspect_err(|err| {
+					error!(?err, "error registering changed selection hotkey");
+					tray::notify(&tr!("config-invalid"), err);
+				})
+				.ok();
+		}
+
+		if config.tray.icon != self.config.tray.icon {
+			tray::set_style(&config.tray, self.mode)
+				.inspect_err(|err| error!(?err, "error changing tray icon style"))
+				.ok();
+		}
+
+		// the weather may be from another provider or location now
+		#[cfg(feature = "weather")]
+		if config.weather != self.config.weather {
+			self.weather.clear();
+		}
+
+		#[cfg(feature = "prices")]
+		if config.price != self.config.price {
+			self.prices.clear();
+		}
+
+		if self.config.tray.result_tooltip && !config.tray.result_tooltip {
+			tray::set_tooltip("Quicalc");
+		}
+
+		let needs_restart = config.language != self.config.language
+			|| config.renderer != self.config.renderer
+			|| config.python != self.config.python
+			|| config.hotkey.double_tap != self.config.hotkey.double_tap
+			|| config.hotkey.chord != self.config.hotkey.chord
+			|| config.hotkey.gesture_timeout_ms != self.config.hotkey.gesture_timeout_ms;
+
+		if needs_restart {
+			tray::notify(&tr!("config-restart"), &tr!("config-restart-body"));
+		}
+
+		// the debounce, confirm keys, update checks, and clipboard prefill are read
+		// from the config whenever they're used
+		self.config = config;
+	}
+
+	fn set_tooltip(&self) {
+		if self.config.tray.result_tooltip
+			&& let Some(result) = &self.result
+		{
+			let input = self.input.chars().take(64).collect::<String>();
+			tray::set_tooltip(&format!("Quicalc\n{input} {result}"));
+		}
+	}
+}
+
+/// The longest prefix shared by all of the strings
+fn common_prefix(strings: &[String]) -> &str {
+	let Some((first, rest)) = strings.split_first() else {
+		return "";
+	};
+
+	let len = rest.iter().fold(first.len(), |len, string| {
+		first[..len]
+			.char_indices()
+			.zip(string.chars())
+			.find(|&((_, a), b)| a != b)
+			.map_or(len.min(string.len()), |((index, _), _)| index)
+	});
+
+	&first[..len]
+}
+
+fn is_rtl(text: &str) -> bool {
+	unicode_bidi::get_base_direction(text) == Direction::Rtl
+}
+
+/// Format a number so kalk can parse it, without floating point noise
+fn format_number(number: f64) -> String {
+	let rounded = (number * 1e10).round() / 1e10;
+
+	if rounded < 0.0 {
+		format!("({rounded})")
+	} else {
+		rounded.to_string()
+	}
+}
+
+/// Format how long something took, precisely enough to tell fast evaluations
+/// apart
+fn format_duration(duration: Duration) -> String {
+	let millis = duration.as_secs_f64() * 1000.0;
+
+	if millis < 10.0 {
+		format!("{millis:.1} ms")
+	} else if millis < 1000.0 {
+		format!("{millis:.0} ms")
+	} else {
+		format!("{:.2} s", duration.as_secs_f64())
+	}
+}
+
+/// Choose iced's renderer, this must be called before any other threads are
+/// spawned
+fn set_renderer(renderer: Renderer) {
+	let backend = match renderer {
+		// iced already tries software rendering if the GPU can't be used
+		Renderer::Auto => return,
+		Renderer::Gpu => "wgpu",
+		Renderer::Software => "tiny-skia",
+	};
+
+	info!(backend, "using renderer");
+
+	// SAFETY: this is called at startup, before any other threads are spawned
+	unsafe { env::set_var("ICED_BACKEND", backend) };
+}
+
+fn main() {
+	let start = Instant::now();
+
+	tracing_subscriber::registry()
+		.with(fmt::layer())
+		.with(EnvFilter::from_env("QUICALC_LOG"))
+		.init();
+
+	let args = Args::parse().unwrap_or_else(|err| {
+		eprintln!("quicalc: {err}\n\n{}", cli::USAGE);
+		process::exit(2);
+	});
+
+	if let Some(profile) = args.profile.clone() {
+		paths::set_profile(profile);
+
+		if let Err(err) = paths::init_profile(args.new_profile) {
+			eprintln!("quicalc: {err}");
+			process::exit(1);
+		}
+	}
+
+	if let Some(action) = &args.action {
+		let result = action
+			.run()
+			.inspect(|_| info!(?action, "done"))
+			.inspect_err(|err| {
+				error!(?err, ?action, "error running action");
+				eprintln!("quicalc: {err}");
+			});
+
+		process::exit(if result.is_ok() { 0 } else { 1 });
+	}
+
+	if args.startup_profile {
+		startup::profile(start);
+	}
+
+	autostart::verify();
+
+	let config = Config::load();
+	locale::init(config.language.as_deref());
+	startup::phase("config loaded");
+
+	// restarting with software rendering is only done once, and only if the
+	// renderer wasn't chosen explicitly
+	let fallback = config.renderer == Renderer::Auto && !args.software_rendering;
+
+	// no threads may be spawned before these, because they set environment
+	// variables
+	set_renderer(if args.software_rendering {
+		Renderer::Software
+	} else {
+		config.renderer
+	});
+	#[cfg(feature = "python")]
+	python::init(&config.python);
+
+	assets::preload();
+
+	#[cfg(target_os = "linux")]
+	let use_hotkey_manager = !portal::is_wayland();
+	#[cfg(not(target_os = "linux"))]
+	let use_hotkey_manager = true;
+
+	if use_hotkey_manager {
+		hotkey::register(&config.hotkey)
+			.inspect(|_| info!("set up global hotkey"))
+			.inspect_err(|err| error!(?err, "error setting up global hotkey"))
+			.ok();
+		hotkey::listen(&config.hotkey);
+
+		#[cfg(feature = "ocr")]
+		if config.ocr.hotkey.is_some() {
+			hotkey::register_capture(config.ocr.hotkey)
+				.inspect_err(|err| error!(?err, "error registering capture hotkey"))
+				.ok();
+		}
+
+		if config.hotkey.selection.is_some() {
+			hotkey::register_selection(config.hotkey.selection)
+				.inspect_err(|err| error!(?err, "error registering selection hotkey"))
+				.ok();
+		}
+	}
+
+	startup::phase("hotkey registered");
+
+	// iced panics on some surface errors, like running out of GPU memory
+	let result = panic::catch_unwind(AssertUnwindSafe(|| {
+		iced::daemon(
+			move || Quicalc::new(config.clone(), args.capture),
+			Quicalc::update,
+			Quicalc::view,
+		)
+		.subscription(Quicalc::subscription)
+		.theme(Quicalc::theme)
+		.title(Quicalc::title)
+		.settings(Settings {
+			antialiasing: true,
+			default_text_size: Pixels(32.0),
 			..Default::default()
 		})
 		.run()
-		.inspect_err(|err| error!(?err, "error running application"))
-		.unwrap();
+	}));
+
+	if fallback && !matches!(result, Ok(Ok(()))) {
+		warn!("the renderer failed, restarting with software rendering");
+		restart_with_software_rendering();
+	}
+
+	match result {
+		Ok(result) => result
+			.inspect_err(|err| error!(?err, "error running application"))
+			.unwrap(),
+		Err(payload) => panic::resume_unwind(payload),
+	}
+}
+
+/// Start Quicalc again with the same arguments and software rendering, and
+/// exit if that worked
+fn restart_with_software_rendering() {
+	// the new process registers the same hotkeys
+	hotkey::release();
+
+	let restarted = env::current_exe().and_then(|exe| {
+		process::Command::new(exe)
+			.args(env::args_os().skip(1))
+			.arg("--software-rendering")
+			.spawn()
+	});
+
+	match restarted {
+		Ok(_) => process::exit(0),
+		Err(err) => error!(?err, "error restarting with software rendering"),
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use std::{sync::mpsc, thread};
+
+	use proptest::prelude::*;
+
+	use super::*;
+
+	fn new(config: Config) -> Quicalc {
+		Quicalc {
+			config,
+			hidden: true,
+			size: Quicalc::WINDOW_SIZE,
+			..Quicalc::default()
+		}
+	}
+
+	/// Handle the messages like the event loop would, without running the
+	/// tasks they return
+	fn update(quicalc: &mut Quicalc, messages: impl IntoIterator<Item = Message>) {
+		let runtime = tokio::runtime::Builder::new_current_thread()
+			.enable_time()
+			.build()
+			.unwrap();
+		let _guard = runtime.enter();
+
+		for message in messages {
+			let _ = quicalc.update(message);
+		}
+	}
+
+	fn submit(quicalc: &mut Quicalc, input: &str) {
+		update(quicalc, [
+			Message::InputChanged(input.to_string()),
+			Message::InputSubmitted,
+		]);
+	}
+
+	#[test]
+	fn submitting_a_mode_command_switches_modes() {
+		let mut quicalc = new(Config::default());
+
+		submit(&mut quicalc, QuicalcMode::CRON_COMMAND);
+		assert_eq!(quicalc.mode, QuicalcMode::Cron);
+		assert!(quicalc.input.is_empty());
+
+		submit(&mut quicalc, timers::COMMAND);
+		assert_eq!(quicalc.mode, QuicalcMode::Timer);
+		assert!(quicalc.input.is_empty());
+
+		submit(&mut quicalc, QuicalcMode::KALK_COMMAND);
+		assert_eq!(quicalc.mode, QuicalcMode::Kalk);
+	}
+
+	#[test]
+	fn q_resets_the_mode_only_as_the_whole_input() {
+		let mut quicalc = new(Config::default());
+		submit(&mut quicalc, QuicalcMode::CRON_COMMAND);
+
+		submit(&mut quicalc, "q 5");
+		assert_eq!(quicalc.mode, QuicalcMode::Cron);
+		assert_eq!(quicalc.input, "q 5");
+
+		submit(&mut quicalc, "5 q");
+		assert_eq!(quicalc.mode, QuicalcMode::Cron);
+
+		submit(&mut quicalc, "q");
+		assert_eq!(quicalc.mode, QuicalcMode::Kalk);
+		assert!(quicalc.input.is_empty());
+	}
+
+	#[test]
+	fn submitting_an_empty_input_resets_the_mode() {
+		let mut quicalc = new(Config::default());
+		submit(&mut quicalc, QuicalcMode::CRON_COMMAND);
+
+		submit(&mut quicalc, "");
+		assert_eq!(quicalc.mode, QuicalcMode::Kalk);
+	}
+
+	#[test]
+	fn showing_the_window_switches_modes() {
+		let mut quicalc = new(Config::default());
+
+		update(&mut quicalc, [Message::ShowWindow(Some(
+			QuicalcMode::Timer,
+		))]);
+		assert!(!quicalc.hidden);
+		assert_eq!(quicalc.mode, QuicalcMode::Timer);
+
+		update(&mut quicalc, [Message::ShowWindow(None)]);
+		assert_eq!(quicalc.mode, QuicalcMode::Timer);
+	}
+
+	#[test]
+	fn hiding_with_an_empty_input_resets_the_mode() {
+		let mut quicalc = new(Config::default());
+
+		update(&mut quicalc, [
+			Message::ShowWindow(Some(QuicalcMode::Cron)),
+			Message::HideWindow,
+		]);
+		assert!(quicalc.hidden);
+		assert_eq!(quicalc.mode, QuicalcMode::Kalk);
+	}
+
+	#[test]
+	fn hiding_keeps_the_mode_and_input() {
+		let mut quicalc = new(Config::default());
+
+		update(&mut quicalc, [
+			Message::ShowWindow(Some(QuicalcMode::Cron)),
+			Message::InputChanged("0 9 * * *".to_string()),
+			Message::HideWindow,
+		]);
+		assert!(quicalc.hidden);
+		assert_eq!(quicalc.mode, QuicalcMode::Cron);
+		assert_eq!(quicalc.input, "0 9 * * *");
+
+		update(&mut quicalc, [Message::ShowWindow(None)]);
+		assert_eq!(quicalc.mode, QuicalcMode::Cron);
+		assert_eq!(quicalc.input, "0 9 * * *");
+	}
+
+	#[test]
+	fn hiding_clears_the_input_if_configured() {
+		let mut config = Config::default();
+		config.hide.clear_input = true;
+		let mut quicalc = new(config);
+
+		update(&mut quicalc, [
+			Message::ShowWindow(Some(QuicalcMode::Cron)),
+			Message::InputChanged("0 9 * * *".to_string()),
+			Message::HideWindow,
+		]);
+		assert_eq!(quicalc.mode, QuicalcMode::Kalk);
+		assert!(quicalc.input.is_empty());
+	}
+
+	#[test]
+	fn hiding_resets_kalk_if_configured() {
+		let mut quicalc = new(Config::default());
+		quicalc.session.push_kalk("x = 1");
+		let context = quicalc.context.load(Ordering::SeqCst);
+
+		update(&mut quicalc, [
+			Message::ShowWindow(None),
+			Message::HideWindow,
+		]);
+		assert!(quicalc.session.kalk.is_empty());
+		assert!(quicalc.context.load(Ordering::SeqCst) > context);
+
+		let mut config = Config::default();
+		config.hide.reset_kalk = false;
+		let mut quicalc = new(config);
+		quicalc.session.push_kalk("x = 1");
+
+		update(&mut quicalc, [
+			Message::ShowWindow(None),
+			Message::HideWindow,
+		]);
+		assert_eq!(quicalc.session.kalk, ["x = 1"]);
+	}
+
+	#[test]
+	fn defining_a_variable_outdates_cached_results() {
+		let mut quicalc = new(Config::default());
+
+		update(&mut quicalc, [
+			Message::InputChanged("x".to_string()),
+			Message::Evaluate,
+		]);
+		update(&mut quicalc, [Message::EvalCompleted {
+			generation: quicalc.generation,
+			result: None,
+		}]);
+
+		update(&mut quicalc, [
+			Message::InputChanged("x = 3".to_string()),
+			Message::Evaluate,
+		]);
+		update(&mut quicalc, [
+			Message::InputChanged("x".to_string()),
+			Message::Evaluate,
+		]);
+		assert!(quicalc.running_eval.is_some());
+	}
+
+	proptest! {
+		#[test]
+		fn parse_any_command(input in any::<String>()) {
+			let config = Config::default();
+
+			for &mode in QuicalcMode::ALL {
+				let _ = Command::parse(&input, mode, &config);
+			}
+		}
+
+		/// Each mode parses on its own thread, so one that never ends fails the
+		/// test instead of hanging it
+		#[test]
+		fn any_input_parses_quickly(input in any::<String>()) {
+			for &mode in QuicalcMode::ALL {
+				let (sender, receiver) = mpsc::channel();
+				let input = input.clone();
+
+				thread::spawn(move || sender.send(mode.parses(&input)));
+
+				prop_assert!(receiver.recv_timeout(Duration::from_secs(1)).is_ok(), "{mode:?}");
+			}
+		}
+	}
 }
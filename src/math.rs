@@ -0,0 +1,43 @@
+use kalk::parser::{Context, eval};
+use tracing::debug;
+
+use crate::percent::Phrase;
+
+/// Evaluate math with kalk, including phrasings about percentages like
+/// `20% of 50` that kalk doesn't understand itself
+pub fn evaluate(ctx: &mut Context, input: &str) -> Option<String> {
+	let mut evaluate = |input: &str| {
+		eval(ctx, input)
+			.inspect_err(|err| debug!(?err, "error evaluating math"))
+			.ok()
+			.flatten()
+			.map(|res| res.to_string())
+	};
+
+	match Phrase::parse(input) {
+		Some(phrase) => phrase.eval(evaluate),
+		None => evaluate(input),
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use std::{sync::mpsc, thread, time::Duration};
+
+	use proptest::prelude::*;
+
+	use super::*;
+
+	proptest! {
+		/// Evaluations run on their own thread, so one that doesn't end would
+		/// keep it (and the context's lock) forever
+		#[test]
+		fn evaluate_any_input_quickly(input in any::<String>()) {
+			let (sender, receiver) = mpsc::channel();
+
+			thread::spawn(move || sender.send(evaluate(&mut Context::default(), &input)));
+
+			prop_assert!(receiver.recv_timeout(Duration::from_secs(1)).is_ok());
+		}
+	}
+}
@@ -0,0 +1,52 @@
+use std::{fs, path::PathBuf};
+
+use iced::{
+	Element,
+	widget::{column, text},
+};
+use tracing::{error, info};
+
+use crate::{Message, QuicalcMode, hotkey, locale::tr, paths};
+
+fn marker_path() -> Option<PathBuf> {
+	paths::data().map(|dir| dir.join("onboarded"))
+}
+
+pub fn is_first_run() -> bool {
+	marker_path().is_some_and(|path| !path.exists())
+}
+
+pub fn complete() {
+	let Some(path) = marker_path() else {
+		return;
+	};
+
+	path.parent()
+		.map_or(Ok(()), fs::create_dir_all)
+		.and_then(|_| fs::write(&path, ""))
+		.inspect(|_| info!("completed onboarding"))
+		.inspect_err(|err| error!(?err, ?path, "error saving onboarding state"))
+		.ok();
+}
+
+pub fn view<'a>() -> Element<'a, Message> {
+	let modes = if cfg!(feature = "python") {
+		tr!(
+			"onboarding-modes",
+			python = QuicalcMode::PYTHON_COMMAND,
+			kalk = QuicalcMode::KALK_COMMAND,
+		)
+	} else {
+		tr!("onboarding-math")
+	};
+
+	column![
+		text(tr!(
+			"onboarding-hotkey",
+			hotkey = hotkey::active().to_string()
+		))
+		.size(16),
+		text(modes).size(16),
+	]
+	.into()
+}
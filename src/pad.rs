@@ -0,0 +1,101 @@
+use kalk::parser::{Context, eval};
+use tracing::debug;
+
+/// Words that stand for the sum of the results above them, up to the previous
+/// empty line
+const TOTAL_KEYWORDS: [&str; 2] = ["total", "sum"];
+
+/// Evaluate each line on its own, with variables and functions shared between
+/// the lines
+pub fn eval_lines(input: &str) -> Vec<Option<String>> {
+	let mut ctx = Context::default();
+	let mut block = Vec::new();
+
+	input
+		.lines()
+		.map(|line| {
+			let line = line.trim();
+
+			if line.is_empty() {
+				block.clear();
+				return None;
+			}
+
+			if line.starts_with('#') || line.starts_with("//") {
+				return None;
+			}
+
+			let total = if block.is_empty() {
+				"0".to_string()
+			} else {
+				format!("({})", block.join(" + "))
+			};
+			let (line, is_total) = replace_totals(line, &total);
+
+			let result = eval(&mut ctx, &line)
+				.inspect_err(|err| debug!(?err, line, "error evaluating pad line"))
+				.ok()
+				.flatten()
+				.map(|res| res.to_string());
+
+			// totals would otherwise count the lines above them twice
+			if !is_total && let Some(result) = &result {
+				block.push(format!("({result})"));
+			}
+
+			result
+		})
+		.collect()
+}
+
+/// Replace the total keywords in a line, unless they're used as a function
+/// (like kalk's `sum`)
+pub fn replace_totals(line: &str, total: &str) -> (String, bool) {
+	let mut replaced = String::with_capacity(line.len());
+	let mut found = false;
+	let mut rest = line;
+
+	while let Some(start) = rest.find(char::is_alphabetic) {
+		let (before, word) = rest.split_at(start);
+		let end = word
+			.find(|c: char| !c.is_alphanumeric() && c != '_')
+			.unwrap_or(word.len());
+		let (word, after) = word.split_at(end);
+
+		replaced.push_str(before);
+
+		if TOTAL_KEYWORDS.contains(&word.to_lowercase().as_str())
+			&& !after.trim_start().starts_with('(')
+		{
+			replaced.push_str(total);
+			found = true;
+		} else {
+			replaced.push_str(word);
+		}
+
+		rest = after;
+	}
+
+	replaced.push_str(rest);
+	(replaced, found)
+}
+
+#[cfg(test)]
+mod tests {
+	use std::{sync::mpsc, thread, time::Duration};
+
+	use proptest::prelude::*;
+
+	use super::*;
+
+	proptest! {
+		#[test]
+		fn eval_any_lines_quickly(input in any::<String>()) {
+			let (sender, receiver) = mpsc::channel();
+
+			thread::spawn(move || sender.send(eval_lines(&input)));
+
+			prop_assert!(receiver.recv_timeout(Duration::from_secs(1)).is_ok());
+		}
+	}
+}
@@ -0,0 +1,25 @@
+/// Find the arithmetic in text like "the total is 12*4.5 plus shipping 7.95",
+/// and add it all up
+///
+/// Returns `None` if there's no arithmetic, or nothing besides it.
+pub fn extract_math(text: &str) -> Option<String> {
+	let parts = text
+		.split(|c: char| !c.is_ascii_digit() && !"+-*/^().×÷ ".contains(c))
+		.map(|part| {
+			part.trim_start_matches(|c: char| c.is_whitespace() || "+*/^)×÷".contains(c))
+				.trim_end_matches(|c: char| c.is_whitespace() || "+-*/^(.×÷".contains(c))
+		})
+		.filter(|part| part.chars().any(|c| c.is_ascii_digit()))
+		.map(|part| {
+			// parentheses around a number and some words can't be kept
+			if part.matches('(').count() == part.matches(')').count() {
+				part.to_string()
+			} else {
+				part.replace(['(', ')'], "")
+			}
+		})
+		.collect::<Vec<_>>();
+
+	let math = parts.join(" + ");
+	(!parts.is_empty() && math != text.trim()).then_some(math)
+}
@@ -0,0 +1,112 @@
+use std::{fs, path::PathBuf, sync::OnceLock};
+
+use tracing::info;
+
+static PROFILE: OnceLock<String> = OnceLock::new();
+
+/// Keep the files of the named profile separate from the default ones
+pub fn set_profile(name: String) {
+	info!(profile = %name, "using profile");
+	PROFILE.set(name).ok();
+}
+
+pub fn profile() -> Option<&'static str> {
+	PROFILE.get().map(String::as_str)
+}
+
+/// Make sure that the current profile exists, creating it if `create` is set,
+/// so that a mistyped name doesn't start an empty profile
+pub fn init_profile(create: bool) -> Result<(), String> {
+	let Some(name) = profile() else {
+		return Ok(());
+	};
+
+	let dir = config().ok_or("no config directory")?;
+
+	if dir.is_dir() {
+		return Ok(());
+	}
+
+	if !create {
+		return Err(format!(
+			"profile {name} doesn't exist, use --new-profile {name} to create it"
+		));
+	}
+
+	fs::create_dir_all(&dir).map_err(|e| e.to_string())?;
+	info!(profile = %name, dir = %dir.display(), "created profile");
+
+	Ok(())
+}
+
+/// Whether a profile name can be used as a directory name everywhere
+pub fn is_valid_profile(name: &str) -> bool {
+	!name.is_empty()
+		&& name
+			.chars()
+			.all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+}
+
+/// Quicalc's directory in one of the platform's base directories, or the
+/// current profile's directory in it
+fn app_dir(base: Option<PathBuf>) -> Option<PathBuf> {
+	let dir = base?.join("quicalc");
+
+	Some(match profile() {
+		Some(profile) => dir.join("profiles").join(profile),
+		None => dir,
+	})
+}
+
+/// Settings, like the config file
+///
+/// This is `~/.config/quicalc` on Linux, `%APPDATA%\quicalc` on Windows, and
+/// `~/Library/Application Support/quicalc` on macOS.
+pub fn config() -> Option<PathBuf> {
+	app_dir(dirs::config_dir())
+}
+
+/// Data that would be missed if it was deleted, like history
+///
+/// This is `~/.local/share/quicalc` on Linux, `%LOCALAPPDATA%\quicalc` on
+/// Windows, and `~/Library/Application Support/quicalc` on macOS.
+pub fn data() -> Option<PathBuf> {
+	app_dir(dirs::data_local_dir())
+}
+
+/// Data that can be recreated, like cached results
+///
+/// This is `~/.cache/quicalc` on Linux, `%LOCALAPPDATA%\quicalc` on Windows,
+/// and `~/Library/Caches/quicalc` on macOS.
+pub fn cache() -> Option<PathBuf> {
+	app_dir(dirs::cache_dir())
+}
+
+/// Log files
+///
+/// This is `~/.local/state/quicalc/logs` on Linux,
+/// `%LOCALAPPDATA%\quicalc\logs` on Windows, and `~/Library/Logs/quicalc` on
+/// macOS.
+pub fn logs() -> Option<PathBuf> {
+	if cfg!(target_os = "macos") {
+		app_dir(dirs::home_dir().map(|dir| dir.join("Library").join("Logs")))
+	} else {
+		app_dir(dirs::state_dir().or_else(dirs::data_local_dir)).map(|dir| dir.join("logs"))
+	}
+}
+
+/// Plugins extending quicalc
+pub fn plugins() -> Option<PathBuf> {
+	data().map(|dir| dir.join("plugins"))
+}
+
+/// All of the directories, by name
+pub fn all() -> [(&'static str, Option<PathBuf>); 5] {
+	[
+		("config", config()),
+		("data", data()),
+		("cache", cache()),
+		("logs", logs()),
+		("plugins", plugins()),
+	]
+}
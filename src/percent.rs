@@ -0,0 +1,150 @@
+use crate::locale::tr;
+
+/// A phrasing about percentages that kalk can't parse, made of kalk
+/// expressions
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Phrase {
+	/// The change from one value to another, like `120 -> 150`
+	Change { from: String, to: String },
+	/// One value as a percentage of another, like `what is 150 as % of 600`
+	Share { part: String, whole: String },
+}
+
+impl Phrase {
+	pub fn parse(input: &str) -> Option<Self> {
+		let input = input.trim();
+
+		if let Some((from, to)) = input.split_once("->").or_else(|| input.split_once('→')) {
+			return Some(Self::Change {
+				from: expression(from)?,
+				to: expression(to)?,
+			});
+		}
+
+		let lowercase = input.to_ascii_lowercase();
+		let start = lowercase
+			.strip_prefix("what is ")
+			.map_or(0, |_| "what is ".len());
+
+		for separator in [" as % of ", " as a percentage of ", " as percent of "] {
+			if let Some(index) = lowercase.find(separator) {
+				return Some(Self::Share {
+					// the separator can overlap the prefix, like in `what is as % of 600`
+					part: expression(input.get(start..index)?)?,
+					whole: expression(input[index + separator.len()..].trim_end_matches('?'))?,
+				});
+			}
+		}
+
+		None
+	}
+
+	/// Evaluate the phrase's expressions with kalk, and describe the results
+	pub fn eval(&self, mut eval: impl FnMut(&str) -> Option<String>) -> Option<String> {
+		match self {
+			Self::Change { from, to } => {
+				let change = eval(&format!("({to}) - ({from})"))?;
+				let percent = eval(&format!("(({to}) - ({from})) / abs({from}) * 100"))?;
+
+				Some(tr!(
+					"percent-change",
+					change = signed(change),
+					percent = signed(percent)
+				))
+			}
+			Self::Share { part, whole } => {
+				let percent = eval(&format!("({part}) / ({whole}) * 100"))?;
+
+				Some(tr!("percent-share", percent = percent))
+			}
+		}
+	}
+}
+
+/// One side of a phrase, which has to be there
+fn expression(text: &str) -> Option<String> {
+	let text = text.trim();
+	(!text.is_empty()).then(|| text.to_string())
+}
+
+/// A result with a `+` in front unless it's negative
+fn signed(result: String) -> String {
+	if result.starts_with('-') {
+		result
+	} else {
+		format!("+{result}")
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use proptest::prelude::*;
+
+	use super::*;
+
+	#[test]
+	fn parse_change() {
+		assert_eq!(
+			Phrase::parse("120 -> 150"),
+			Some(Phrase::Change {
+				from: "120".to_string(),
+				to: "150".to_string(),
+			})
+		);
+		assert_eq!(
+			Phrase::parse("2^3 → 10"),
+			Some(Phrase::Change {
+				from: "2^3".to_string(),
+				to: "10".to_string(),
+			})
+		);
+		assert_eq!(Phrase::parse("-> 150"), None);
+	}
+
+	#[test]
+	fn parse_share() {
+		assert_eq!(
+			Phrase::parse("What is 150 as % of 600?"),
+			Some(Phrase::Share {
+				part: "150".to_string(),
+				whole: "600".to_string(),
+			})
+		);
+		assert_eq!(
+			Phrase::parse("3 as a percentage of 4"),
+			Some(Phrase::Share {
+				part: "3".to_string(),
+				whole: "4".to_string(),
+			})
+		);
+		assert_eq!(Phrase::parse("as percent of 4"), None);
+	}
+
+	#[test]
+	fn parse_share_without_part() {
+		assert_eq!(Phrase::parse("what is as % of 600"), None);
+		assert_eq!(Phrase::parse("what is  as % of 600"), None);
+		assert_eq!(Phrase::parse("what is as a percentage of 600?"), None);
+	}
+
+	proptest! {
+		#[test]
+		fn parse_any_input(input in any::<String>()) {
+			let _ = Phrase::parse(&input);
+		}
+
+		#[test]
+		fn parse_any_share(
+			input in "([Ww]hat [Ii]s)? ?.{0,8} ?(as % of|as a percentage of|as percent of) ?.{0,8}\\??"
+		) {
+			if let Some(Phrase::Share { part, whole }) = Phrase::parse(&input) {
+				prop_assert!(!part.is_empty() && !whole.is_empty());
+			}
+		}
+
+		#[test]
+		fn parse_any_change(from in ".{0,8}", arrow in "->|→", to in ".{0,8}") {
+			let _ = Phrase::parse(&format!("{from}{arrow}{to}"));
+		}
+	}
+}
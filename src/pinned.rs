@@ -0,0 +1,47 @@
+use iced::{
+	Color, Element, Length, Size,
+	widget::{column, text},
+	window::{Level, Settings as WindowSettings},
+};
+
+use crate::Message;
+
+/// A calculation shown in its own window, which stays open while the main
+/// window is shown and hidden
+#[derive(Debug, Clone)]
+pub struct Pinned {
+	pub input: String,
+	pub result: String,
+	pub accent: Color,
+	/// Whether the window stays above other windows
+	pub on_top: bool,
+}
+
+impl Pinned {
+	const WINDOW_SIZE: Size = Size::new(360.0, 120.0);
+
+	pub fn settings() -> WindowSettings {
+		WindowSettings {
+			size: Self::WINDOW_SIZE,
+			min_size: Some(Size::new(160.0, 80.0)),
+			level: Level::AlwaysOnTop,
+			exit_on_close_request: false,
+			..WindowSettings::default()
+		}
+	}
+
+	pub fn title(&self) -> String {
+		format!("{} – Quicalc", self.input)
+	}
+
+	pub fn view(&self) -> Element<'_, Message> {
+		column![
+			text(&self.input).size(14).style(text::secondary),
+			text(&self.result).size(28).color(self.accent),
+		]
+		.spacing(4)
+		.padding(8)
+		.width(Length::Fill)
+		.into()
+	}
+}
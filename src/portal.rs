@@ -0,0 +1,58 @@
+use std::env;
+
+use ashpd::desktop::global_shortcuts::{GlobalShortcuts, NewShortcut};
+use iced::{
+	futures::{SinkExt, Stream, StreamExt, channel::mpsc::Sender},
+	stream,
+};
+use tracing::{debug, error, info};
+
+use crate::{Message, locale::tr};
+
+const SHOW_SHORTCUT_ID: &str = "show";
+const SHOW_SHORTCUT_TRIGGER: &str = "ALT+Return";
+
+pub fn is_wayland() -> bool {
+	env::var_os("WAYLAND_DISPLAY").is_some()
+		|| env::var("XDG_SESSION_TYPE").is_ok_and(|session| session == "wayland")
+}
+
+pub fn shortcuts() -> impl Stream<Item = Message> {
+	stream::channel(0, async move |mut sender| {
+		if let Err(err) = listen(&mut sender).await {
+			error!(
+				?err,
+				"error listening for global shortcuts from the desktop portal"
+			);
+		}
+	})
+}
+
+async fn listen(sender: &mut Sender<Message>) -> Result<(), ashpd::Error> {
+	let portal = GlobalShortcuts::new().await?;
+	let session = portal.create_session().await?;
+
+	let shortcut = NewShortcut::new(SHOW_SHORTCUT_ID, tr!("portal-shortcut"))
+		.preferred_trigger(SHOW_SHORTCUT_TRIGGER);
+
+	let bound = portal
+		.bind_shortcuts(&session, &[shortcut], None)
+		.await?
+		.response()?;
+
+	info!(shortcuts = ?bound.shortcuts(), "bound global shortcuts via the desktop portal");
+
+	let mut activated = portal.receive_activated().await?;
+
+	while let Some(event) = activated.next().await {
+		debug!(?event, "new global shortcut activation");
+
+		if event.shortcut_id() == SHOW_SHORTCUT_ID
+			&& let Err(err) = sender.send(Message::ShowWindow(None)).await
+		{
+			error!("error processing global shortcut: {err:?}");
+		}
+	}
+
+	Ok(())
+}
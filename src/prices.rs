@@ -0,0 +1,72 @@
+use std::time::Duration;
+
+use serde_json::Value;
+use tracing::debug;
+
+use crate::{
+	config::{PriceConfig, PriceSource},
+	locale::tr,
+	queries,
+};
+
+/// How long a fetched price is used before it's fetched again
+pub const MAX_AGE: Duration = Duration::from_secs(60);
+/// What's replaced by the symbol in a price source's URL
+const PLACEHOLDER: &str = "{symbol}";
+const USER_AGENT: &str = concat!("quicalc/", env!("CARGO_PKG_VERSION"));
+
+/// Split an input like `btc * 0.05` into the uppercase symbol and the math
+/// that follows it
+pub fn split(input: &str) -> Option<(String, &str)> {
+	let input = input.trim_start();
+	let end = input
+		.find(|c: char| !(c.is_ascii_alphanumeric() || matches!(c, '.' | '-' | '^')))
+		.unwrap_or(input.len());
+	let (symbol, math) = input.split_at(end);
+
+	// symbols start with a letter, except for indices like `^GSPC`
+	symbol
+		.starts_with(|c: char| c.is_ascii_alphabetic() || c == '^')
+		.then(|| (symbol.to_ascii_uppercase(), math))
+}
+
+/// Fetch the price of a symbol from the first source that has it
+pub async fn fetch(config: PriceConfig, symbol: String) -> Result<f64, String> {
+	tokio::task::spawn_blocking(move || {
+		for source in &config.sources {
+			match quote(source, &symbol) {
+				Ok(price) => {
+					debug!(%symbol, url = %source.url, price, "fetched price");
+					return Ok(price);
+				}
+				Err(err) => debug!(%err, %symbol, url = %source.url, "no price from source"),
+			}
+		}
+
+		Err(tr!("price-unknown", symbol = symbol.as_str()))
+	})
+	.await
+	.map_err(|e| e.to_string())?
+}
+
+fn quote(source: &PriceSource, symbol: &str) -> Result<f64, String> {
+	let url = source.url.replace(PLACEHOLDER, &queries::encode(symbol));
+
+	let response: Value = ureq::get(&url)
+		.header("User-Agent", USER_AGENT)
+		.call()
+		.map_err(|e| e.to_string())?
+		.into_body()
+		.read_json()
+		.map_err(|e| e.to_string())?;
+
+	let value = response
+		.pointer(&source.pointer.replace(PLACEHOLDER, symbol))
+		.ok_or("no price in the response")?;
+
+	// some APIs give prices as strings, to keep them exact
+	value
+		.as_f64()
+		.or_else(|| value.as_str()?.parse().ok())
+		.ok_or_else(|| format!("not a price: {value}"))
+}
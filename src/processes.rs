@@ -0,0 +1,97 @@
+use std::thread;
+
+use sysinfo::{MINIMUM_CPU_UPDATE_INTERVAL, Pid, ProcessRefreshKind, ProcessesToUpdate, System};
+
+use crate::{fuzzy::Searchable, locale::tr};
+
+/// A running process, as it was when the processes were listed
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProcessInfo {
+	pub pid: u32,
+	pub name: String,
+	/// CPU usage in percent of one core
+	pub cpu: f32,
+	/// Memory usage in bytes
+	pub memory: u64,
+}
+
+impl Searchable for ProcessInfo {
+	type Id = u32;
+
+	fn id(&self) -> u32 {
+		self.pid
+	}
+
+	fn text(&self) -> String {
+		self.name.clone()
+	}
+
+	/// The process's name with its PID, CPU usage, and memory usage
+	fn summary(&self) -> String {
+		tr!(
+			"kill-process",
+			name = self.name.as_str(),
+			pid = self.pid,
+			cpu = format!("{:.1}", self.cpu),
+			memory = format_memory(self.memory),
+		)
+	}
+}
+
+/// The running processes besides quicalc, busiest first, measuring their CPU
+/// usage over a short while
+pub fn list() -> Vec<ProcessInfo> {
+	let refresh = ProcessRefreshKind::nothing().with_cpu().with_memory();
+	let mut system = System::new();
+
+	// CPU usage is measured between two refreshes
+	system.refresh_processes_specifics(ProcessesToUpdate::All, true, refresh);
+	thread::sleep(MINIMUM_CPU_UPDATE_INTERVAL);
+	system.refresh_processes_specifics(ProcessesToUpdate::All, true, refresh);
+
+	let own = sysinfo::get_current_pid().ok();
+
+	let mut processes = system
+		.processes()
+		.values()
+		.filter(|process| Some(process.pid()) != own && process.thread_kind().is_none())
+		.map(|process| ProcessInfo {
+			pid: process.pid().as_u32(),
+			name: process.name().to_string_lossy().into_owned(),
+			cpu: process.cpu_usage(),
+			memory: process.memory(),
+		})
+		.collect::<Vec<_>>();
+
+	processes.sort_by(|a, b| b.cpu.total_cmp(&a.cpu));
+	processes
+}
+
+/// Terminate a process, if it's still running
+pub fn kill(pid: u32) -> Result<(), String> {
+	let pid = Pid::from_u32(pid);
+	let mut system = System::new();
+	system.refresh_processes(ProcessesToUpdate::Some(&[pid]), true);
+
+	let process = system
+		.process(pid)
+		.ok_or("the process is no longer running")?;
+
+	if process.kill() {
+		Ok(())
+	} else {
+		Err("the process could not be terminated".to_string())
+	}
+}
+
+/// Format a number of bytes like a task manager would
+fn format_memory(bytes: u64) -> String {
+	const MIB: f64 = 1024.0 * 1024.0;
+	let mib = bytes as f64 / MIB;
+
+	if mib < 1024.0 {
+		format!("{mib:.0} MB")
+	} else {
+		format!("{:.1} GB", mib / 1024.0)
+	}
+}
@@ -0,0 +1,595 @@
+#[cfg(feature = "python-embedded")]
+mod embedded;
+mod subprocess;
+
+use std::{
+	io::Cursor,
+	mem,
+	sync::{
+		Arc, LazyLock, OnceLock,
+		atomic::{AtomicU64, Ordering},
+	},
+	thread,
+	time::{Duration, Instant},
+};
+
+use crossbeam_channel::{Receiver, RecvTimeoutError, Sender};
+use iced::{
+	futures::{Stream, channel::mpsc},
+	widget::image::Handle,
+};
+use image::{ImageFormat, ImageReader};
+use tracing::{debug, error, warn};
+
+use crate::config::{PythonBackend, PythonConfig};
+
+/// Forgets all variables, like restarting the interpreter
+pub const RESET_COMMAND: &str = "reset";
+
+const POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+/// The helper script rendering figures, which renders nothing without plots
+#[cfg(feature = "plots")]
+const FIGURE_SCRIPT: &str = include_str!("python/figure.py");
+#[cfg(not(feature = "plots"))]
+const FIGURE_SCRIPT: &str = "def figure_png(result):\n    return None\n";
+/// The helper script copying the globals that previews are evaluated in
+const PREVIEW_SCRIPT: &str = include_str!("python/preview.py");
+
+static EVENTS: LazyLock<(Sender<Event>, Receiver<Event>)> =
+	LazyLock::new(crossbeam_channel::unbounded);
+static VERSION: OnceLock<String> = OnceLock::new();
+
+/// Stops a running evaluation, returning `false` if it should be retried later
+type Interrupt = Arc<dyn Fn() -> bool + Send + Sync>;
+
+/// What the worker reports back
+#[derive(Debug, Clone)]
+pub enum Event {
+	/// The interpreter started, and its version is known
+	Ready,
+	/// The result of the job with the id
+	Evaluated(u64, Evaluation),
+}
+
+#[derive(Debug, Clone)]
+pub enum Evaluation {
+	/// The result of an expression, anything it printed, and a figure it drew
+	Expression {
+		result: Option<String>,
+		output: String,
+		figure: Option<Figure>,
+		error: Option<Exception>,
+	},
+	/// Anything printed and drawn by code that was run, and its result if it
+	/// was an expression
+	Statements {
+		result: Option<String>,
+		output: String,
+		figure: Option<Figure>,
+		error: Option<Exception>,
+	},
+	/// Possible completions of the name or attribute at the end of the input
+	Completions(Vec<String>),
+	TimedOut,
+}
+
+/// An exception raised by the evaluated code
+#[derive(Debug, Clone)]
+pub struct Exception {
+	/// The type and message of the exception, like `TypeError: ...`
+	pub summary: String,
+	pub traceback: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct Figure {
+	pub handle: Handle,
+	pub width: u32,
+	pub height: u32,
+}
+
+impl Figure {
+	fn from_png(png: Vec<u8>) -> Option<Self> {
+		let (width, height) = ImageReader::with_format(Cursor::new(&png), ImageFormat::Png)
+			.into_dimensions()
+			.inspect_err(|err| warn!(?err, "invalid figure"))
+			.ok()?;
+
+		Some(Self {
+			handle: Handle::from_bytes(png),
+			width,
+			height,
+		})
+	}
+}
+
+/// What a backend produced when evaluating some code
+#[derive(Debug, Default)]
+struct Outcome {
+	result: Option<String>,
+	output: String,
+	/// A PNG image
+	figure: Option<Vec<u8>>,
+	error: Option<Exception>,
+}
+
+#[derive(Debug)]
+enum Job {
+	Eval(String),
+	Run(String),
+	#[cfg(feature = "sym")]
+	Symbolic(String),
+	Complete(String),
+	Reset,
+}
+
+enum Progress {
+	Started {
+		id: u64,
+		cancellable: bool,
+		interrupt: Interrupt,
+	},
+	Finished,
+}
+
+struct Running {
+	id: u64,
+	/// Whether the job can be cancelled once a newer one is queued
+	cancellable: bool,
+	interrupt: Interrupt,
+	deadline: Instant,
+	timed_out: bool,
+	interrupted: bool,
+}
+
+#[derive(Debug)]
+enum Backend {
+	#[cfg(feature = "python-embedded")]
+	Embedded(embedded::Session),
+	Subprocess(subprocess::Subprocess),
+}
+
+impl Backend {
+	fn new(config: &PythonConfig) -> Self {
+		// restrictions can only be enforced in a separate process
+		if config.restricted {
+			return Self::Subprocess(subprocess::Subprocess::new(config));
+		}
+
+		match config.backend {
+			#[cfg(feature = "python-embedded")]
+			PythonBackend::Embedded => Self::Embedded(embedded::Session::new(config)),
+			#[cfg(not(feature = "python-embedded"))]
+			PythonBackend::Embedded => {
+				warn!("embedded python is not available in this build, using a subprocess instead");
+				Self::Subprocess(subprocess::Subprocess::new(config))
+			}
+			PythonBackend::Subprocess => Self::Subprocess(subprocess::Subprocess::new(config)),
+		}
+	}
+
+	fn version(&self) -> Option<String> {
+		match self {
+			#[cfg(feature = "python-embedded")]
+			Self::Embedded(session) => Some(session.version()),
+			Self::Subprocess(subprocess) => subprocess.version(),
+		}
+	}
+
+	fn interrupter(&self) -> Interrupt {
+		match self {
+			#[cfg(feature = "python-embedded")]
+			Self::Embedded(session) => session.interrupter(),
+			Self::Subprocess(subprocess) => subprocess.interrupter(),
+		}
+	}
+
+	/// Whether stale evaluations can be interrupted without losing the session
+	fn is_cancellable(&self) -> bool {
+		match self {
+			#[cfg(feature = "python-embedded")]
+			Self::Embedded(_) => true,
+			Self::Subprocess(_) => false,
+		}
+	}
+
+	fn eval(&mut self, input: &str) -> Outcome {
+		match self {
+			#[cfg(feature = "python-embedded")]
+			Self::Embedded(session) => session.eval(input),
+			Self::Subprocess(subprocess) => subprocess.eval(input),
+		}
+	}
+
+	fn run(&mut self, input: &str) -> Option<Outcome> {
+		match self {
+			#[cfg(feature = "python-embedded")]
+			Self::Embedded(session) => session.run(input),
+			Self::Subprocess(subprocess) => subprocess.run(input),
+		}
+	}
+
+	#[cfg(feature = "sym")]
+	fn symbolic(&mut self, input: &str) -> Outcome {
+		match self {
+			#[cfg(feature = "python-embedded")]
+			Self::Embedded(session) => session.symbolic(input),
+			Self::Subprocess(subprocess) => subprocess.symbolic(input),
+		}
+	}
+
+	fn complete(&mut self, word: &str) -> Vec<String> {
+		match self {
+			#[cfg(feature = "python-embedded")]
+			Self::Embedded(session) => session.complete(word),
+			Self::Subprocess(subprocess) => subprocess.complete(word),
+		}
+	}
+
+	fn reset(&mut self) {
+		match self {
+			#[cfg(feature = "python-embedded")]
+			Self::Embedded(session) => session.reset(),
+			Self::Subprocess(subprocess) => subprocess.reset(),
+		}
+	}
+}
+
+/// Evaluates Python on a separate thread, so that slow code doesn't block the
+/// UI
+///
+/// Results are sent to [`evaluations`] along with the id returned when queueing
+/// the job. The interpreter is only initialized once the first job is queued or
+/// the worker is started, so sessions that never use Python don't pay for it.
+#[derive(Debug, Default)]
+pub struct Worker {
+	config: PythonConfig,
+	latest: Arc<AtomicU64>,
+	jobs: Option<Sender<(u64, Job)>>,
+	/// Code from an earlier session, run as soon as the interpreter starts
+	session: Vec<String>,
+}
+
+impl Worker {
+	pub fn new(config: PythonConfig) -> Self {
+		Self {
+			config,
+			..Self::default()
+		}
+	}
+
+	/// Run the code from an earlier session, once the interpreter is started
+	/// if it isn't yet
+	pub fn restore(&mut self, session: Vec<String>) {
+		if self.is_started() {
+			for code in session {
+				self.send(Job::Run(code));
+			}
+		} else {
+			self.session = session;
+		}
+	}
+
+	/// Start the interpreter ahead of the first evaluation
+	pub fn start(&mut self) {
+		self.jobs();
+	}
+
+	pub fn is_started(&self) -> bool {
+		self.jobs.is_some()
+	}
+
+	pub fn eval(&mut self, input: &str) -> u64 {
+		self.send(Job::Eval(input.to_string()))
+	}
+
+	pub fn run(&mut self, input: &str) -> u64 {
+		self.send(Job::Run(input.to_string()))
+	}
+
+	/// Simplify an expression or solve an equation with SymPy
+	#[cfg(feature = "sym")]
+	pub fn symbolic(&mut self, input: &str) -> u64 {
+		self.send(Job::Symbolic(input.to_string()))
+	}
+
+	/// Complete a name, or an attribute after a `.`
+	pub fn complete(&mut self, word: &str) -> u64 {
+		self.send(Job::Complete(word.to_string()))
+	}
+
+	/// Forget all variables and re-run the configured imports, returning the
+	/// job's id if the interpreter was running
+	pub fn reset(&mut self) -> Option<u64> {
+		self.session.clear();
+
+		// there's nothing to reset if no python code has been run yet
+		self.is_started().then(|| self.send(Job::Reset))
+	}
+
+	fn send(&mut self, job: Job) -> u64 {
+		let id = self.latest.fetch_add(1, Ordering::SeqCst) + 1;
+
+		self.jobs()
+			.send((id, job))
+			.inspect_err(|err| error!(?err, "error sending job to the python worker"))
+			.ok();
+
+		id
+	}
+
+	fn jobs(&mut self) -> &Sender<(u64, Job)> {
+		self.jobs.get_or_insert_with(|| {
+			let jobs = spawn(self.config.clone(), self.latest.clone());
+
+			// the session is restored before anything else runs
+			for code in mem::take(&mut self.session) {
+				let id = self.latest.fetch_add(1, Ordering::SeqCst) + 1;
+
+				jobs.send((id, Job::Run(code)))
+					.inspect_err(|err| error!(?err, "error sending job to the python worker"))
+					.ok();
+			}
+
+			jobs
+		})
+	}
+}
+
+/// Prepare for using Python, this must be called at startup
+#[cfg_attr(not(feature = "python-embedded"), expect(unused_variables))]
+pub fn init(config: &PythonConfig) {
+	#[cfg(feature = "python-embedded")]
+	if config.backend == PythonBackend::Embedded
+		&& let Some(home) = &config.home
+	{
+		embedded::set_home(home);
+	}
+}
+
+/// The name or attribute at the end of the input, which can be completed
+pub fn completion_word(input: &str) -> &str {
+	let start = input
+		.trim_end_matches(|c: char| c.is_alphanumeric() || c == '_' || c == '.')
+		.len();
+
+	&input[start..]
+}
+
+/// The version of the Python interpreter, once it has been started
+pub fn version() -> Option<&'static str> {
+	VERSION.get().map(String::as_str)
+}
+
+/// Events with the results of evaluations
+///
+/// Those are received from a blocking channel, so they are forwarded from a
+/// separate thread instead of blocking the async executor.
+pub fn evaluations() -> impl Stream<Item = Event> {
+	let (sender, receiver) = mpsc::unbounded();
+
+	thread::Builder::new()
+		.name("python-results".to_string())
+		.spawn(move || {
+			for event in EVENTS.1.iter() {
+				if sender.unbounded_send(event).is_err() {
+					debug!("no longer forwarding python evaluations");
+					break;
+				}
+			}
+		})
+		.inspect_err(|err| error!(?err, "error spawning python result forwarding thread"))
+		.ok();
+
+	receiver
+}
+
+fn spawn(config: PythonConfig, latest: Arc<AtomicU64>) -> Sender<(u64, Job)> {
+	let timeout = Duration::from_millis(config.timeout_ms);
+	let (jobs, queue) = crossbeam_channel::unbounded::<(u64, Job)>();
+	let (progress, updates) = crossbeam_channel::unbounded();
+	let timed_out = Arc::new(AtomicU64::new(0));
+
+	let worker = {
+		let latest = latest.clone();
+		let timed_out = timed_out.clone();
+
+		move || {
+			let mut backend = Backend::new(&config);
+
+			if let Some(version) = backend.version() {
+				VERSION.set(version).ok();
+				EVENTS.0.send(Event::Ready).ok();
+			}
+
+			for (id, job) in queue {
+				let is_eval = !matches!(job, Job::Run(_) | Job::Reset);
+
+				// evaluating an expression is pointless once the input has changed again
+				if is_eval && latest.load(Ordering::SeqCst) != id {
+					continue;
+				}
+
+				progress
+					.send(Progress::Started {
+						id,
+						cancellable: is_eval && backend.is_cancellable(),
+						interrupt: backend.interrupter(),
+					})
+					.ok();
+
+				let evaluation = match job {
+					Job::Eval(input) => {
+						let outcome = backend.eval(&input);
+
+						Some(Evaluation::Expression {
+							result: outcome.result,
+							output: outcome.output,
+							figure: outcome.figure.and_then(Figure::from_png),
+							error: outcome.error,
+						})
+					}
+					#[cfg(feature = "sym")]
+					Job::Symbolic(input) => {
+						let outcome = backend.symbolic(&input);
+
+						Some(Evaluation::Expression {
+							result: outcome.result,
+							output: outcome.output,
+							figure: None,
+							error: outcome.error,
+						})
+					}
+					Job::Complete(word) => Some(Evaluation::Completions(backend.complete(&word))),
+					Job::Run(input) => backend.run(&input).map(|outcome| Evaluation::Statements {
+						result: outcome.result,
+						output: outcome.output,
+						figure: outcome.figure.and_then(Figure::from_png),
+						error: outcome.error,
+					}),
+					Job::Reset => {
+						backend.reset();
+						None
+					}
+				};
+
+				progress.send(Progress::Finished).ok();
+
+				if let Some(evaluation) = evaluation
+					&& timed_out.load(Ordering::SeqCst) != id
+				{
+					EVENTS.0.send(Event::Evaluated(id, evaluation)).ok();
+				}
+			}
+		}
+	};
+
+	let supervisor = move || {
+		let mut running: Option<Running> = None;
+
+		loop {
+			let update = if running.is_some() {
+				updates.recv_timeout(POLL_INTERVAL)
+			} else {
+				updates.recv().map_err(|_| RecvTimeoutError::Disconnected)
+			};
+
+			let update = match update {
+				Ok(update) => Some(update),
+				Err(RecvTimeoutError::Timeout) => None,
+				Err(RecvTimeoutError::Disconnected) => break,
+			};
+
+			// only act on the latest state of the worker
+			for update in update.into_iter().chain(updates.try_iter()) {
+				running = match update {
+					Progress::Started {
+						id,
+						cancellable,
+						interrupt,
+					} => Some(Running {
+						id,
+						cancellable,
+						interrupt,
+						deadline: Instant::now() + timeout,
+						timed_out: false,
+						interrupted: false,
+					}),
+					Progress::Finished => None,
+				};
+			}
+
+			let Some(job) = running.as_mut().filter(|job| !job.interrupted) else {
+				continue;
+			};
+
+			if !job.timed_out && Instant::now() >= job.deadline {
+				warn!(id = job.id, ?timeout, "python evaluation timed out");
+				job.timed_out = true;
+				timed_out.store(job.id, Ordering::SeqCst);
+				EVENTS
+					.0
+					.send(Event::Evaluated(job.id, Evaluation::TimedOut))
+					.ok();
+			}
+
+			if job.timed_out || (job.cancellable && latest.load(Ordering::SeqCst) != job.id) {
+				job.interrupted = (job.interrupt)();
+			}
+		}
+	};
+
+	thread::Builder::new()
+		.name("python".to_string())
+		.spawn(worker)
+		.inspect_err(|err| error!(?err, "error spawning python worker"))
+		.ok();
+
+	thread::Builder::new()
+		.name("python-supervisor".to_string())
+		.spawn(supervisor)
+		.inspect_err(|err| error!(?err, "error spawning python supervisor"))
+		.ok();
+
+	jobs
+}
+
+#[cfg(test)]
+mod tests {
+	use std::sync::Mutex;
+
+	use proptest::prelude::*;
+
+	use super::*;
+
+	const TIMEOUT: Duration = Duration::from_secs(1);
+
+	/// A worker shared by all cases, like the one in the UI, started before the
+	/// first case so its evaluation isn't held up by the interpreter starting
+	static WORKER: LazyLock<Mutex<Worker>> = LazyLock::new(|| {
+		let mut worker = Worker::new(PythonConfig {
+			timeout_ms: TIMEOUT.as_millis() as u64,
+			..PythonConfig::default()
+		});
+
+		worker.start();
+		EVENTS.1.recv_timeout(Duration::from_secs(60)).ok();
+		Mutex::new(worker)
+	});
+
+	/// The result of the job with the id, unless the worker didn't send it (or
+	/// that it timed out) soon after the timeout
+	fn evaluated(id: u64) -> Option<Evaluation> {
+		let deadline = Instant::now() + TIMEOUT * 2;
+
+		loop {
+			match EVENTS.1.recv_deadline(deadline).ok()? {
+				Event::Evaluated(evaluated, evaluation) if evaluated == id => {
+					return Some(evaluation);
+				}
+				_ => {}
+			}
+		}
+	}
+
+	proptest! {
+		#[test]
+		fn eval_any_input(input in any::<String>()) {
+			let id = WORKER.lock().unwrap().eval(&input);
+
+			prop_assert!(evaluated(id).is_some());
+		}
+	}
+
+	#[test]
+	#[cfg(feature = "sym")]
+	fn symbolic_errors_are_shown() {
+		let id = WORKER.lock().unwrap().symbolic("x.foo");
+
+		assert!(matches!(
+			evaluated(id),
+			Some(Evaluation::Expression { error: Some(_), .. })
+		));
+	}
+}
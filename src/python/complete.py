@@ -0,0 +1,13 @@
+# Completes Python names and attributes for Quicalc
+
+import rlcompleter
+
+
+def complete(word, namespace):
+    completer = rlcompleter.Completer(namespace)
+    completions = []
+
+    while (completion := completer.complete(word, len(completions))) is not None:
+        completions.append(completion)
+
+    return sorted(set(completions))
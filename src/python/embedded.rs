@@ -0,0 +1,413 @@
+use std::{
+	collections::HashMap,
+	env,
+	ffi::{CStr, CString, c_long},
+	path::Path,
+	ptr,
+	sync::{
+		Arc,
+		atomic::{AtomicU64, Ordering},
+	},
+};
+
+use pyo3::{
+	Bound, Py, PyAny, PyErr, PyResult, Python, PythonVersionInfo,
+	exceptions::PySyntaxError,
+	ffi,
+	types::{
+		PyAnyMethods, PyCode, PyCodeInput, PyCodeMethods, PyDict, PyDictMethods, PyModule,
+		PyTracebackMethods,
+	},
+};
+use tracing::{debug, error, info, warn};
+
+use crate::{
+	config::PythonConfig,
+	python::{Exception, FIGURE_SCRIPT, Interrupt, Outcome, PREVIEW_SCRIPT},
+};
+
+#[cfg(feature = "sym")]
+const SYMBOLIC: &str = include_str!("symbolic.py");
+const COMPLETE: &str = include_str!("complete.py");
+
+/// Use the given Python installation, this must be called before the
+/// interpreter is initialized
+pub fn set_home(home: &Path) {
+	info!(?home, "using python installation");
+
+	// SAFETY: this is called at startup, before any other threads are spawned
+	unsafe { env::set_var("PYTHONHOME", home) };
+}
+
+/// Make a virtualenv's packages available to the interpreter
+fn activate(py: Python<'_>, venv: &Path) -> PyResult<()> {
+	let version = py.version_info();
+	let site_packages = if cfg!(windows) {
+		venv.join("Lib").join("site-packages")
+	} else {
+		venv.join("lib")
+			.join(format!("python{}.{}", version.major, version.minor))
+			.join("site-packages")
+	};
+
+	if !site_packages.is_dir() {
+		warn!(
+			?venv,
+			?site_packages,
+			"virtualenv has no packages for this python version"
+		);
+		return Ok(());
+	}
+
+	PyModule::import(py, "site")?.call_method1("addsitedir", (site_packages,))?;
+	info!(?venv, "activated virtualenv");
+
+	Ok(())
+}
+
+#[derive(Debug)]
+pub struct Session {
+	imports: Vec<String>,
+	globals: Option<Py<PyDict>>,
+	thread: c_long,
+	/// Counts the jobs that have been started, so interrupts only affect the
+	/// intended one
+	jobs: Arc<AtomicU64>,
+	helpers: HashMap<&'static str, Py<PyAny>>,
+}
+
+impl Session {
+	pub fn new(config: &PythonConfig) -> Self {
+		if let Some(venv) = &config.venv {
+			Python::attach(|py| activate(py, venv))
+				.inspect_err(|err| error!(?err, ?venv, "error activating virtualenv"))
+				.ok();
+		}
+
+		// render matplotlib figures without opening windows
+		Python::attach(|py| {
+			PyModule::import(py, "os")?
+				.getattr("environ")?
+				.call_method1("setdefault", ("MPLBACKEND", "Agg"))
+				.map(drop)
+		})
+		.inspect_err(|err| warn!(?err, "error configuring matplotlib"))
+		.ok();
+
+		let thread = Python::attach(|py| {
+			PyModule::import(py, "threading")?
+				.getattr("get_ident")?
+				.call0()?
+				.extract::<u64>()
+		})
+		.inspect_err(|err| error!(?err, "error getting python thread id"))
+		.unwrap_or_default() as c_long;
+
+		Self {
+			imports: config.imports.clone(),
+			globals: None,
+			thread,
+			jobs: Arc::default(),
+			helpers: HashMap::new(),
+		}
+	}
+
+	pub fn version(&self) -> String {
+		Python::attach(|py| {
+			let PythonVersionInfo { major, minor, .. } = py.version_info();
+			format!("{major}.{minor}")
+		})
+	}
+
+	/// Raise `KeyboardInterrupt` in the next job run in this session
+	///
+	/// This only takes effect once the thread runs Python code again, so a long
+	/// call into native code will still run to completion.
+	pub fn interrupter(&self) -> Interrupt {
+		let thread = self.thread;
+		let jobs = self.jobs.clone();
+		let job = jobs.load(Ordering::SeqCst) + 1;
+
+		Arc::new(move || {
+			Python::attach(|_| match jobs.load(Ordering::SeqCst) {
+				current if current < job => false,
+				current if current > job => true,
+				_ => {
+					debug!(thread, job, "interrupting python evaluation");
+
+					// SAFETY: the GIL is held and the exception is a valid, static exception type
+					unsafe { ffi::PyThreadState_SetAsyncExc(thread, ffi::PyExc_KeyboardInterrupt) };
+					true
+				}
+			})
+		})
+	}
+
+	/// Count a new job, discarding an interrupt meant for the previous one
+	fn begin(&self, _py: Python<'_>) {
+		self.jobs.fetch_add(1, Ordering::SeqCst);
+
+		// SAFETY: the GIL is held, and a null exception clears a pending one
+		unsafe { ffi::PyThreadState_SetAsyncExc(self.thread, ptr::null_mut()) };
+	}
+
+	pub fn eval(&mut self, input: &str) -> Outcome {
+		let Ok(code) =
+			CString::new(input).inspect_err(|err| warn!(?err, "invalid python expression entered"))
+		else {
+			return Outcome::default();
+		};
+
+		Python::attach(|py| {
+			self.begin(py);
+			let globals = self.preview_globals(py);
+			self.evaluate(py, &code, &globals)
+		})
+	}
+
+	/// Evaluate an expression in `globals`
+	fn evaluate(&mut self, py: Python<'_>, code: &CStr, globals: &Bound<'_, PyDict>) -> Outcome {
+		let (outcome, output) = capture(py, || {
+			let result = match py.eval(code, Some(globals), None) {
+				Ok(result) => result,
+				// incomplete expressions and statements are expected while typing
+				Err(err) if err.is_instance_of::<PySyntaxError>(py) => {
+					return Outcome::default();
+				}
+				Err(err) => {
+					debug!(?err, "error evaluating python expression");
+
+					return Outcome {
+						error: Some(exception(py, &err)),
+						..Outcome::default()
+					};
+				}
+			};
+
+			match self.figure(py, Some(&result)) {
+				Some(figure) => Outcome {
+					figure: Some(figure),
+					..Outcome::default()
+				},
+				None => Outcome {
+					result: Some(result.to_string()),
+					..Outcome::default()
+				},
+			}
+		})
+		.inspect_err(|err| warn!(?err, "error capturing python output"))
+		.unwrap_or_default();
+
+		Outcome { output, ..outcome }
+	}
+
+	/// Run statements, or an expression, in the session's globals
+	pub fn run(&mut self, input: &str) -> Option<Outcome> {
+		let code = CString::new(input).ok()?;
+
+		Python::attach(|py| {
+			self.begin(py);
+			let globals = self.globals(py);
+
+			match PyCode::compile(py, &code, c"<input>", PyCodeInput::Eval) {
+				// expressions are only evaluated in a copy of the globals while typing, so
+				// their side effects happen now
+				Ok(_) => Some(self.evaluate(py, &code, &globals)),
+				Err(err) if err.is_instance_of::<PySyntaxError>(py) => capture(py, || {
+					let error = PyCode::compile(py, &code, c"<input>", PyCodeInput::File)
+						.and_then(|code| code.run(Some(&globals), None))
+						.inspect_err(|err| debug!(?err, "error running python statements"))
+						.err()
+						.map(|err| exception(py, &err));
+
+					(self.figure(py, None), error)
+				})
+				.inspect_err(|err| warn!(?err, "error capturing python output"))
+				.ok()
+				.map(|((figure, error), output)| Outcome {
+					result: None,
+					output,
+					figure,
+					error,
+				}),
+				_ => None,
+			}
+		})
+	}
+
+	pub fn reset(&mut self) {
+		self.globals = None;
+	}
+
+	/// Simplify an expression or solve an equation with SymPy
+	#[cfg(feature = "sym")]
+	pub fn symbolic(&mut self, input: &str) -> Outcome {
+		Python::attach(|py| {
+			self.begin(py);
+
+			let Some(symbolic) = self.helper(py, SYMBOLIC, "symbolic") else {
+				return Outcome::default();
+			};
+
+			let (outcome, output) = capture(py, || {
+				match symbolic
+					.call1((input,))
+					.and_then(|res| res.extract::<String>())
+				{
+					Ok(result) => Outcome {
+						result: Some(result),
+						..Outcome::default()
+					},
+					// incomplete expressions are expected while typing
+					Err(err) if err.is_instance_of::<PySyntaxError>(py) => Outcome::default(),
+					Err(err) => {
+						debug!(?err, "error evaluating symbolic math");
+
+						Outcome {
+							error: Some(exception(py, &err)),
+							..Outcome::default()
+						}
+					}
+				}
+			})
+			.inspect_err(|err| warn!(?err, "error capturing python output"))
+			.unwrap_or_default();
+
+			Outcome { output, ..outcome }
+		})
+	}
+
+	/// Complete a name in the namespace, or an attribute after a `.`
+	pub fn complete(&mut self, word: &str) -> Vec<String> {
+		Python::attach(|py| {
+			self.begin(py);
+			let globals = self.globals(py);
+
+			self.helper(py, COMPLETE, "complete")
+				.and_then(|complete| {
+					complete
+						.call1((word, globals))
+						.and_then(|completions| completions.extract())
+						.inspect_err(|err| debug!(?err, "error completing python"))
+						.ok()
+				})
+				.unwrap_or_default()
+		})
+	}
+
+	/// Render the result, or a figure drawn while evaluating, as a PNG
+	fn figure(&mut self, py: Python<'_>, result: Option<&Bound<'_, PyAny>>) -> Option<Vec<u8>> {
+		self.helper(py, FIGURE_SCRIPT, "figure_png")?
+			.call1((result,))
+			.and_then(|png| png.extract::<Option<Vec<u8>>>())
+			.inspect_err(|err| debug!(?err, "error rendering figure"))
+			.ok()
+			.flatten()
+	}
+
+	/// Get a function from one of the bundled Python helper scripts
+	fn helper<'py>(
+		&mut self,
+		py: Python<'py>,
+		source: &'static str,
+		name: &'static str,
+	) -> Option<Bound<'py, PyAny>> {
+		if let Some(helper) = self.helpers.get(name) {
+			return Some(helper.bind(py).clone());
+		}
+
+		let helper = CString::new(source)
+			.map_err(|e| e.to_string())
+			.and_then(|code| {
+				let file = CString::new(format!("{name}.py")).map_err(|e| e.to_string())?;
+
+				PyModule::from_code(py, &code, &file, &file)
+					.and_then(|module| module.getattr(name))
+					.map_err(|e| e.to_string())
+			})
+			.inspect_err(|err| error!(?err, name, "error loading python helper"))
+			.ok()?;
+
+		self.helpers.insert(name, helper.clone().unbind());
+		Some(helper)
+	}
+
+	/// A copy of the globals to evaluate expressions in while typing, so that
+	/// they don't change the session
+	fn preview_globals<'py>(&mut self, py: Python<'py>) -> Bound<'py, PyDict> {
+		let globals = self.globals(py);
+
+		self.helper(py, PREVIEW_SCRIPT, "preview_globals")
+			.and_then(|preview| {
+				preview
+					.call1((&globals,))
+					.and_then(|preview| preview.cast_into().map_err(PyErr::from))
+					.inspect_err(|err| warn!(?err, "error copying python globals"))
+					.ok()
+			})
+			.or_else(|| globals.copy().ok())
+			.unwrap_or(globals)
+	}
+
+	fn globals<'py>(&mut self, py: Python<'py>) -> Bound<'py, PyDict> {
+		self.globals
+			.get_or_insert_with(|| {
+				let globals = PyDict::new(py);
+
+				for module in &self.imports {
+					CString::new(format!("import {module}"))
+						.map_err(|e| e.to_string())
+						.and_then(|code| {
+							py.run(&code, Some(&globals), None)
+								.map_err(|e| e.to_string())
+						})
+						.inspect(|()| debug!(module, "imported python module"))
+						.inspect_err(|err| warn!(?err, module, "error importing python module"))
+						.ok();
+				}
+
+				globals.unbind()
+			})
+			.bind(py)
+			.clone()
+	}
+}
+
+/// Describe an exception like Python does when it's uncaught
+fn exception(py: Python<'_>, err: &PyErr) -> Exception {
+	let summary = err.to_string();
+	let summary = summary.strip_suffix(": ").unwrap_or(&summary).to_string();
+
+	let traceback = err
+		.traceback(py)
+		.and_then(|traceback| {
+			traceback
+				.format()
+				.inspect_err(|err| warn!(?err, "error formatting python traceback"))
+				.ok()
+		})
+		.unwrap_or_default();
+
+	Exception {
+		traceback: format!("{traceback}{summary}"),
+		summary,
+	}
+}
+
+/// Run `f` with `sys.stdout` and `sys.stderr` redirected into a buffer
+fn capture<T>(py: Python<'_>, f: impl FnOnce() -> T) -> PyResult<(T, String)> {
+	let sys = PyModule::import(py, "sys")?;
+	let buffer = PyModule::import(py, "io")?.getattr("StringIO")?.call0()?;
+
+	let stdout = sys.getattr("stdout")?;
+	let stderr = sys.getattr("stderr")?;
+	sys.setattr("stdout", &buffer)?;
+	sys.setattr("stderr", &buffer)?;
+
+	let res = f();
+
+	sys.setattr("stdout", stdout)?;
+	sys.setattr("stderr", stderr)?;
+
+	Ok((res, buffer.call_method0("getvalue")?.extract()?))
+}
@@ -0,0 +1,34 @@
+# Renders matplotlib figures and objects with a `_repr_png_` for Quicalc
+
+import io
+import sys
+
+
+def figure_png(result):
+    try:
+        repr_png = getattr(result, "_repr_png_", None)
+
+        if callable(repr_png):
+            png = repr_png()
+
+            if isinstance(png, bytes):
+                return png
+
+        pyplot = sys.modules.get("matplotlib.pyplot")
+
+        if hasattr(result, "savefig"):
+            figure = result
+        elif pyplot is not None and pyplot.get_fignums():
+            figure = pyplot.gcf()
+        else:
+            return None
+
+        buffer = io.BytesIO()
+        figure.savefig(buffer, format="png", bbox_inches="tight")
+
+        if pyplot is not None:
+            pyplot.close("all")
+
+        return buffer.getvalue()
+    except Exception:
+        return None
@@ -0,0 +1,21 @@
+import copy
+
+
+def preview_globals(globals):
+    # expressions are evaluated while typing in copies of the values, so that
+    # something like `items.pop()` only changes the session once it's run, while
+    # values that can't be copied (like modules) and the builtins are shared
+    memo = {}
+    preview = {}
+
+    for name, value in globals.items():
+        if name == "__builtins__":
+            preview[name] = value
+            continue
+
+        try:
+            preview[name] = copy.deepcopy(value, memo)
+        except Exception:
+            preview[name] = value
+
+    return preview
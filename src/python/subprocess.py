@@ -0,0 +1,123 @@
+# Evaluates Python for Quicalc, reading one JSON request per line from stdin
+# and writing one JSON response per line to stdout
+
+import builtins
+import contextlib
+import io
+import json
+import sys
+import traceback
+
+UNSAFE_BUILTINS = {
+    "__import__",
+    "breakpoint",
+    "compile",
+    "eval",
+    "exec",
+    "exit",
+    "globals",
+    "help",
+    "input",
+    "locals",
+    "open",
+    "quit",
+    "vars",
+}
+
+protocol = sys.stdout
+namespace = {}
+
+
+def respond(**response):
+    protocol.write(json.dumps(response) + "\n")
+    protocol.flush()
+
+
+def show(response, result):
+    figure = figure_png(result)
+
+    if figure is None:
+        response["result"] = str(result)
+    else:
+        response["figure"] = figure.hex()
+
+
+def evaluate(kind, code):
+    output = io.StringIO()
+    response = {}
+
+    with contextlib.redirect_stdout(output), contextlib.redirect_stderr(output):
+        try:
+            if kind == "eval":
+                show(response, eval(code, preview_globals(namespace)))
+            elif kind == "symbolic":
+                response["result"] = symbolic(code)
+            else:
+                response["ran"] = True
+
+                try:
+                    expression = compile(code, "<input>", "eval")
+                except SyntaxError:
+                    exec(compile(code, "<input>", "exec"), namespace)
+                    figure = figure_png(None)
+
+                    if figure is not None:
+                        response["figure"] = figure.hex()
+                else:
+                    # expressions are only evaluated in a copy of the namespace while
+                    # typing, so their side effects happen now
+                    show(response, eval(expression, namespace))
+        except BaseException as exception:
+            # leave out this function and the syntax error that made statements run
+            response["error"] = "".join(
+                traceback.format_exception(
+                    type(exception),
+                    exception,
+                    exception.__traceback__.tb_next,
+                    chain=False,
+                )
+            ).rstrip()
+
+            # incomplete expressions and statements are expected while typing
+            if kind == "run" or not isinstance(exception, SyntaxError):
+                summary = traceback.format_exception_only(type(exception), exception)
+                response["summary"] = summary[-1].strip()
+
+    response["output"] = output.getvalue()
+    return response
+
+
+def restrict(memory_limit):
+    try:
+        import resource
+
+        resource.setrlimit(resource.RLIMIT_AS, (memory_limit, memory_limit))
+    except (ImportError, ValueError, OSError):
+        # memory limits aren't supported on all platforms
+        pass
+
+    namespace["__builtins__"] = {
+        name: value
+        for name, value in vars(builtins).items()
+        if name not in UNSAFE_BUILTINS
+    }
+
+
+respond(version="{}.{}".format(*sys.version_info))
+
+for line in sys.stdin:
+    request = json.loads(line)
+
+    if request["kind"] == "reset":
+        namespace = {}
+        respond()
+    elif request["kind"] == "restrict":
+        restrict(request["memory_limit"])
+        respond()
+    elif request["kind"] == "complete":
+        try:
+            respond(completions=complete(request["word"], namespace))
+        except Exception:
+            respond(error=traceback.format_exc())
+    else:
+        respond(**evaluate(request["kind"], request["code"]))
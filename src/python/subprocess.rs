@@ -0,0 +1,333 @@
+use std::{
+	io::{BufRead, BufReader, Write},
+	path::PathBuf,
+	process::{Child, ChildStdin, ChildStdout, Command, Stdio},
+	sync::{Arc, Mutex, PoisonError},
+};
+
+use serde::{Deserialize, Serialize};
+use tracing::{debug, error, info, warn};
+
+use crate::{
+	config::PythonConfig,
+	python::{Exception, FIGURE_SCRIPT, Interrupt, Outcome, PREVIEW_SCRIPT},
+};
+
+#[cfg(feature = "sym")]
+const SYMBOLIC: &str = include_str!("symbolic.py");
+const COMPLETE: &str = include_str!("complete.py");
+const SCRIPT: &str = include_str!("subprocess.py");
+
+#[derive(Debug, Serialize)]
+#[serde(tag = "kind", rename_all = "kebab-case")]
+enum Request<'a> {
+	Eval {
+		code: &'a str,
+	},
+	Run {
+		code: &'a str,
+	},
+	#[cfg(feature = "sym")]
+	Symbolic {
+		code: &'a str,
+	},
+	Complete {
+		word: &'a str,
+	},
+	Reset,
+	/// Remove dangerous builtins and limit the memory of the subprocess
+	Restrict {
+		memory_limit: u64,
+	},
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct Response {
+	version: Option<String>,
+	result: Option<String>,
+	output: String,
+	/// A rendered figure as a hex-encoded PNG
+	figure: Option<String>,
+	ran: bool,
+	completions: Vec<String>,
+	/// The type and message of an exception raised by the code
+	summary: Option<String>,
+	/// The full traceback of an exception, or an error in the subprocess itself
+	error: Option<String>,
+}
+
+impl Response {
+	fn into_outcome(self) -> Outcome {
+		Outcome {
+			result: self.result,
+			output: self.output,
+			figure: self.figure.as_deref().and_then(decode_hex),
+			error: self
+				.summary
+				.zip(self.error)
+				.map(|(summary, traceback)| Exception { summary, traceback }),
+		}
+	}
+}
+
+/// Evaluates Python in a separate `python` process, so that Quicalc doesn't
+/// need to be linked against a specific version of Python
+#[derive(Debug)]
+pub struct Subprocess {
+	executable: PathBuf,
+	imports: Vec<String>,
+	/// The memory limit in bytes, if evaluation is restricted
+	restricted: Option<u64>,
+	child: Arc<Mutex<Option<Child>>>,
+	pipes: Option<(ChildStdin, BufReader<ChildStdout>)>,
+	version: Option<String>,
+}
+
+impl Subprocess {
+	pub fn new(config: &PythonConfig) -> Self {
+		let mut subprocess = Self {
+			executable: executable(config),
+			imports: config.imports.clone(),
+			restricted: config
+				.restricted
+				.then_some(config.memory_limit_mb * 1024 * 1024),
+			child: Arc::default(),
+			pipes: None,
+			version: None,
+		};
+
+		subprocess
+			.start()
+			.inspect_err(|err| error!(?err, "error starting python subprocess"))
+			.ok();
+
+		subprocess
+	}
+
+	pub fn version(&self) -> Option<String> {
+		self.version.clone()
+	}
+
+	/// Kill the subprocess, which is restarted (losing any variables) for the
+	/// next evaluation
+	pub fn interrupter(&self) -> Interrupt {
+		let child = self.child.clone();
+
+		Arc::new(move || {
+			debug!("killing python subprocess");
+
+			if let Some(child) = child
+				.lock()
+				.unwrap_or_else(PoisonError::into_inner)
+				.as_mut()
+			{
+				child
+					.kill()
+					.inspect_err(|err| warn!(?err, "error killing python subprocess"))
+					.ok();
+			}
+
+			true
+		})
+	}
+
+	pub fn eval(&mut self, input: &str) -> Outcome {
+		self.request(&Request::Eval { code: input })
+			.map(Response::into_outcome)
+			.unwrap_or_default()
+	}
+
+	pub fn run(&mut self, input: &str) -> Option<Outcome> {
+		self.request(&Request::Run { code: input })
+			.ok()
+			.filter(|res| res.ran)
+			.map(Response::into_outcome)
+	}
+
+	#[cfg(feature = "sym")]
+	pub fn symbolic(&mut self, input: &str) -> Outcome {
+		self.request(&Request::Symbolic { code: input })
+			.map(Response::into_outcome)
+			.unwrap_or_default()
+	}
+
+	pub fn complete(&mut self, word: &str) -> Vec<String> {
+		self.request(&Request::Complete { word })
+			.map(|res| res.completions)
+			.unwrap_or_default()
+	}
+
+	pub fn reset(&mut self) {
+		if self.pipes.is_some() && self.send(&Request::Reset).is_ok() {
+			self.prepare();
+		}
+	}
+
+	fn start(&mut self) -> Result<(), String> {
+		let script = [
+			FIGURE_SCRIPT,
+			PREVIEW_SCRIPT,
+			#[cfg(feature = "sym")]
+			SYMBOLIC,
+			COMPLETE,
+			SCRIPT,
+		]
+		.concat();
+
+		let mut command = Command::new(&self.executable);
+		command
+			.args(["-u", "-c", &script])
+			.env("PYTHONIOENCODING", "utf-8")
+			// render matplotlib figures without opening windows
+			.env("MPLBACKEND", "Agg")
+			.stdin(Stdio::piped())
+			.stdout(Stdio::piped())
+			.stderr(Stdio::null());
+
+		#[cfg(windows)]
+		{
+			use std::os::windows::process::CommandExt;
+
+			// CREATE_NO_WINDOW, to not flash a console window when starting
+			command.creation_flags(0x0800_0000);
+		}
+
+		let mut child = command.spawn().map_err(|e| e.to_string())?;
+		let stdin = child.stdin.take().ok_or("python subprocess has no stdin")?;
+		let mut stdout = BufReader::new(
+			child
+				.stdout
+				.take()
+				.ok_or("python subprocess has no stdout")?,
+		);
+
+		// the subprocess announces its version once it's ready
+		let mut line = String::new();
+		stdout.read_line(&mut line).map_err(|e| e.to_string())?;
+		let ready: Response = serde_json::from_str(&line).map_err(|e| e.to_string())?;
+
+		info!(executable = ?self.executable, version = ?ready.version, "started python subprocess");
+
+		self.version = ready.version;
+		*self.child.lock().unwrap_or_else(PoisonError::into_inner) = Some(child);
+		self.pipes = Some((stdin, stdout));
+		self.prepare();
+
+		Ok(())
+	}
+
+	fn stop(&mut self) {
+		self.pipes = None;
+
+		if let Some(mut child) = self
+			.child
+			.lock()
+			.unwrap_or_else(PoisonError::into_inner)
+			.take()
+		{
+			child.kill().ok();
+			child.wait().ok();
+		}
+	}
+
+	/// Import modules and apply restrictions in a fresh namespace
+	fn prepare(&mut self) {
+		for module in self.imports.clone() {
+			self.send(&Request::Run {
+				code: &format!("import {module}"),
+			})
+			.and_then(|res| res.error.map_or(Ok(()), Err))
+			.inspect(|()| debug!(module, "imported python module"))
+			.inspect_err(|err| warn!(?err, module, "error importing python module"))
+			.ok();
+		}
+
+		// imports need `__import__`, so restrictions are only applied afterwards
+		if let Some(memory_limit) = self.restricted {
+			self.send(&Request::Restrict { memory_limit })
+				.inspect(|_| info!(memory_limit, "restricted python evaluation"))
+				.inspect_err(|err| error!(?err, "error restricting python evaluation"))
+				.ok();
+		}
+	}
+
+	/// Send a request, starting the subprocess first if it isn't running
+	fn request(&mut self, request: &Request) -> Result<Response, String> {
+		if self.pipes.is_none() {
+			self.start()?;
+		}
+
+		self.send(request)
+	}
+
+	fn send(&mut self, request: &Request) -> Result<Response, String> {
+		let (stdin, stdout) = self
+			.pipes
+			.as_mut()
+			.ok_or("python subprocess is not running")?;
+
+		let response = serde_json::to_string(request)
+			.map_err(|e| e.to_string())
+			.and_then(|request| writeln!(stdin, "{request}").map_err(|e| e.to_string()))
+			.and_then(|()| {
+				let mut line = String::new();
+
+				match stdout.read_line(&mut line) {
+					Ok(0) => Err("python subprocess exited".to_string()),
+					Ok(_) => serde_json::from_str::<Response>(&line).map_err(|e| e.to_string()),
+					Err(err) => Err(err.to_string()),
+				}
+			});
+
+		match response {
+			Ok(response) => {
+				if let Some(err) = &response.error {
+					debug!(err, "error evaluating python");
+				}
+
+				Ok(response)
+			}
+			Err(err) => {
+				warn!(?err, "error communicating with python subprocess");
+				self.stop();
+				Err(err)
+			}
+		}
+	}
+}
+
+impl Drop for Subprocess {
+	fn drop(&mut self) {
+		self.stop();
+	}
+}
+
+fn decode_hex(hex: &str) -> Option<Vec<u8>> {
+	(0..hex.len())
+		.step_by(2)
+		.map(|i| u8::from_str_radix(hex.get(i..i + 2)?, 16).ok())
+		.collect()
+}
+
+fn executable(config: &PythonConfig) -> PathBuf {
+	let (scripts, python) = if cfg!(windows) {
+		("Scripts", "python.exe")
+	} else {
+		("bin", "python3")
+	};
+
+	if let Some(executable) = &config.executable {
+		executable.clone()
+	} else if let Some(venv) = &config.venv {
+		venv.join(scripts).join(python)
+	} else if let Some(home) = &config.home {
+		if cfg!(windows) {
+			home.join(python)
+		} else {
+			home.join("bin").join(python)
+		}
+	} else {
+		PathBuf::from(python)
+	}
+}
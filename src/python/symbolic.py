@@ -0,0 +1,40 @@
+# Evaluates symbolic math with SymPy for Quicalc
+
+import re
+from tokenize import TokenError
+
+
+def symbolic(code):
+    import sympy
+    from sympy.parsing.sympy_parser import (
+        convert_xor,
+        implicit_multiplication_application,
+        parse_expr,
+        standard_transformations,
+    )
+
+    transformations = standard_transformations + (
+        implicit_multiplication_application,
+        convert_xor,
+    )
+
+    def parse(code):
+        try:
+            return parse_expr(code, transformations=transformations)
+        except TokenError as error:
+            # unclosed brackets, like while still typing, are incomplete like any
+            # other syntax error
+            raise SyntaxError(error.args[0]) from None
+
+    # a single `=` makes an equation, which is solved
+    sides = re.split(r"(?<![=<>!])=(?!=)", code)
+
+    if len(sides) == 2:
+        result = sympy.solve(sympy.Eq(parse(sides[0]), parse(sides[1])))
+    else:
+        result = parse(code)
+
+        if isinstance(result, sympy.Basic):
+            result = sympy.simplify(result)
+
+    return sympy.pretty(result, use_unicode=True)
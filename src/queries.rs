@@ -0,0 +1,36 @@
+use std::collections::BTreeMap;
+
+/// What's replaced by the query in a web query's URL
+const PLACEHOLDER: &str = "{q}";
+
+/// The URL of the web query entered as `input`, like `npm tokio`, with the
+/// query filled into the configured URL
+pub fn url(queries: &BTreeMap<String, String>, input: &str) -> Option<String> {
+	let (name, query) = input.trim().split_once(char::is_whitespace)?;
+	let template = queries.get(name)?;
+
+	Some(template.replace(PLACEHOLDER, &encode(query.trim())))
+}
+
+/// The names of the web queries starting with `word`, followed by a space for
+/// entering the query
+pub fn complete(queries: &BTreeMap<String, String>, word: &str) -> Vec<String> {
+	queries
+		.keys()
+		.filter(|name| name.starts_with(word))
+		.map(|name| format!("{name} "))
+		.collect()
+}
+
+/// Percent-encode a query to be put into a URL
+pub fn encode(query: &str) -> String {
+	query
+		.bytes()
+		.map(|byte| match byte {
+			b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+				(byte as char).to_string()
+			}
+			_ => format!("%{byte:02X}"),
+		})
+		.collect()
+}
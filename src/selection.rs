@@ -0,0 +1,77 @@
+#[cfg(not(target_os = "linux"))]
+use std::{thread, time::Duration};
+
+use iced::{Task, clipboard};
+#[cfg(not(target_os = "linux"))]
+use rdev::{EventType, Key};
+#[cfg(not(target_os = "linux"))]
+use tracing::error;
+
+/// How long to wait between simulated key events, so that programs see each
+/// of them
+#[cfg(not(target_os = "linux"))]
+const KEY_DELAY: Duration = Duration::from_millis(20);
+
+/// How long the focused program gets to put its selection on the clipboard
+#[cfg(not(target_os = "linux"))]
+const COPY_DELAY: Duration = Duration::from_millis(150);
+
+/// The modifiers that could still be held from pressing the hotkey, which
+/// would change the copy shortcut into something else
+#[cfg(not(target_os = "linux"))]
+const MODIFIERS: [Key; 7] = [
+	Key::Alt,
+	Key::AltGr,
+	Key::ShiftLeft,
+	Key::ShiftRight,
+	Key::ControlRight,
+	Key::MetaLeft,
+	Key::MetaRight,
+];
+
+/// The text selected in the focused program, if there is any
+///
+/// On Linux this is the primary selection. Elsewhere the copy shortcut is
+/// pressed to put the selection on the clipboard, replacing what was there.
+pub fn read() -> Task<Option<String>> {
+	#[cfg(target_os = "linux")]
+	{
+		clipboard::read_primary()
+	}
+
+	#[cfg(not(target_os = "linux"))]
+	{
+		Task::perform(tokio::task::spawn_blocking(copy), |res| {
+			res.unwrap_or_else(|err| Err(err.to_string()))
+				.inspect_err(|err| error!(?err, "error copying selection"))
+				.ok();
+		})
+		.then(|()| clipboard::read())
+	}
+}
+
+/// Press the copy shortcut, and wait for the copied text to arrive
+#[cfg(not(target_os = "linux"))]
+fn copy() -> Result<(), String> {
+	let modifier = if cfg!(target_os = "macos") {
+		Key::MetaLeft
+	} else {
+		Key::ControlLeft
+	};
+
+	let events = MODIFIERS.into_iter().map(EventType::KeyRelease).chain([
+		EventType::KeyPress(modifier),
+		EventType::KeyPress(Key::KeyC),
+		EventType::KeyRelease(Key::KeyC),
+		EventType::KeyRelease(modifier),
+	]);
+
+	for event in events {
+		rdev::simulate(&event).map_err(|e| e.to_string())?;
+		thread::sleep(KEY_DELAY);
+	}
+
+	thread::sleep(COPY_DELAY);
+
+	Ok(())
+}
@@ -0,0 +1,148 @@
+use std::{
+	fs,
+	io::ErrorKind,
+	path::{Path, PathBuf},
+};
+
+use kalk::parser::{Context, eval};
+use serde::{Deserialize, Serialize, de::DeserializeOwned};
+use tracing::{debug, error, info};
+
+use crate::paths;
+
+/// Saves or loads a named session, like `session save projectx`
+pub const COMMAND: &str = "session";
+
+/// The most definitions kept for each language, forgetting the oldest ones
+/// first
+const MAX_ENTRIES: usize = 500;
+
+/// The variables and functions defined since the context was last reset, as
+/// the code defining them
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Session {
+	/// Kalk inputs with assignments or definitions, in the order they were
+	/// submitted
+	pub kalk: Vec<String>,
+	/// Python code that ran without errors, in the order it was run
+	pub python: Vec<String>,
+}
+
+/// A session saved under a name, along with the inputs leading up to it
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Snapshot {
+	pub session: Session,
+	/// Earlier inputs, most recent last
+	pub history: Vec<String>,
+	/// The running total of submitted results
+	pub total: Option<f64>,
+}
+
+impl Session {
+	fn path() -> Option<PathBuf> {
+		paths::data().map(|dir| dir.join("session.toml"))
+	}
+
+	/// The session saved when quicalc last ran, or an empty one
+	pub fn load() -> Self {
+		let Some(path) = Self::path() else {
+			return Self::default();
+		};
+
+		match read(&path) {
+			Ok(Some(session)) => {
+				info!(?path, "loaded session");
+				session
+			}
+			Ok(None) => Self::default(),
+			Err(err) => {
+				error!(?err, ?path, "error loading session");
+				Self::default()
+			}
+		}
+	}
+
+	pub fn save(&self) -> Result<(), String> {
+		let path = Self::path().ok_or("could not determine session file location")?;
+		write(&path, self)
+	}
+
+	pub fn push_kalk(&mut self, input: &str) {
+		push(&mut self.kalk, input);
+	}
+
+	#[cfg(feature = "python")]
+	pub fn push_python(&mut self, code: &str) {
+		push(&mut self.python, code);
+	}
+
+	/// Define the kalk variables and functions from this session in `ctx`
+	pub fn restore_kalk(&self, ctx: &mut Context) {
+		for input in &self.kalk {
+			eval(ctx, input)
+				.inspect_err(|err| debug!(?err, input, "error restoring kalk definition"))
+				.ok();
+		}
+	}
+}
+
+/// Whether a session name can be used, which ends up in a file name like a
+/// profile name
+pub fn is_valid_name(name: &str) -> bool {
+	paths::is_valid_profile(name)
+}
+
+fn named_path(name: &str) -> Option<PathBuf> {
+	paths::data().map(|dir| dir.join("sessions").join(format!("{name}.toml")))
+}
+
+pub fn save_named(name: &str, snapshot: &Snapshot) -> Result<(), String> {
+	let path = named_path(name).ok_or("could not determine session file location")?;
+	write(&path, snapshot)?;
+	info!(name, "saved named session");
+
+	Ok(())
+}
+
+/// The session saved under `name`, or `None` if there is none
+pub fn load_named(name: &str) -> Result<Option<Snapshot>, String> {
+	let path = named_path(name).ok_or("could not determine session file location")?;
+	read(&path)
+}
+
+fn read<T: DeserializeOwned>(path: &Path) -> Result<Option<T>, String> {
+	match fs::read_to_string(path) {
+		Ok(contents) => toml::from_str(&contents)
+			.map(Some)
+			.map_err(|e| e.to_string()),
+		Err(err) if err.kind() == ErrorKind::NotFound => Ok(None),
+		Err(err) => Err(err.to_string()),
+	}
+}
+
+fn write<T: Serialize>(path: &Path, value: &T) -> Result<(), String> {
+	if let Some(dir) = path.parent() {
+		fs::create_dir_all(dir).map_err(|e| e.to_string())?;
+	}
+
+	let contents = toml::to_string(value).map_err(|e| e.to_string())?;
+	fs::write(path, contents).map_err(|e| e.to_string())?;
+	debug!(?path, "saved session");
+
+	Ok(())
+}
+
+fn push(entries: &mut Vec<String>, entry: &str) {
+	// repeating the same definition changes nothing
+	if entries.last().is_some_and(|last| last == entry) {
+		return;
+	}
+
+	entries.push(entry.to_string());
+
+	if entries.len() > MAX_ENTRIES {
+		entries.remove(0);
+	}
+}
@@ -0,0 +1,86 @@
+use std::{collections::BTreeMap, fs, path::Path};
+
+use serde::{Deserialize, Serialize};
+use tracing::{info, warn};
+
+use crate::config::Config;
+
+/// The files in the config directory that are carried over between machines
+const FILES: &[&str] = &["config.toml"];
+
+/// All of quicalc's settings in a single file
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct Archive {
+	/// The version of quicalc that exported the settings
+	version: String,
+	/// The contents of the exported files, by their name in the config
+	/// directory
+	files: BTreeMap<String, String>,
+}
+
+/// Write the settings from this machine to an archive at `path`
+pub fn export(path: &Path) -> Result<(), String> {
+	let config_path = Config::create_if_missing()?;
+	let dir = config_path
+		.parent()
+		.ok_or("could not determine config directory")?;
+
+	let mut archive = Archive {
+		version: env!("CARGO_PKG_VERSION").to_string(),
+		..Archive::default()
+	};
+
+	for &name in FILES {
+		match fs::read_to_string(dir.join(name)) {
+			Ok(contents) => {
+				archive.files.insert(name.to_string(), contents);
+			}
+			Err(err) => warn!(?err, name, "error reading settings file, skipping it"),
+		}
+	}
+
+	let archive = toml::to_string_pretty(&archive).map_err(|e| e.to_string())?;
+	fs::write(path, archive).map_err(|e| e.to_string())?;
+	info!(?path, "exported settings");
+
+	Ok(())
+}
+
+/// Replace the settings on this machine with the ones in the archive at
+/// `path`, keeping the replaced files as backups
+pub fn import(path: &Path) -> Result<(), String> {
+	let archive = fs::read_to_string(path).map_err(|e| e.to_string())?;
+	let archive: Archive = toml::from_str(&archive).map_err(|e| e.to_string())?;
+	info!(version = %archive.version, "importing settings");
+
+	if let Some(name) = archive
+		.files
+		.keys()
+		.find(|name| !FILES.contains(&name.as_str()))
+	{
+		return Err(format!("unknown file in settings archive: {name}"));
+	}
+
+	if let Some(config) = archive.files.get("config.toml") {
+		toml::from_str::<Config>(config).map_err(|e| format!("invalid config: {e}"))?;
+	}
+
+	let config_path = Config::path().ok_or("could not determine config file location")?;
+	let dir = config_path
+		.parent()
+		.ok_or("could not determine config directory")?;
+	fs::create_dir_all(dir).map_err(|e| e.to_string())?;
+
+	for (name, contents) in &archive.files {
+		let path = dir.join(name);
+
+		if path.exists() {
+			fs::copy(&path, dir.join(format!("{name}.bak"))).map_err(|e| e.to_string())?;
+		}
+
+		fs::write(&path, contents).map_err(|e| e.to_string())?;
+		info!(?path, "imported settings file");
+	}
+
+	Ok(())
+}
@@ -0,0 +1,140 @@
+use global_hotkey::hotkey::HotKey;
+use iced::{
+	Element, Length,
+	keyboard::{
+		Modifiers as IcedModifiers,
+		key::{Code as KeyCode, Physical},
+	},
+	widget::{column, scrollable},
+};
+
+use crate::{
+	CLOSE_KEYBIND, COPY_RESULT_KEYBIND, Message, ON_TOP_KEYBIND, PIN_KEYBIND, REDO_KEYBIND,
+	SHORTCUTS_KEYBIND, SUBMIT_KEYBIND, TOGGLE_OUTPUT_KEYBIND, UNDO_KEYBIND,
+	config::{Config, EditingStyle},
+	help, hotkey,
+	locale::tr,
+};
+
+/// Typing this into the empty input shows the shortcuts
+pub const INPUT: &str = "?";
+
+/// The name of a key combination, written like the ones in the config
+fn name(&(modifiers, key): &(IcedModifiers, Physical)) -> String {
+	hotkey::from_iced(modifiers, key).map_or_else(
+		|| format!("{key:?}"),
+		|(modifiers, code)| HotKey::new(Some(modifiers), code).to_string(),
+	)
+}
+
+/// The name of a keybind matched by the letter its key types, in the same
+/// format as the others
+fn letter_name(&(modifiers, letter): &(IcedModifiers, char)) -> String {
+	let mut name = [
+		(modifiers.shift(), "shift+"),
+		(modifiers.control(), "control+"),
+		(modifiers.alt(), "alt+"),
+		(modifiers.logo(), "super+"),
+	]
+	.into_iter()
+	.filter_map(|(held, name)| held.then_some(name))
+	.collect::<String>();
+
+	name.push(letter.to_ascii_uppercase());
+	name
+}
+
+/// Shortcuts that work while Quicalc is hidden
+fn global(config: &Config) -> Vec<(String, String)> {
+	let mut shortcuts = vec![(hotkey::active().to_string(), tr!("help-key-open"))];
+
+	if let Some(tap) = config.hotkey.double_tap {
+		shortcuts.push((format!("{tap:?} ×2"), tr!("help-key-open")));
+	}
+
+	if !config.hotkey.chord.is_empty() {
+		let chord = config
+			.hotkey
+			.chord
+			.iter()
+			.map(HotKey::to_string)
+			.collect::<Vec<_>>();
+		shortcuts.push((chord.join(", "), tr!("help-key-open")));
+	}
+
+	#[cfg(feature = "ocr")]
+	if let Some(capture) = config.ocr.hotkey {
+		shortcuts.push((capture.to_string(), tr!("shortcut-capture")));
+	}
+
+	shortcuts
+}
+
+/// Shortcuts in Quicalc's window
+fn window(config: &Config) -> Vec<(String, String)> {
+	let submit = ["Enter".to_string(), name(&SUBMIT_KEYBIND)]
+		.into_iter()
+		.chain(config.keys.confirm.iter().map(HotKey::to_string))
+		.collect::<Vec<_>>();
+
+	let mut shortcuts = vec![
+		(submit.join(" / "), tr!("shortcut-submit")),
+		("shift+Enter".to_string(), tr!("shortcut-new-line")),
+		("control+Enter".to_string(), tr!("shortcut-pad-submit")),
+		("Tab".to_string(), tr!("shortcut-complete")),
+		(letter_name(&UNDO_KEYBIND), tr!("shortcut-undo")),
+		(letter_name(&REDO_KEYBIND), tr!("shortcut-redo")),
+		(name(&TOGGLE_OUTPUT_KEYBIND), tr!("shortcut-output")),
+		(name(&COPY_RESULT_KEYBIND), tr!("shortcut-copy")),
+		(name(&PIN_KEYBIND), tr!("shortcut-pin")),
+		(name(&ON_TOP_KEYBIND), tr!("shortcut-on-top")),
+		(
+			format!("{} / {INPUT}", name(&SHORTCUTS_KEYBIND)),
+			tr!("shortcut-shortcuts"),
+		),
+		(name(&CLOSE_KEYBIND), tr!("help-key-hide")),
+	];
+
+	if config.keys.editing == EditingStyle::Vi {
+		shortcuts.extend([
+			(name(&CLOSE_KEYBIND), tr!("shortcut-vi-normal")),
+			("i / a / I / A".to_string(), tr!("shortcut-vi-insert")),
+			("h j k l w b 0 ^ $".to_string(), tr!("shortcut-vi-move")),
+			(
+				"x X D C dw dd cw cc diw ciw".to_string(),
+				tr!("shortcut-vi-edit"),
+			),
+			("u".to_string(), tr!("shortcut-undo")),
+		]);
+	}
+
+	if config.keys.editing == EditingStyle::Emacs {
+		let ctrl = |letter| letter_name(&(IcedModifiers::CTRL, letter));
+		let alt_backspace = name(&(IcedModifiers::ALT, Physical::Code(KeyCode::Backspace)));
+
+		shortcuts.extend([
+			(ctrl('a'), tr!("shortcut-line-start")),
+			(ctrl('e'), tr!("shortcut-line-end")),
+			(ctrl('u'), tr!("shortcut-kill-line")),
+			(
+				format!("{} / {alt_backspace}", ctrl('w')),
+				tr!("shortcut-kill-word"),
+			),
+		]);
+	}
+
+	shortcuts
+}
+
+pub fn view<'a>(config: &Config) -> Element<'a, Message> {
+	scrollable(
+		column![
+			help::section(tr!("shortcuts-global"), global(config)),
+			help::section(tr!("shortcuts-window"), window(config)),
+		]
+		.spacing(8)
+		.padding(4),
+	)
+	.height(Length::Fill)
+	.into()
+}
@@ -0,0 +1,271 @@
+use std::time::Duration;
+
+use crate::{locale::tr, timers};
+
+/// The prefixes of data size units, from kilo on
+const PREFIXES: [char; 6] = ['K', 'M', 'G', 'T', 'P', 'E'];
+
+/// A unit of data size, like `MiB`, `MB`, or `Mbit`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Unit {
+	/// The power of 1000 or 1024 of bytes or bits that the unit is
+	power: i32,
+	/// Whether the unit is always a power of 1024, like `MiB`, instead of
+	/// meaning 1000 or 1024 depending on who's asked, like `MB`
+	binary: bool,
+	/// Whether the unit is of bits instead of bytes
+	bits: bool,
+}
+
+impl Unit {
+	/// A unit like `MiB`, `MB`, `Mbit`, or `Mb`, where `b` is a bit and `B` a
+	/// byte
+	fn parse(text: &str) -> Option<Self> {
+		let (prefix, bits) = if let Some(prefix) = text
+			.strip_suffix("bits")
+			.or_else(|| text.strip_suffix("bit"))
+			.or_else(|| text.strip_suffix('b'))
+		{
+			(prefix, true)
+		} else if text.eq_ignore_ascii_case("bytes") {
+			("", false)
+		} else {
+			(text.strip_suffix('B')?, false)
+		};
+
+		let mut chars = prefix.chars();
+
+		let Some(prefix) = chars.next() else {
+			return Some(Self {
+				power: 0,
+				binary: false,
+				bits,
+			});
+		};
+
+		let power = PREFIXES
+			.iter()
+			.position(|&p| p == prefix.to_ascii_uppercase())? as i32
+			+ 1;
+
+		let binary = match chars.as_str() {
+			"i" => true,
+			"" => false,
+			_ => return None,
+		};
+
+		Some(Self {
+			power,
+			binary,
+			bits,
+		})
+	}
+
+	/// A rate like `35 Mbit/s`, `35 Mbps`, or `4 MB/s`
+	fn parse_rate(text: &str) -> Option<Self> {
+		Self::parse(
+			text.strip_suffix("/s")
+				.or_else(|| text.strip_suffix("ps"))?,
+		)
+	}
+
+	/// How many bytes the unit is, with ambiguous units as powers of 1024 or
+	/// 1000
+	fn bytes(self, ambiguous_base: f64) -> f64 {
+		let base = match self {
+			Self { binary: true, .. } => 1024.0,
+			// bits are counted in powers of 1000, like in network speeds
+			Self { bits: true, .. } => 1000.0,
+			_ => ambiguous_base,
+		};
+		let bytes = base.powi(self.power);
+
+		if self.bits { bytes / 8.0 } else { bytes }
+	}
+
+	fn is_ambiguous(self) -> bool {
+		!self.binary && !self.bits && self.power > 0
+	}
+}
+
+/// Convert a data size like `1.5 TiB in GB`, or calculate how long a transfer
+/// like `4.7 GB at 35 Mbit/s` takes, showing the result with both meanings of
+/// units like `GB`, or return `None` if the input isn't either
+pub fn eval(input: &str) -> Option<Result<String, String>> {
+	let words = input.split_whitespace().collect::<Vec<_>>();
+
+	if let Some(at) = words.iter().position(|&word| word == "at") {
+		return transfer(&words[..at], &words[at + 1..]);
+	}
+
+	let ((amount, from_unit), to) = match words.as_slice() {
+		[quantity @ .., "in" | "to", to] => (self::quantity(quantity, Unit::parse)?, *to),
+		_ => return None,
+	};
+
+	let to_unit = Unit::parse(to)?;
+	let amount = match number(amount) {
+		Ok(amount) => amount,
+		Err(err) => return Some(Err(err)),
+	};
+
+	let convert = |base: f64| format_size(amount * from_unit.bytes(base) / to_unit.bytes(base));
+
+	Some(Ok(if from_unit.is_ambiguous() || to_unit.is_ambiguous() {
+		tr!(
+			"size-ambiguous",
+			decimal = convert(1000.0),
+			binary = convert(1024.0),
+			unit = to
+		)
+	} else {
+		tr!("size-converted", size = convert(1000.0), unit = to)
+	}))
+}
+
+/// How long transferring a size like `4.7 GB` takes at a rate like
+/// `35 Mbit/s`
+fn transfer(size: &[&str], rate: &[&str]) -> Option<Result<String, String>> {
+	let ((size, size_unit), (rate, rate_unit)) = (
+		quantity(size, Unit::parse)?,
+		quantity(rate, Unit::parse_rate)?,
+	);
+
+	let (size, rate) = match (number(size), number(rate)) {
+		(Ok(size), Ok(rate)) => (size, rate),
+		(Err(err), _) | (_, Err(err)) => return Some(Err(err)),
+	};
+
+	let duration = |base: f64| {
+		Duration::try_from_secs_f64(size * size_unit.bytes(base) / (rate * rate_unit.bytes(base)))
+			.map_or_else(|_| "∞".to_string(), timers::format_clock)
+	};
+
+	Some(Ok(
+		if size_unit.is_ambiguous() || rate_unit.is_ambiguous() {
+			tr!(
+				"size-transfer-ambiguous",
+				decimal = duration(1000.0),
+				binary = duration(1024.0)
+			)
+		} else {
+			tr!("size-transfer", duration = duration(1000.0))
+		},
+	))
+}
+
+/// An amount and its unit, which can be attached to the number, like `1.5TiB`
+///
+/// Attached units start at the first letter that begins a whole unit, since
+/// amounts can have letters too, like `1e3KB`.
+fn quantity<'a>(
+	words: &[&'a str],
+	parse: impl Fn(&str) -> Option<Unit>,
+) -> Option<(&'a str, Unit)> {
+	match words {
+		[amount, unit] => Some((amount, parse(unit)?)),
+		[quantity] => quantity
+			.char_indices()
+			.filter(|(_, c)| c.is_ascii_alphabetic())
+			.find_map(|(start, _)| Some((&quantity[..start], parse(&quantity[start..])?))),
+		_ => None,
+	}
+}
+
+fn number(text: &str) -> Result<f64, String> {
+	text.parse::<f64>()
+		.ok()
+		.filter(|number| number.is_finite() && *number >= 0.0)
+		.ok_or_else(|| tr!("size-invalid", amount = text))
+}
+
+/// A size with at most 2 decimals, and none if they're zeros, or in
+/// scientific notation if it's tiny
+fn format_size(size: f64) -> String {
+	if size != 0.0 && size < 0.01 {
+		return format!("{size:.2e}");
+	}
+
+	let formatted = format!("{size:.2}");
+	formatted
+		.trim_end_matches('0')
+		.trim_end_matches('.')
+		.to_string()
+}
+
+#[cfg(test)]
+mod tests {
+	use proptest::prelude::*;
+
+	use super::*;
+
+	const AMOUNT: &str = "-?[0-9.e]{0,8}|inf|NaN";
+	const UNIT: &str = "[KkMGTPE]i?(B|b|bit|bits)|B|b|bit|bits|bytes";
+
+	#[test]
+	fn parse_units() {
+		assert_eq!(
+			Unit::parse("MiB"),
+			Some(Unit {
+				power: 2,
+				binary: true,
+				bits: false,
+			})
+		);
+		assert_eq!(
+			Unit::parse_rate("Mbps"),
+			Some(Unit {
+				power: 2,
+				binary: false,
+				bits: true,
+			})
+		);
+		assert_eq!(Unit::parse("iB"), None);
+		assert_eq!(Unit::parse("XB"), None);
+	}
+
+	proptest! {
+		#[test]
+		fn parse_any_unit(text in any::<String>()) {
+			let _ = Unit::parse(&text);
+			let _ = Unit::parse_rate(&text);
+		}
+
+		#[test]
+		fn parse_known_units(unit in UNIT) {
+			let rate = format!("{unit}/s");
+
+			prop_assert!(Unit::parse(&unit).is_some());
+			prop_assert!(Unit::parse_rate(&rate).is_some());
+		}
+
+		#[test]
+		fn eval_any_input(input in any::<String>()) {
+			let _ = eval(&input);
+		}
+
+		#[test]
+		fn eval_any_conversion(amount in AMOUNT, from in UNIT, to in UNIT) {
+			let (spaced, attached) = (format!("{amount} {from} in {to}"), format!("{amount}{from} to {to}"));
+
+			prop_assert!(eval(&spaced).is_some());
+			prop_assert!(eval(&attached).is_some());
+		}
+
+		#[test]
+		fn eval_any_transfer(
+			size in AMOUNT,
+			rate in AMOUNT,
+			size_unit in UNIT,
+			rate_unit in UNIT,
+		) {
+			let (spaced, attached) = (
+				format!("{size} {size_unit} at {rate} {rate_unit}/s"),
+				format!("{size}{size_unit} at {rate}{rate_unit}/s"),
+			);
+
+			prop_assert!(eval(&spaced).is_some());
+			prop_assert!(eval(&attached).is_some());
+		}
+	}
+}
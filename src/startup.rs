@@ -0,0 +1,19 @@
+use std::{sync::OnceLock, time::Instant};
+
+static START: OnceLock<Instant> = OnceLock::new();
+
+/// Print how long each phase of startup took, measured from `start`
+pub fn profile(start: Instant) {
+	START.set(start).ok();
+	phase("arguments parsed");
+}
+
+/// Record that a phase of startup is done, if startup is being profiled
+pub fn phase(name: &str) {
+	if let Some(start) = START.get() {
+		eprintln!(
+			"startup: {name} after {:.1} ms",
+			start.elapsed().as_secs_f64() * 1000.0
+		);
+	}
+}
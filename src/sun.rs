@@ -0,0 +1,100 @@
+use jiff::{Timestamp, Zoned, civil::Date, tz::TimeZone};
+use tracing::warn;
+
+use crate::{calendar, geo, locale::tr};
+
+/// The Julian date of 2000-01-01 12:00 UTC
+const J2000: f64 = 2_451_545.0;
+/// The Julian date of 1970-01-01 00:00 UTC
+const UNIX_EPOCH: f64 = 2_440_587.5;
+/// The tilt of the earth's axis, in degrees
+const OBLIQUITY: f64 = 23.4397;
+/// How far below the horizon the sun's center is at sunrise and sunset, in
+/// degrees, because of refraction and the size of the sun
+const HORIZON: f64 = -0.833;
+
+/// The sunrise, sunset, and day length at a place on a date, like
+/// `ljubljana 2026-06-21`, or today if there's no date, or `None` if the input
+/// is empty
+pub fn eval(input: &str) -> Option<Result<String, String>> {
+	let input = input.trim();
+
+	if input.is_empty() {
+		return None;
+	}
+
+	let (place, date) = input
+		.rsplit_once(char::is_whitespace)
+		.and_then(|(place, date)| Some((place, calendar::parse_date(date).ok()?)))
+		.unwrap_or_else(|| (input, Zoned::now().date()));
+
+	Some(times(place, date))
+}
+
+/// The times with the sunrise equation, which is accurate to about a minute
+fn times(place: &str, date: Date) -> Result<String, String> {
+	let place = geo::find(place)?;
+	let (latitude, longitude) = (place.point.latitude.to_radians(), place.point.longitude);
+
+	// places entered as coordinates are shown in the local time zone
+	let tz = place
+		.zone
+		.and_then(|zone| {
+			jiff::tz::db()
+				.get(zone)
+				.inspect_err(|err| warn!(?err, %zone, "unknown time zone of city"))
+				.ok()
+		})
+		.unwrap_or_else(TimeZone::system);
+
+	let noon = date
+		.at(12, 0, 0, 0)
+		.to_zoned(TimeZone::UTC)
+		.map_err(|e| e.to_string())?;
+	let days = (noon.timestamp().as_second() as f64 / 86400.0 + UNIX_EPOCH - J2000).round();
+
+	let mean_noon = days - longitude / 360.0;
+	let anomaly = (357.5291 + 0.985_600_28 * mean_noon)
+		.rem_euclid(360.0)
+		.to_radians();
+	let center =
+		1.9148 * anomaly.sin() + 0.02 * (2.0 * anomaly).sin() + 0.0003 * (3.0 * anomaly).sin();
+	let ecliptic_longitude = (anomaly.to_degrees() + center + 180.0 + 102.9372)
+		.rem_euclid(360.0)
+		.to_radians();
+	let transit =
+		J2000 + mean_noon + 0.0053 * anomaly.sin() - 0.0069 * (2.0 * ecliptic_longitude).sin();
+	let declination = (ecliptic_longitude.sin() * OBLIQUITY.to_radians().sin()).asin();
+
+	let cos_hour_angle = (HORIZON.to_radians().sin() - latitude.sin() * declination.sin())
+		/ (latitude.cos() * declination.cos());
+
+	if cos_hour_angle < -1.0 {
+		return Ok(tr!("sun-polar-day"));
+	}
+
+	if cos_hour_angle > 1.0 {
+		return Ok(tr!("sun-polar-night"));
+	}
+
+	// in days
+	let hour_angle = cos_hour_angle.acos().to_degrees() / 360.0;
+
+	let time = |julian: f64| {
+		Timestamp::from_second(((julian - UNIX_EPOCH) * 86400.0).round() as i64)
+			.map(|timestamp| timestamp.to_zoned(tz.clone()))
+			.map_err(|e| e.to_string())
+	};
+
+	let (sunrise, sunset) = (time(transit - hour_angle)?, time(transit + hour_angle)?);
+	let minutes = (2.0 * hour_angle * 24.0 * 60.0).round() as i64;
+
+	Ok(tr!(
+		"sun-times",
+		sunrise = sunrise.strftime("%H:%M").to_string(),
+		sunset = sunset.strftime("%H:%M").to_string(),
+		zone = sunrise.strftime("%Z").to_string(),
+		hours = minutes / 60,
+		minutes = minutes % 60
+	))
+}
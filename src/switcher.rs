@@ -0,0 +1,285 @@
+#[cfg(not(windows))]
+use std::process::Command;
+
+use crate::{fuzzy::Searchable, locale::tr};
+
+/// An open window of another program than quicalc
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WindowInfo {
+	/// How the window is found again to focus it, which is empty on macOS,
+	/// where the app and title are used instead
+	pub id: String,
+	pub title: String,
+	pub app: String,
+}
+
+impl Searchable for WindowInfo {
+	type Id = (String, String, String);
+
+	fn id(&self) -> Self::Id {
+		(self.id.clone(), self.app.clone(), self.title.clone())
+	}
+
+	fn text(&self) -> String {
+		format!("{} {}", self.title, self.app)
+	}
+
+	fn summary(&self) -> String {
+		tr!(
+			"win-window",
+			title = self.title.as_str(),
+			app = self.app.as_str()
+		)
+	}
+}
+
+/// The open windows with titles, most recently used first where the platform
+/// keeps track of that
+pub fn list() -> Result<Vec<WindowInfo>, String> {
+	platform::list()
+}
+
+/// Bring a window to the front and focus it
+pub fn focus(window: &WindowInfo) -> Result<(), String> {
+	platform::focus(window)
+}
+
+/// Run a program, returning what it printed
+#[cfg(not(windows))]
+fn run(command: &mut Command) -> Result<String, String> {
+	let output = command.output().map_err(|e| e.to_string())?;
+
+	if !output.status.success() {
+		return Err(String::from_utf8_lossy(&output.stderr).trim().to_string());
+	}
+
+	Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+/// Windows are listed with `wmctrl`, which only sees X11 windows
+#[cfg(target_os = "linux")]
+mod platform {
+	use std::process::Command;
+
+	use super::{WindowInfo, run};
+	use crate::portal;
+
+	pub fn list() -> Result<Vec<WindowInfo>, String> {
+		if portal::is_wayland() {
+			return Err("windows can't be listed on Wayland".to_string());
+		}
+
+		let output = run(Command::new("wmctrl").arg("-lpx"))?;
+		let own = std::process::id().to_string();
+
+		Ok(output
+			.lines()
+			.filter_map(|line| parse_line(line, &own))
+			.collect())
+	}
+
+	pub fn focus(window: &WindowInfo) -> Result<(), String> {
+		run(Command::new("wmctrl").args(["-ia", &window.id])).map(|_| ())
+	}
+
+	/// Parse a line like `0x03a00007  0 1234   firefox.Firefox  host Title`,
+	/// skipping quicalc's own windows and windows on every desktop, like panels
+	/// and docks
+	fn parse_line(line: &str, own: &str) -> Option<WindowInfo> {
+		let mut rest = line;
+		let mut fields = [""; 5];
+
+		for field in &mut fields {
+			rest = rest.trim_start();
+			let end = rest.find(char::is_whitespace)?;
+			*field = &rest[..end];
+			rest = &rest[end..];
+		}
+
+		let [id, desktop, pid, class, _host] = fields;
+		let title = rest.trim();
+
+		if desktop == "-1" || pid == own || title.is_empty() {
+			return None;
+		}
+
+		// the class is like `instance.Class`, and the class is capitalized
+		let app = class.rsplit('.').next().unwrap_or(class);
+
+		Some(WindowInfo {
+			id: id.to_string(),
+			title: title.to_string(),
+			app: app.to_string(),
+		})
+	}
+}
+
+/// Windows are listed and focused through System Events, which needs the
+/// accessibility permission
+#[cfg(target_os = "macos")]
+mod platform {
+	use std::process::Command;
+
+	use super::{WindowInfo, run};
+
+	const LIST_SCRIPT: &str = r#"
+		on run argv
+			set output to ""
+			tell application "System Events"
+				set own to (item 1 of argv) as integer
+				repeat with proc in (processes whose background only is false and unix id is not own)
+					set appName to name of proc
+					repeat with win in windows of proc
+						try
+							set output to output & appName & tab & (name of win) & linefeed
+						end try
+					end repeat
+				end repeat
+			end tell
+			return output
+		end run
+	"#;
+
+	const FOCUS_SCRIPT: &str = r#"
+		on run argv
+			tell application "System Events" to tell process (item 1 of argv)
+				set frontmost to true
+				perform action "AXRaise" of (first window whose name is (item 2 of argv))
+			end tell
+		end run
+	"#;
+
+	pub fn list() -> Result<Vec<WindowInfo>, String> {
+		let output = run(Command::new("osascript").args([
+			"-e",
+			LIST_SCRIPT,
+			&std::process::id().to_string(),
+		]))?;
+
+		Ok(output
+			.lines()
+			.filter_map(|line| line.split_once('\t'))
+			.filter(|(_, title)| !title.is_empty())
+			.map(|(app, title)| WindowInfo {
+				id: String::new(),
+				title: title.to_string(),
+				app: app.to_string(),
+			})
+			.collect())
+	}
+
+	pub fn focus(window: &WindowInfo) -> Result<(), String> {
+		run(Command::new("osascript").args(["-e", FOCUS_SCRIPT, &window.app, &window.title]))
+			.map(|_| ())
+	}
+}
+
+#[cfg(windows)]
+mod platform {
+	use std::collections::HashMap;
+
+	use sysinfo::{ProcessRefreshKind, ProcessesToUpdate, System};
+	use windows_sys::{
+		Win32::{
+			Foundation::{HWND, LPARAM, TRUE},
+			UI::WindowsAndMessaging::{
+				EnumWindows, GW_OWNER, GWL_EXSTYLE, GetWindow, GetWindowLongW,
+				GetWindowTextLengthW, GetWindowTextW, GetWindowThreadProcessId, IsIconic,
+				IsWindowVisible, SW_RESTORE, SetForegroundWindow, ShowWindow, WS_EX_TOOLWINDOW,
+			},
+		},
+		core::BOOL,
+	};
+
+	use super::WindowInfo;
+
+	pub fn list() -> Result<Vec<WindowInfo>, String> {
+		let mut windows = Vec::<(HWND, u32, String)>::new();
+
+		// SAFETY: the callback only runs during this call, while `windows` lives
+		let listed = unsafe { EnumWindows(Some(collect), &raw mut windows as LPARAM) };
+
+		if listed == 0 {
+			return Err(std::io::Error::last_os_error().to_string());
+		}
+
+		let mut system = System::new();
+		system.refresh_processes_specifics(
+			ProcessesToUpdate::All,
+			true,
+			ProcessRefreshKind::nothing(),
+		);
+		let names = system
+			.processes()
+			.values()
+			.map(|process| {
+				let name = process.name().to_string_lossy();
+				let name = name.strip_suffix(".exe").unwrap_or(&name).to_string();
+				(process.pid().as_u32(), name)
+			})
+			.collect::<HashMap<_, _>>();
+
+		Ok(windows
+			.into_iter()
+			.filter(|(_, pid, _)| *pid != std::process::id())
+			.map(|(hwnd, pid, title)| WindowInfo {
+				id: (hwnd as isize).to_string(),
+				title,
+				app: names.get(&pid).cloned().unwrap_or_default(),
+			})
+			.collect())
+	}
+
+	pub fn focus(window: &WindowInfo) -> Result<(), String> {
+		let hwnd = window.id.parse::<isize>().map_err(|e| e.to_string())? as HWND;
+
+		// SAFETY: these only take the handle, which is checked by Windows
+		unsafe {
+			if IsIconic(hwnd) != 0 {
+				ShowWindow(hwnd, SW_RESTORE);
+			}
+
+			if SetForegroundWindow(hwnd) == 0 {
+				return Err("the window could not be focused".to_string());
+			}
+		}
+
+		Ok(())
+	}
+
+	/// Add a window to the list in `windows`, if it's one that shows up in the
+	/// taskbar
+	unsafe extern "system" fn collect(hwnd: HWND, windows: LPARAM) -> BOOL {
+		// SAFETY: `list` passes a pointer to its list
+		let windows = unsafe { &mut *(windows as *mut Vec<(HWND, u32, String)>) };
+
+		// SAFETY: Windows passes a valid handle
+		unsafe {
+			let tool_window = (GetWindowLongW(hwnd, GWL_EXSTYLE) as u32 & WS_EX_TOOLWINDOW) != 0;
+			let owned = !GetWindow(hwnd, GW_OWNER).is_null();
+
+			if IsWindowVisible(hwnd) == 0 || tool_window || owned {
+				return TRUE;
+			}
+
+			let len = GetWindowTextLengthW(hwnd);
+
+			if len == 0 {
+				return TRUE;
+			}
+
+			let mut title = vec![0; len as usize + 1];
+			let copied = GetWindowTextW(hwnd, title.as_mut_ptr(), title.len() as i32);
+			let mut pid = 0;
+			GetWindowThreadProcessId(hwnd, &raw mut pid);
+
+			windows.push((
+				hwnd,
+				pid,
+				String::from_utf16_lossy(&title[..copied.max(0) as usize]),
+			));
+		}
+
+		TRUE
+	}
+}
@@ -0,0 +1,141 @@
+use std::process::Command;
+
+use crate::locale::tr;
+
+/// An action on the whole system, run from the `sys` mode after confirming it
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SystemCommand {
+	Lock,
+	Sleep,
+	Restart,
+	Shutdown,
+	EmptyTrash,
+}
+
+impl SystemCommand {
+	pub const ALL: &[Self] = &[
+		Self::Lock,
+		Self::Sleep,
+		Self::Restart,
+		Self::Shutdown,
+		Self::EmptyTrash,
+	];
+
+	/// The command entered as `input`, ignoring case and extra whitespace
+	pub fn parse(input: &str) -> Option<Self> {
+		let input = input
+			.split_whitespace()
+			.collect::<Vec<_>>()
+			.join(" ")
+			.to_lowercase();
+
+		match input.as_str() {
+			"lock" => Some(Self::Lock),
+			"sleep" | "suspend" => Some(Self::Sleep),
+			"restart" | "reboot" => Some(Self::Restart),
+			"shutdown" | "shut down" | "poweroff" => Some(Self::Shutdown),
+			"empty trash" | "empty recycle bin" => Some(Self::EmptyTrash),
+			_ => None,
+		}
+	}
+
+	/// The input running this command
+	pub const fn name(self) -> &'static str {
+		match self {
+			Self::Lock => "lock",
+			Self::Sleep => "sleep",
+			Self::Restart => "restart",
+			Self::Shutdown => "shutdown",
+			Self::EmptyTrash => "empty trash",
+		}
+	}
+
+	/// What this command does, shown while it's entered
+	pub fn description(self) -> String {
+		match self {
+			Self::Lock => tr!("sys-lock"),
+			Self::Sleep => tr!("sys-sleep"),
+			Self::Restart => tr!("sys-restart"),
+			Self::Shutdown => tr!("sys-shutdown"),
+			Self::EmptyTrash => tr!("sys-empty-trash"),
+		}
+	}
+
+	/// Run this command, returning once it has been started
+	pub async fn run(self) -> Result<(), String> {
+		tokio::task::spawn_blocking(move || self.run_blocking())
+			.await
+			.map_err(|e| e.to_string())?
+	}
+
+	fn run_blocking(self) -> Result<(), String> {
+		let (program, args) = self.program();
+		let mut command = Command::new(program);
+		command.args(args);
+
+		#[cfg(windows)]
+		{
+			use std::os::windows::process::CommandExt;
+
+			// CREATE_NO_WINDOW, to not flash a console window when starting
+			command.creation_flags(0x0800_0000);
+		}
+
+		let output = command.output().map_err(|e| e.to_string())?;
+
+		if !output.status.success() {
+			return Err(String::from_utf8_lossy(&output.stderr).trim().to_string());
+		}
+
+		Ok(())
+	}
+
+	/// The program running this command, and its arguments
+	#[cfg(target_os = "linux")]
+	const fn program(self) -> (&'static str, &'static [&'static str]) {
+		match self {
+			Self::Lock => ("loginctl", &["lock-session"]),
+			Self::Sleep => ("systemctl", &["suspend"]),
+			Self::Restart => ("systemctl", &["reboot"]),
+			Self::Shutdown => ("systemctl", &["poweroff"]),
+			Self::EmptyTrash => ("gio", &["trash", "--empty"]),
+		}
+	}
+
+	/// The program running this command, and its arguments
+	#[cfg(target_os = "macos")]
+	const fn program(self) -> (&'static str, &'static [&'static str]) {
+		match self {
+			Self::Lock => ("pmset", &["displaysleepnow"]),
+			Self::Sleep => ("pmset", &["sleepnow"]),
+			Self::Restart => ("osascript", &[
+				"-e",
+				r#"tell application "System Events" to restart"#,
+			]),
+			Self::Shutdown => ("osascript", &[
+				"-e",
+				r#"tell application "System Events" to shut down"#,
+			]),
+			Self::EmptyTrash => ("osascript", &[
+				"-e",
+				r#"tell application "Finder" to empty trash"#,
+			]),
+		}
+	}
+
+	/// The program running this command, and its arguments
+	#[cfg(windows)]
+	const fn program(self) -> (&'static str, &'static [&'static str]) {
+		match self {
+			Self::Lock => ("rundll32.exe", &["user32.dll,LockWorkStation"]),
+			Self::Sleep => ("rundll32.exe", &["powrprof.dll,SetSuspendState", "0,1,0"]),
+			Self::Restart => ("shutdown.exe", &["/r", "/t", "0"]),
+			Self::Shutdown => ("shutdown.exe", &["/s", "/t", "0"]),
+			Self::EmptyTrash => ("powershell.exe", &[
+				"-NoProfile",
+				"-Command",
+				"Clear-RecycleBin -Force",
+			]),
+		}
+	}
+}
@@ -0,0 +1,132 @@
+use std::{cell::OnceCell, io, ptr, sync::LazyLock, thread};
+
+use iced::futures::{
+	Stream,
+	channel::mpsc::{self, UnboundedSender},
+};
+use tracing::{debug, error, info};
+use windows_sys::{
+	Win32::{
+		Foundation::{HWND, LPARAM, LRESULT, WPARAM},
+		System::LibraryLoader::GetModuleHandleW,
+		UI::WindowsAndMessaging::{
+			ChangeWindowMessageFilterEx, CreateWindowExW, DefWindowProcW, DispatchMessageW,
+			GetMessageW, MSG, MSGFLT_ALLOW, RegisterClassW, RegisterWindowMessageW, WNDCLASSW,
+		},
+	},
+	w,
+};
+
+use crate::Message;
+
+/// The message broadcast to all top-level windows when the taskbar is created,
+/// like when explorer.exe restarts, after which tray icons have to be added
+/// again
+static TASKBAR_CREATED: LazyLock<u32> =
+	// SAFETY: the name is a valid null-terminated string
+	LazyLock::new(|| unsafe { RegisterWindowMessageW(w!("TaskbarCreated")) });
+
+thread_local! {
+	/// Where the window procedure sends messages to, on the taskbar thread
+	static SENDER: OnceCell<UnboundedSender<Message>> = const { OnceCell::new() };
+}
+
+/// Messages for the taskbar being created again
+///
+/// The taskbar only announces that to top-level windows, so a hidden one is
+/// created on a separate thread to run its message loop.
+pub fn stream() -> impl Stream<Item = Message> {
+	let (sender, receiver) = mpsc::unbounded();
+
+	thread::Builder::new()
+		.name("taskbar".to_string())
+		.spawn(move || listen(sender))
+		.inspect_err(|err| error!(?err, "error spawning taskbar thread"))
+		.ok();
+
+	receiver
+}
+
+fn listen(sender: UnboundedSender<Message>) {
+	SENDER.with(|cell| cell.set(sender)).ok();
+
+	let class_name = w!("QuicalcTaskbar");
+
+	// SAFETY: the strings are static and null-terminated, and the window
+	// procedure lives for the whole program
+	let window = unsafe {
+		let instance = GetModuleHandleW(ptr::null());
+		let class = WNDCLASSW {
+			lpfnWndProc: Some(window_proc),
+			hInstance: instance,
+			lpszClassName: class_name,
+			..Default::default()
+		};
+
+		if RegisterClassW(&class) == 0 {
+			error!(err = ?io::Error::last_os_error(), "error registering taskbar window class");
+			return;
+		}
+
+		// a top-level window (unlike a message-only one) receives broadcasts, and it's
+		// never shown
+		CreateWindowExW(
+			0,
+			class_name,
+			w!("Quicalc"),
+			0,
+			0,
+			0,
+			0,
+			0,
+			ptr::null_mut(),
+			ptr::null_mut(),
+			instance,
+			ptr::null(),
+		)
+	};
+
+	if window.is_null() {
+		error!(err = ?io::Error::last_os_error(), "error creating taskbar window");
+		return;
+	}
+
+	// SAFETY: the window was just created, and no filter info is requested
+	unsafe {
+		// the taskbar can also reach quicalc when it runs elevated
+		ChangeWindowMessageFilterEx(window, *TASKBAR_CREATED, MSGFLT_ALLOW, ptr::null_mut());
+	}
+
+	debug!("listening for the taskbar being created");
+
+	let mut msg = MSG::default();
+
+	// SAFETY: `msg` is valid for writes, and the loop ends on errors (-1) as well
+	// as on `WM_QUIT` (0)
+	while unsafe { GetMessageW(&mut msg, ptr::null_mut(), 0, 0) } > 0 {
+		// SAFETY: the message was just received
+		unsafe { DispatchMessageW(&msg) };
+	}
+}
+
+unsafe extern "system" fn window_proc(
+	window: HWND,
+	msg: u32,
+	wparam: WPARAM,
+	lparam: LPARAM,
+) -> LRESULT {
+	if msg == *TASKBAR_CREATED {
+		info!("taskbar created");
+
+		SENDER.with(|sender| {
+			if let Some(sender) = sender.get() {
+				sender.unbounded_send(Message::TaskbarCreated).ok();
+			}
+		});
+
+		return 0;
+	}
+
+	// SAFETY: the arguments are passed on unchanged
+	unsafe { DefWindowProcW(window, msg, wparam, lparam) }
+}
@@ -0,0 +1,119 @@
+use crate::{config::TaxConfig, locale::tr};
+
+pub const NET_COMMAND: &str = "net";
+pub const GROSS_COMMAND: &str = "gross";
+
+/// The breakdown of a net amount like `net 100` or a gross amount like
+/// `gross 120 reduced` into the net amount and the tax, at the configured rate
+/// or one of the named or entered (like `9.5%`) rates, or `None` if the input
+/// isn't one
+pub fn eval(input: &str, config: &TaxConfig) -> Option<Result<String, String>> {
+	let words = input.split_whitespace().collect::<Vec<_>>();
+
+	let (is_gross, amount, rate) = match words.as_slice() {
+		[NET_COMMAND, amount] => (false, *amount, None),
+		[GROSS_COMMAND, amount] => (true, *amount, None),
+		[NET_COMMAND, amount, rate] => (false, *amount, Some(*rate)),
+		[GROSS_COMMAND, amount, rate] => (true, *amount, Some(*rate)),
+		_ => return None,
+	};
+
+	Some(breakdown(is_gross, amount, rate, config))
+}
+
+fn breakdown(
+	is_gross: bool,
+	amount: &str,
+	rate: Option<&str>,
+	config: &TaxConfig,
+) -> Result<String, String> {
+	let amount = amount
+		.parse::<f64>()
+		.ok()
+		.filter(|amount| amount.is_finite())
+		.ok_or_else(|| tr!("tax-invalid"))?;
+
+	let rate = match rate {
+		None => config.rate.ok_or_else(|| tr!("tax-no-rate"))?,
+		Some(rate) => match rate.strip_suffix('%') {
+			Some(percent) => percent.parse().map_err(|_| tr!("tax-invalid"))?,
+			None => *config
+				.rates
+				.get(rate)
+				.ok_or_else(|| tr!("tax-unknown-rate", rate = rate))?,
+		},
+	};
+
+	// at -100% or less, there's no net amount for a gross amount
+	if !rate.is_finite() || rate <= -100.0 {
+		return Err(tr!("tax-invalid"));
+	}
+
+	let (net, gross) = if is_gross {
+		(amount / (1.0 + rate / 100.0), amount)
+	} else {
+		(amount, amount * (1.0 + rate / 100.0))
+	};
+
+	Ok(tr!(
+		"tax-breakdown",
+		net = format!("{net:.2}"),
+		tax = format!("{:.2}", gross - net),
+		rate = rate,
+		gross = format!("{gross:.2}")
+	))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn config(rate: f64) -> TaxConfig {
+		TaxConfig {
+			rate: Some(rate),
+			rates: [("reduced".to_string(), -100.0)].into(),
+		}
+	}
+
+	#[test]
+	fn breakdown_at_rate() {
+		assert_eq!(
+			eval("gross 120", &config(20.0)),
+			Some(Ok(tr!(
+				"tax-breakdown",
+				net = "100.00".to_string(),
+				tax = "20.00".to_string(),
+				rate = 20.0,
+				gross = "120.00".to_string()
+			)))
+		);
+		assert!(matches!(eval("net 100 9.5%", &config(20.0)), Some(Ok(_))));
+	}
+
+	#[test]
+	fn invalid_configured_rate() {
+		assert_eq!(
+			eval("gross 120", &config(-100.0)),
+			Some(Err(tr!("tax-invalid")))
+		);
+		assert_eq!(
+			eval("net 100", &config(f64::NAN)),
+			Some(Err(tr!("tax-invalid")))
+		);
+		assert_eq!(
+			eval("gross 120 reduced", &config(20.0)),
+			Some(Err(tr!("tax-invalid")))
+		);
+	}
+
+	#[test]
+	fn invalid_entered_rate() {
+		for rate in ["-100%", "-150%", "inf%", "NaN%"] {
+			assert_eq!(
+				eval(&format!("gross 120 {rate}"), &config(20.0)),
+				Some(Err(tr!("tax-invalid"))),
+				"{rate}"
+			);
+		}
+	}
+}
@@ -0,0 +1,170 @@
+use std::time::{Duration, Instant};
+
+use tracing::error;
+
+use crate::locale::tr;
+
+/// Starts a countdown from any mode, like `timer 25m tea`
+pub const COMMAND: &str = "timer";
+/// Starts the stopwatch, or stops it if it's running
+pub const STOPWATCH_COMMAND: &str = "sw";
+
+/// A countdown that notifies once it's done, also while the window is hidden
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Timer {
+	pub id: u64,
+	/// What the timer is for, which may be empty
+	pub label: String,
+	pub ends: Instant,
+}
+
+impl Timer {
+	/// How long is left, rounded up to whole seconds, since a countdown showing
+	/// 0:00 would already be done
+	pub fn remaining(&self) -> Duration {
+		let remaining = self.ends.saturating_duration_since(Instant::now());
+		Duration::from_secs(remaining.as_secs() + u64::from(remaining.subsec_nanos() > 0))
+	}
+
+	/// The timer's label and how long is left
+	pub fn summary(&self) -> String {
+		tr!(
+			"timer-running",
+			label = self.label(),
+			remaining = format_clock(self.remaining())
+		)
+	}
+
+	pub fn label(&self) -> String {
+		label(&self.label)
+	}
+}
+
+/// A timer's label, or a generic one if it has none
+pub fn label(label: &str) -> String {
+	if label.is_empty() {
+		tr!("timer-unlabeled")
+	} else {
+		label.to_string()
+	}
+}
+
+/// A duration followed by an optional label, like `25m tea`, `1h30m`, `90s`,
+/// `4:30`, or `10` (in minutes)
+pub fn parse(input: &str) -> Option<(Duration, String)> {
+	let input = input.trim();
+	let (duration, label) = input.split_once(char::is_whitespace).unwrap_or((input, ""));
+
+	Some((parse_duration(duration)?, label.trim().to_string()))
+}
+
+fn parse_duration(text: &str) -> Option<Duration> {
+	let secs = if let Ok(minutes) = text.parse::<f64>() {
+		minutes * 60.0
+	} else if text.contains(':') {
+		// like `4:30` or `1:00:00`
+		let parts = text
+			.split(':')
+			.map(|part| part.parse::<u32>().ok())
+			.collect::<Option<Vec<_>>>()?;
+
+		if !(2..=3).contains(&parts.len()) {
+			return None;
+		}
+
+		parts
+			.into_iter()
+			.fold(0.0, |secs, part| secs * 60.0 + f64::from(part))
+	} else {
+		// like `1h30m`
+		let mut secs = 0.0;
+		let mut number = String::new();
+
+		for c in text.chars() {
+			let unit = match c {
+				'0'..='9' | '.' => {
+					number.push(c);
+					continue;
+				}
+				'h' => 3600.0,
+				'm' => 60.0,
+				's' => 1.0,
+				_ => return None,
+			};
+
+			secs += number.parse::<f64>().ok()? * unit;
+			number.clear();
+		}
+
+		if !number.is_empty() {
+			return None;
+		}
+
+		secs
+	};
+
+	Duration::try_from_secs_f64(secs)
+		.ok()
+		.filter(|duration| duration.as_secs() >= 1)
+}
+
+/// Format a duration like a clock, as `m:ss` or `h:mm:ss`
+pub fn format_clock(duration: Duration) -> String {
+	let secs = duration.as_secs();
+	let (hours, minutes, secs) = (secs / 3600, secs / 60 % 60, secs % 60);
+
+	if hours > 0 {
+		format!("{hours}:{minutes:02}:{secs:02}")
+	} else {
+		format!("{minutes}:{secs:02}")
+	}
+}
+
+/// Play a short sound for a timer that's done
+pub async fn play_sound() {
+	tokio::task::spawn_blocking(platform::play_sound)
+		.await
+		.unwrap_or_else(|err| Err(err.to_string()))
+		.inspect_err(|err| error!(?err, "error playing timer sound"))
+		.ok();
+}
+
+#[cfg(target_os = "linux")]
+mod platform {
+	use std::process::Command;
+
+	pub fn play_sound() -> Result<(), String> {
+		Command::new("canberra-gtk-play")
+			.args(["-i", "complete"])
+			.status()
+			.map(|_| ())
+			.map_err(|e| e.to_string())
+	}
+}
+
+#[cfg(target_os = "macos")]
+mod platform {
+	use std::process::Command;
+
+	pub fn play_sound() -> Result<(), String> {
+		Command::new("afplay")
+			.arg("/System/Library/Sounds/Glass.aiff")
+			.status()
+			.map(|_| ())
+			.map_err(|e| e.to_string())
+	}
+}
+
+#[cfg(windows)]
+mod platform {
+	use windows_sys::Win32::UI::WindowsAndMessaging::{MB_ICONINFORMATION, MessageBeep};
+
+	pub fn play_sound() -> Result<(), String> {
+		// SAFETY: this only takes the kind of sound to play
+		if unsafe { MessageBeep(MB_ICONINFORMATION) } == 0 {
+			return Err(std::io::Error::last_os_error().to_string());
+		}
+
+		Ok(())
+	}
+}
@@ -0,0 +1,61 @@
+use crate::locale::tr;
+
+pub const COMMAND: &str = "tip";
+
+/// The tip, total, and share of each person for a bill like `84.60 18% /3`,
+/// or `None` if the input is empty
+pub fn eval(input: &str) -> Option<Result<String, String>> {
+	if input.trim().is_empty() {
+		return None;
+	}
+
+	Some(calculate(input).ok_or_else(|| tr!("tip-invalid")))
+}
+
+fn calculate(input: &str) -> Option<String> {
+	let mut amount = None;
+	let mut percent = None;
+	let mut people = None;
+
+	// the number of people can also be entered as `/ 3`
+	let input = input.replace("/ ", "/");
+
+	for word in input.split_whitespace() {
+		if let Some(word) = word.strip_suffix('%') {
+			percent = Some(amount_of(word)?);
+		} else if let Some(word) = word.strip_prefix('/') {
+			people = Some(word.parse::<u32>().ok().filter(|&n| n > 0)?);
+		} else if amount.is_none() {
+			amount = Some(amount_of(word)?);
+		} else {
+			return None;
+		}
+	}
+
+	let amount = amount?;
+	let tip = amount * percent? / 100.0;
+	let total = amount + tip;
+
+	let mut result = tr!(
+		"tip-result",
+		tip = format!("{tip:.2}"),
+		total = format!("{total:.2}")
+	);
+
+	if let Some(people) = people.filter(|&n| n > 1) {
+		result += " · ";
+		result += &tr!(
+			"tip-each",
+			share = format!("{:.2}", total / f64::from(people))
+		);
+	}
+
+	Some(result)
+}
+
+/// A number that can be an amount of money or a percentage
+fn amount_of(word: &str) -> Option<f64> {
+	word.parse::<f64>()
+		.ok()
+		.filter(|amount| amount.is_finite() && *amount >= 0.0)
+}
@@ -0,0 +1,233 @@
+use std::{cell::RefCell, sync::LazyLock};
+
+use image::DynamicImage;
+use notify_rust::Notification;
+use tracing::error;
+use tray_icon::{
+	Icon, TrayIcon, TrayIconBuilder,
+	menu::{CheckMenuItem, Menu, MenuId, MenuItem, Submenu},
+};
+
+use crate::{
+	QuicalcMode, assets, autostart,
+	config::{TrayConfig, TrayIconStyle},
+	locale::tr,
+};
+
+pub static MENU_SHOW: LazyLock<MenuId> = LazyLock::new(|| MenuId::new("show"));
+pub static MENU_AUTOSTART: LazyLock<MenuId> = LazyLock::new(|| MenuId::new("autostart"));
+pub static MENU_CONFIG: LazyLock<MenuId> = LazyLock::new(|| MenuId::new("config"));
+pub static MENU_ABOUT: LazyLock<MenuId> = LazyLock::new(|| MenuId::new("about"));
+#[cfg(feature = "updater")]
+pub static MENU_UPDATE: LazyLock<MenuId> = LazyLock::new(|| MenuId::new("update"));
+#[cfg(feature = "ocr")]
+pub static MENU_CAPTURE: LazyLock<MenuId> = LazyLock::new(|| MenuId::new("capture"));
+pub static MENU_EXIT: LazyLock<MenuId> = LazyLock::new(|| MenuId::new("exit"));
+
+thread_local! {
+	static TRAY: RefCell<Option<Tray>> = const { RefCell::new(None) };
+}
+
+struct Tray {
+	icon: TrayIcon,
+	default_icon: Icon,
+	template: bool,
+	/// The icon that's shown, the default one or the current mode's, and
+	/// whether it's a template
+	shown: (Icon, bool),
+	tooltip: String,
+	menu: Menu,
+	#[cfg(feature = "updater")]
+	update: MenuItem,
+	autostart: CheckMenuItem,
+}
+
+pub fn set_up(config: &TrayConfig) -> Result<(), String> {
+	let mode_items = QuicalcMode::ALL
+		.iter()
+		.map(|mode| MenuItem::with_id(mode_menu_id(*mode), mode.name(), true, None))
+		.collect::<Vec<_>>();
+	let mode_menu = Submenu::new(tr!("tray-mode"), true);
+	for item in &mode_items {
+		mode_menu.append(item).map_err(|e| e.to_string())?;
+	}
+
+	let autostart = CheckMenuItem::with_id(
+		&*MENU_AUTOSTART.0,
+		tr!("tray-autostart"),
+		true,
+		autostart::is_enabled(),
+		None,
+	);
+
+	let tray_menu = Menu::with_items(&[
+		&MenuItem::with_id(&*MENU_SHOW.0, tr!("tray-show"), true, None),
+		&mode_menu,
+		#[cfg(feature = "ocr")]
+		&MenuItem::with_id(&*MENU_CAPTURE.0, tr!("tray-capture"), true, None),
+		&autostart,
+		&MenuItem::with_id(&*MENU_CONFIG.0, tr!("tray-config"), true, None),
+		&MenuItem::with_id(&*MENU_ABOUT.0, tr!("tray-about"), true, None),
+		&MenuItem::with_id(&*MENU_EXIT.0, tr!("tray-exit"), true, None),
+	])
+	.map_err(|e| e.to_string())?;
+
+	let (default_icon, template) = default_icon(config.icon)?;
+	let shown = (default_icon.clone(), template);
+	let tooltip = "Quicalc".to_string();
+
+	TRAY.set(Some(Tray {
+		icon: build(&tray_menu, &shown, &tooltip)?,
+		default_icon,
+		template,
+		shown,
+		tooltip,
+		menu: tray_menu,
+		#[cfg(feature = "updater")]
+		update: MenuItem::with_id(&*MENU_UPDATE.0, tr!("tray-update"), true, None),
+		autostart,
+	}));
+
+	Ok(())
+}
+
+fn build(menu: &Menu, (icon, template): &(Icon, bool), tooltip: &str) -> Result<TrayIcon, String> {
+	TrayIconBuilder::new()
+		.with_tooltip(tooltip)
+		.with_icon(icon.clone())
+		.with_icon_as_template(*template)
+		.with_menu_on_left_click(false)
+		.with_menu(Box::new(menu.clone()))
+		.build()
+		.map_err(|e| e.to_string())
+}
+
+fn default_icon(style: TrayIconStyle) -> Result<(Icon, bool), String> {
+	match style {
+		TrayIconStyle::Color => Ok((to_icon(&assets::ICON)?, false)),
+		TrayIconStyle::Monochrome => Ok((to_icon(&assets::ICON_MONO)?, true)),
+	}
+}
+
+/// Switch to the icon style from a changed config
+pub fn set_style(config: &TrayConfig, mode: QuicalcMode) -> Result<(), String> {
+	let (icon, template) = default_icon(config.icon)?;
+
+	TRAY.with_borrow_mut(|tray| {
+		if let Some(tray) = tray {
+			tray.default_icon = icon;
+			tray.template = template;
+		}
+	});
+
+	set_mode(mode);
+	Ok(())
+}
+
+fn to_icon(image: &DynamicImage) -> Result<Icon, String> {
+	let (width, height, pixels) = (image.width(), image.height(), image.to_rgba8().into_vec());
+	Icon::from_rgba(pixels, width, height).map_err(|e| e.to_string())
+}
+
+pub fn set_mode(mode: QuicalcMode) {
+	TRAY.with_borrow_mut(|tray| {
+		let Some(tray) = tray else {
+			return;
+		};
+
+		let icon = if mode == QuicalcMode::default() {
+			Ok((tray.default_icon.clone(), tray.template))
+		} else {
+			to_icon(mode.indicator_image()).map(|icon| (icon, false))
+		};
+
+		icon.and_then(|(icon, template)| {
+			tray.icon
+				.set_icon_with_as_template(Some(icon.clone()), template)
+				.map_err(|e| e.to_string())?;
+			tray.shown = (icon, template);
+			Ok(())
+		})
+		.inspect_err(|err| error!(?err, "error changing tray icon"))
+		.ok();
+	});
+}
+
+pub fn mode_menu_id(mode: QuicalcMode) -> MenuId {
+	MenuId::new(format!("mode-{}", mode.command()))
+}
+
+pub fn mode_for_menu_id(id: &MenuId) -> Option<QuicalcMode> {
+	QuicalcMode::ALL
+		.iter()
+		.copied()
+		.find(|mode| &mode_menu_id(*mode) == id)
+}
+
+pub fn refresh() {
+	TRAY.with_borrow(|tray| {
+		if let Some(tray) = tray {
+			tray.autostart.set_checked(autostart::is_enabled());
+		}
+	});
+}
+
+/// Replace the tray icon with a new one showing the same icon, tooltip, and
+/// menu, for when the old one may have been lost, like when the taskbar was
+/// restarted
+pub fn recreate() -> Result<(), String> {
+	TRAY.with_borrow_mut(|tray| {
+		let Some(tray) = tray else {
+			return Ok(());
+		};
+
+		// the old icon is removed when it's dropped
+		tray.icon = build(&tray.menu, &tray.shown, &tray.tooltip)?;
+		Ok(())
+	})
+}
+
+pub fn set_tooltip(tooltip: &str) {
+	TRAY.with_borrow_mut(|tray| {
+		if let Some(tray) = tray {
+			tray.icon
+				.set_tooltip(Some(tooltip))
+				.inspect_err(|err| error!(?err, "error setting tray tooltip"))
+				.ok();
+			tray.tooltip = tooltip.to_string();
+		}
+	});
+}
+
+#[cfg(feature = "updater")]
+pub fn show_update(label: &str) {
+	TRAY.with_borrow(|tray| {
+		let Some(tray) = tray else {
+			return;
+		};
+
+		tray.update.set_text(label);
+
+		if !tray
+			.menu
+			.items()
+			.iter()
+			.any(|item| item.id() == &*MENU_UPDATE)
+		{
+			tray.menu
+				.insert(&tray.update, 2)
+				.inspect_err(|err| error!(?err, "error adding update menu item"))
+				.ok();
+		}
+	});
+}
+
+pub fn notify(summary: &str, body: &str) {
+	Notification::new()
+		.appname("Quicalc")
+		.summary(summary)
+		.body(body)
+		.show()
+		.inspect_err(|err| error!(?err, "error showing notification"))
+		.ok();
+}
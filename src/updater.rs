@@ -0,0 +1,233 @@
+use std::{env, fs};
+
+use serde::Deserialize;
+use sha2::{Digest, Sha256};
+use tracing::debug;
+
+use crate::about::BUILD_TAG;
+
+const RELEASES_URL: &str = "https://api.github.com/repos/janm-dev/quicalc/releases/latest";
+const USER_AGENT: &str = concat!("quicalc/", env!("CARGO_PKG_VERSION"));
+
+#[cfg(all(windows, target_arch = "x86_64"))]
+const ASSET_NAME: Option<&str> = Some("quicalc-x86_64-pc-windows-msvc.exe");
+#[cfg(not(all(windows, target_arch = "x86_64")))]
+const ASSET_NAME: Option<&str> = None;
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Release {
+	pub tag_name: String,
+	pub html_url: String,
+	#[serde(default)]
+	assets: Vec<Asset>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct Asset {
+	name: String,
+	browser_download_url: String,
+}
+
+impl Release {
+	pub fn can_self_update(&self) -> bool {
+		self.asset().is_some() && self.checksum_asset().is_some()
+	}
+
+	fn asset(&self) -> Option<&Asset> {
+		ASSET_NAME.and_then(|name| self.assets.iter().find(|asset| asset.name == name))
+	}
+
+	/// The asset with the SHA-256 checksum of the platform's asset, published
+	/// next to it as `<asset name>.sha256`
+	fn checksum_asset(&self) -> Option<&Asset> {
+		let name = format!("{}.sha256", ASSET_NAME?);
+		self.assets.iter().find(|asset| asset.name == name)
+	}
+}
+
+/// The version in a release tag like `v1.2.3` or `v1.3.0-beta.2`, ordered
+/// like in semver, with pre-releases before the release they lead up to
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+struct Version {
+	numbers: Vec<u64>,
+	is_release: bool,
+	pre_release: Vec<Identifier>,
+}
+
+/// A part of a pre-release version, with numbers ordered before words
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+enum Identifier {
+	Number(u64),
+	Word(String),
+}
+
+fn parse_tag(tag: &str) -> Option<Version> {
+	// build metadata doesn't affect which version is newer
+	let version = tag.trim_start_matches('v').split('+').next()?;
+	let (numbers, pre_release) = match version.split_once('-') {
+		Some((numbers, pre_release)) => (numbers, Some(pre_release)),
+		None => (version, None),
+	};
+
+	Some(Version {
+		numbers: numbers
+			.split('.')
+			.map(|part| part.parse().ok())
+			.collect::<Option<_>>()?,
+		is_release: pre_release.is_none(),
+		pre_release: pre_release
+			.into_iter()
+			.flat_map(|pre_release| pre_release.split('.'))
+			.map(|part| match part.parse() {
+				Ok(number) => Identifier::Number(number),
+				Err(_) => Identifier::Word(part.to_string()),
+			})
+			.collect(),
+	})
+}
+
+fn is_newer(current: &str, latest: &str) -> bool {
+	match (parse_tag(current), parse_tag(latest)) {
+		(Some(current), Some(latest)) => latest > current,
+		_ => false,
+	}
+}
+
+fn download(url: &str) -> Result<Vec<u8>, String> {
+	ureq::get(url)
+		.header("User-Agent", USER_AGENT)
+		.call()
+		.map_err(|e| e.to_string())?
+		.into_body()
+		.into_with_config()
+		.limit(256 * 1024 * 1024)
+		.read_to_vec()
+		.map_err(|e| e.to_string())
+}
+
+async fn run_blocking<T: Send + 'static>(
+	f: impl FnOnce() -> Result<T, String> + Send + 'static,
+) -> Result<T, String> {
+	tokio::task::spawn_blocking(f)
+		.await
+		.map_err(|e| e.to_string())?
+}
+
+pub async fn check() -> Result<Option<Release>, String> {
+	let Some(current) = BUILD_TAG else {
+		debug!("not a release build, skipping update check");
+		return Ok(None);
+	};
+
+	run_blocking(move || {
+		let release: Release = ureq::get(RELEASES_URL)
+			.header("User-Agent", USER_AGENT)
+			.header("Accept", "application/vnd.github+json")
+			.call()
+			.map_err(|e| e.to_string())?
+			.into_body()
+			.read_json()
+			.map_err(|e| e.to_string())?;
+
+		debug!(current, latest = %release.tag_name, "fetched latest release");
+
+		Ok(is_newer(current, &release.tag_name).then_some(release))
+	})
+	.await
+}
+
+pub async fn install(release: Release) -> Result<(), String> {
+	run_blocking(move || {
+		let asset = release.asset().ok_or_else(|| {
+			format!(
+				"release {} has no asset for this platform",
+				release.tag_name
+			)
+		})?;
+
+		let checksum_asset = release.checksum_asset().ok_or_else(|| {
+			format!(
+				"release {} has no checksum for {}",
+				release.tag_name, asset.name
+			)
+		})?;
+
+		// like the output of `sha256sum`, the checksum can be followed by the
+		// file name
+		let checksum = String::from_utf8(download(&checksum_asset.browser_download_url)?)
+			.map_err(|e| e.to_string())?
+			.split_whitespace()
+			.next()
+			.unwrap_or_default()
+			.to_ascii_lowercase();
+
+		let executable = download(&asset.browser_download_url)?;
+		let hash = format!("{:x}", Sha256::digest(&executable));
+
+		if hash != checksum {
+			return Err(format!(
+				"checksum of {} doesn't match, expected {checksum:?} but got {hash}",
+				asset.name
+			));
+		}
+
+		debug!(%hash, "verified downloaded update");
+
+		let path = env::temp_dir().join(&asset.name);
+		fs::write(&path, executable).map_err(|e| e.to_string())?;
+		self_replace::self_replace(&path).map_err(|e| e.to_string())?;
+		fs::remove_file(&path).map_err(|e| e.to_string())?;
+
+		Ok(())
+	})
+	.await
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn parses_tags() {
+		assert_eq!(
+			parse_tag("v1.3.0-beta.2+build.5"),
+			Some(Version {
+				numbers: vec![1, 3, 0],
+				is_release: false,
+				pre_release: vec![Identifier::Word("beta".to_string()), Identifier::Number(2)],
+			})
+		);
+		assert_eq!(
+			parse_tag("1.2.3").map(|version| version.numbers),
+			Some(vec![1, 2, 3])
+		);
+		assert_eq!(parse_tag("v1.x.3"), None);
+		assert_eq!(parse_tag("nightly"), None);
+	}
+
+	#[test]
+	fn versions_are_ordered_numerically() {
+		assert!(is_newer("v1.2.3", "v1.2.4"));
+		assert!(is_newer("v1.9.0", "v1.10.0"));
+		assert!(is_newer("v1.2.3", "v2.0.0"));
+		assert!(!is_newer("v1.2.3", "v1.2.3"));
+		assert!(!is_newer("v1.10.0", "v1.9.0"));
+	}
+
+	#[test]
+	fn pre_releases_come_before_the_release() {
+		assert!(is_newer("v1.3.0-beta.2", "v1.3.0"));
+		assert!(is_newer("v1.3.0-beta.2", "v1.3.0-beta.10"));
+		assert!(is_newer("v1.3.0-beta.2", "v1.3.0-rc.1"));
+		assert!(is_newer("v1.3.0-beta", "v1.3.0-beta.1"));
+		assert!(is_newer("v1.2.9", "v1.3.0-beta.1"));
+		assert!(!is_newer("v1.3.0", "v1.3.0-rc.1"));
+		assert!(!is_newer("v1.3.0+a", "v1.3.0+b"));
+	}
+
+	#[test]
+	fn unparsable_tags_are_never_newer() {
+		assert!(!is_newer("v1.2.3", "latest"));
+		assert!(!is_newer("dev", "v1.2.3"));
+	}
+}
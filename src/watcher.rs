@@ -0,0 +1,70 @@
+use std::{fs, sync::mpsc, thread, time::Duration};
+
+use iced::futures::{
+	Stream,
+	channel::mpsc::{self as channel, UnboundedSender},
+};
+use notify::{Event, RecursiveMode, Watcher};
+use tracing::{debug, error, info};
+
+use crate::{Message, config::Config};
+
+/// How long the config file must stay unchanged before it's reloaded
+const SETTLE_TIME: Duration = Duration::from_millis(200);
+
+/// Messages for changes to the config file
+pub fn stream() -> impl Stream<Item = Message> {
+	let (sender, receiver) = channel::unbounded();
+
+	thread::Builder::new()
+		.name("config-watcher".to_string())
+		.spawn(move || {
+			watch(&sender)
+				.inspect_err(|err| error!(?err, "error watching config file"))
+				.ok();
+		})
+		.inspect_err(|err| error!(?err, "error spawning config watcher thread"))
+		.ok();
+
+	receiver
+}
+
+fn watch(sender: &UnboundedSender<Message>) -> Result<(), String> {
+	let path = Config::path().ok_or("could not determine config file location")?;
+	let dir = path
+		.parent()
+		.ok_or("could not determine config directory")?;
+	fs::create_dir_all(dir).map_err(|e| e.to_string())?;
+
+	let (events_sender, events) = mpsc::channel();
+	let mut watcher = notify::recommended_watcher(events_sender).map_err(|e| e.to_string())?;
+
+	// editors often replace the file instead of writing to it, so the directory
+	// is watched to keep seeing changes after that
+	watcher
+		.watch(dir, RecursiveMode::NonRecursive)
+		.map_err(|e| e.to_string())?;
+	info!(?path, "watching config file");
+
+	for event in &events {
+		let changed = event
+			.inspect_err(|err| error!(?err, "error receiving config file event"))
+			.is_ok_and(|event: Event| !event.kind.is_access() && event.paths.contains(&path));
+
+		if !changed {
+			continue;
+		}
+
+		// saving can take several writes, so only reload once they're done
+		while events.recv_timeout(SETTLE_TIME).is_ok() {}
+
+		debug!("config file changed");
+
+		if sender.unbounded_send(Message::ConfigChanged).is_err() {
+			debug!("no longer watching config file");
+			break;
+		}
+	}
+
+	Ok(())
+}
@@ -0,0 +1,308 @@
+use std::time::Duration;
+
+use jiff::civil::Date;
+use serde::Deserialize;
+use tracing::debug;
+
+use crate::{
+	config::{WeatherConfig, WeatherProvider},
+	locale::tr,
+};
+
+/// How long fetched weather is shown before it's fetched again
+pub const MAX_AGE: Duration = Duration::from_secs(10 * 60);
+const USER_AGENT: &str = concat!("quicalc/", env!("CARGO_PKG_VERSION"));
+
+/// The current conditions and the forecast for the next few days at a place
+#[derive(Debug, Clone, PartialEq)]
+pub struct Weather {
+	pub place: String,
+	/// The temperature in °C
+	pub temperature: f64,
+	/// The wind speed in km/h
+	pub wind: f64,
+	pub icon: &'static str,
+	pub days: Vec<Forecast>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Forecast {
+	pub date: String,
+	pub icon: &'static str,
+	/// The lowest and highest temperatures in °C
+	pub min: f64,
+	pub max: f64,
+}
+
+impl Weather {
+	/// The weather on one line, like `Ljubljana ☀ 21°C, 8 km/h · Tue 🌧 12–19°`
+	pub fn summary(&self) -> String {
+		let current = tr!(
+			"wx-current",
+			place = self.place.as_str(),
+			icon = self.icon,
+			temperature = format!("{:.0}", self.temperature),
+			wind = format!("{:.0}", self.wind)
+		);
+
+		[current]
+			.into_iter()
+			.chain(self.days.iter().map(|day| {
+				let weekday = day
+					.date
+					.parse::<Date>()
+					.map_or_else(|_| day.date.clone(), |date| date.strftime("%a").to_string());
+
+				format!("{weekday} {} {:.0}–{:.0}°", day.icon, day.min, day.max)
+			}))
+			.collect::<Vec<_>>()
+			.join(" · ")
+	}
+}
+
+/// Fetch the weather at `location`, or at the configured location if it's
+/// empty
+pub async fn fetch(config: WeatherConfig, location: String) -> Result<Weather, String> {
+	tokio::task::spawn_blocking(move || {
+		let location = if location.is_empty() {
+			config.location
+		} else {
+			location
+		};
+
+		let weather = match config.provider {
+			WeatherProvider::OpenMeteo => open_meteo::fetch(&location),
+			WeatherProvider::Wttr => wttr::fetch(&location),
+		}?;
+
+		debug!(%location, place = %weather.place, "fetched weather");
+		Ok(weather)
+	})
+	.await
+	.map_err(|e| e.to_string())?
+}
+
+fn get<T: for<'de> Deserialize<'de>>(url: &str) -> Result<T, String> {
+	ureq::get(url)
+		.header("User-Agent", USER_AGENT)
+		.call()
+		.map_err(|e| e.to_string())?
+		.into_body()
+		.read_json()
+		.map_err(|e| e.to_string())
+}
+
+/// Open-Meteo, which needs no API key, with places found by its geocoding API
+mod open_meteo {
+	use serde::Deserialize;
+
+	use super::{Forecast, Weather, get};
+	use crate::{locale::tr, queries};
+
+	#[derive(Deserialize)]
+	struct Places {
+		#[serde(default)]
+		results: Vec<Place>,
+	}
+
+	#[derive(Deserialize)]
+	struct Place {
+		name: String,
+		latitude: f64,
+		longitude: f64,
+	}
+
+	#[derive(Deserialize)]
+	struct Response {
+		current: Current,
+		daily: Daily,
+	}
+
+	#[derive(Deserialize)]
+	struct Current {
+		temperature_2m: f64,
+		wind_speed_10m: f64,
+		weather_code: u8,
+	}
+
+	#[derive(Deserialize)]
+	struct Daily {
+		time: Vec<String>,
+		weather_code: Vec<u8>,
+		temperature_2m_min: Vec<f64>,
+		temperature_2m_max: Vec<f64>,
+	}
+
+	pub fn fetch(location: &str) -> Result<Weather, String> {
+		if location.is_empty() {
+			return Err(tr!("wx-no-location"));
+		}
+
+		let places: Places = get(&format!(
+			"https://geocoding-api.open-meteo.com/v1/search?count=1&name={}",
+			queries::encode(location)
+		))?;
+		let place = places
+			.results
+			.into_iter()
+			.next()
+			.ok_or_else(|| tr!("wx-unknown-location", location = location))?;
+
+		let response: Response = get(&format!(
+			"https://api.open-meteo.com/v1/forecast?latitude={}&longitude={}&timezone=auto&forecast_days=4&current=temperature_2m,wind_speed_10m,weather_code&daily=weather_code,temperature_2m_min,temperature_2m_max",
+			place.latitude, place.longitude
+		))?;
+
+		let daily = response.daily;
+		let days = daily
+			.time
+			.into_iter()
+			.zip(daily.weather_code)
+			.zip(daily.temperature_2m_min.into_iter().zip(daily.temperature_2m_max))
+			// today is already covered by the current conditions
+			.skip(1)
+			.map(|((date, code), (min, max))| Forecast {
+				date,
+				icon: icon(code),
+				min,
+				max,
+			})
+			.collect();
+
+		Ok(Weather {
+			place: place.name,
+			temperature: response.current.temperature_2m,
+			wind: response.current.wind_speed_10m,
+			icon: icon(response.current.weather_code),
+			days,
+		})
+	}
+
+	/// The icon for a WMO weather code
+	const fn icon(code: u8) -> &'static str {
+		match code {
+			0 => "☀",
+			1 | 2 => "⛅",
+			3 => "☁",
+			45 | 48 => "🌫",
+			71..=77 | 85 | 86 => "🌨",
+			95..=99 => "⛈",
+			_ => "🌧",
+		}
+	}
+}
+
+/// wttr.in, which finds places itself, and guesses the location from the IP
+/// address if there's none
+mod wttr {
+	use serde::Deserialize;
+
+	use super::{Forecast, Weather, get};
+	use crate::queries;
+
+	#[derive(Deserialize)]
+	struct Response {
+		current_condition: Vec<Current>,
+		nearest_area: Vec<Area>,
+		weather: Vec<Day>,
+	}
+
+	#[derive(Deserialize)]
+	#[serde(rename_all = "camelCase")]
+	struct Current {
+		#[serde(rename = "temp_C")]
+		temp_c: String,
+		windspeed_kmph: String,
+		weather_code: String,
+	}
+
+	#[derive(Deserialize)]
+	#[serde(rename_all = "camelCase")]
+	struct Area {
+		area_name: Vec<Value>,
+	}
+
+	#[derive(Deserialize)]
+	struct Value {
+		value: String,
+	}
+
+	#[derive(Deserialize)]
+	#[serde(rename_all = "camelCase")]
+	struct Day {
+		date: String,
+		#[serde(rename = "mintempC")]
+		min_temp_c: String,
+		#[serde(rename = "maxtempC")]
+		max_temp_c: String,
+		hourly: Vec<Hour>,
+	}
+
+	#[derive(Deserialize)]
+	#[serde(rename_all = "camelCase")]
+	struct Hour {
+		weather_code: String,
+	}
+
+	pub fn fetch(location: &str) -> Result<Weather, String> {
+		let response: Response = get(&format!(
+			"https://wttr.in/{}?format=j1",
+			queries::encode(location)
+		))?;
+
+		let current = response
+			.current_condition
+			.into_iter()
+			.next()
+			.ok_or("no current conditions")?;
+		let place = response
+			.nearest_area
+			.into_iter()
+			.next()
+			.and_then(|area| area.area_name.into_iter().next())
+			.map_or_else(|| location.to_string(), |name| name.value);
+
+		let days = response
+			.weather
+			.into_iter()
+			// today is already covered by the current conditions
+			.skip(1)
+			.map(|day| Forecast {
+				// the weather around noon stands for the whole day
+				icon: icon(
+					day.hourly
+						.get(day.hourly.len() / 2)
+						.map_or("", |hour| hour.weather_code.as_str()),
+				),
+				date: day.date,
+				min: number(&day.min_temp_c),
+				max: number(&day.max_temp_c),
+			})
+			.collect();
+
+		Ok(Weather {
+			place,
+			temperature: number(&current.temp_c),
+			wind: number(&current.windspeed_kmph),
+			icon: icon(&current.weather_code),
+			days,
+		})
+	}
+
+	fn number(text: &str) -> f64 {
+		text.parse().unwrap_or(f64::NAN)
+	}
+
+	/// The icon for a WorldWeatherOnline weather code
+	fn icon(code: &str) -> &'static str {
+		match code.parse::<u16>().unwrap_or_default() {
+			113 => "☀",
+			116 => "⛅",
+			119 | 122 => "☁",
+			143 | 248 | 260 => "🌫",
+			200 | 386..=395 => "⛈",
+			179 | 182 | 185 | 227 | 230 | 281 | 284 | 311..=338 | 350 | 362..=377 => "🌨",
+			_ => "🌧",
+		}
+	}
+}